@@ -0,0 +1,351 @@
+//! Abstract syntax tree produced by `vitte-parser`.
+
+use vitte_core::Span;
+
+/// Implemented by AST nodes that carry a source [`Span`], so passes built on
+/// top of the parser (type checking, the emitter, the LSP) can always
+/// recover *where* a node came from.
+pub trait WithSpan {
+    fn span(&self) -> Span;
+    fn with_span(self, span: Span) -> Self;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    And,
+    Or,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnOp {
+    Neg,
+    Not,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Ident(String, Span),
+    Int(i64, Span),
+    Float(f64, Span),
+    Str(String, Span),
+    Bool(bool, Span),
+    Unary {
+        op: UnOp,
+        expr: Box<Expr>,
+        span: Span,
+    },
+    Binary {
+        op: BinOp,
+        lhs: Box<Expr>,
+        rhs: Box<Expr>,
+        span: Span,
+    },
+    Call {
+        callee: Box<Expr>,
+        args: Vec<Expr>,
+        span: Span,
+    },
+    Field {
+        expr: Box<Expr>,
+        name: String,
+        span: Span,
+    },
+    Index {
+        expr: Box<Expr>,
+        index: Box<Expr>,
+        span: Span,
+    },
+    StructLit {
+        name: String,
+        fields: Vec<(String, Expr)>,
+        span: Span,
+    },
+    /// Constructs a tagged value of an enum, e.g. `Option::Some(1)`.
+    Variant {
+        enum_name: String,
+        tag: String,
+        value: Box<Expr>,
+        span: Span,
+    },
+    Range {
+        start: Option<Box<Expr>>,
+        end: Option<Box<Expr>>,
+        inclusive: bool,
+        span: Span,
+    },
+    Group(Box<Expr>, Span),
+}
+
+impl WithSpan for Expr {
+    fn span(&self) -> Span {
+        match self {
+            Expr::Ident(_, s)
+            | Expr::Int(_, s)
+            | Expr::Float(_, s)
+            | Expr::Str(_, s)
+            | Expr::Bool(_, s)
+            | Expr::Unary { span: s, .. }
+            | Expr::Binary { span: s, .. }
+            | Expr::Call { span: s, .. }
+            | Expr::Field { span: s, .. }
+            | Expr::Index { span: s, .. }
+            | Expr::StructLit { span: s, .. }
+            | Expr::Variant { span: s, .. }
+            | Expr::Range { span: s, .. }
+            | Expr::Group(_, s) => *s,
+        }
+    }
+
+    fn with_span(mut self, span: Span) -> Self {
+        let slot = match &mut self {
+            Expr::Ident(_, s)
+            | Expr::Int(_, s)
+            | Expr::Float(_, s)
+            | Expr::Str(_, s)
+            | Expr::Bool(_, s)
+            | Expr::Unary { span: s, .. }
+            | Expr::Binary { span: s, .. }
+            | Expr::Call { span: s, .. }
+            | Expr::Field { span: s, .. }
+            | Expr::Index { span: s, .. }
+            | Expr::StructLit { span: s, .. }
+            | Expr::Variant { span: s, .. }
+            | Expr::Range { span: s, .. }
+            | Expr::Group(_, s) => s,
+        };
+        *slot = span;
+        self
+    }
+}
+
+impl Expr {
+    /// Whether `self` is a syntactic value: a literal, a bare variable, or
+    /// a record literal built entirely out of values. Syntactic values
+    /// can't hide a side effect, which is what `vitte-typer`'s value
+    /// restriction on `let` bindings relies on.
+    pub fn is_value(&self) -> bool {
+        match self {
+            Expr::Ident(..) | Expr::Int(..) | Expr::Float(..) | Expr::Str(..) | Expr::Bool(..) => true,
+            Expr::Group(inner, _) => inner.is_value(),
+            Expr::Unary { expr, .. } => expr.is_value(),
+            Expr::StructLit { fields, .. } => fields.iter().all(|(_, value)| value.is_value()),
+            Expr::Variant { value, .. } => value.is_value(),
+            Expr::Binary { .. } | Expr::Call { .. } | Expr::Field { .. } | Expr::Index { .. } | Expr::Range { .. } => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Block {
+    pub stmts: Vec<Stmt>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Stmt {
+    Expr(Expr, Span),
+    Let {
+        name: String,
+        /// Span of just the `name` identifier, for diagnostics and
+        /// go-to-definition that need to point at the binding itself
+        /// rather than the whole `let ... ;` statement.
+        name_span: Span,
+        ty: Option<Type>,
+        value: Expr,
+        span: Span,
+    },
+    Return(Option<Expr>, Span),
+    If {
+        cond: Expr,
+        then_branch: Block,
+        else_branch: Option<Box<Stmt>>,
+        span: Span,
+    },
+    While {
+        cond: Expr,
+        body: Block,
+        span: Span,
+    },
+    For {
+        var: String,
+        iter: Expr,
+        body: Block,
+        span: Span,
+    },
+    Block(Block, Span),
+    Assign {
+        target: Expr,
+        value: Expr,
+        span: Span,
+    },
+}
+
+impl WithSpan for Stmt {
+    fn span(&self) -> Span {
+        match self {
+            Stmt::Expr(_, s)
+            | Stmt::Return(_, s)
+            | Stmt::If { span: s, .. }
+            | Stmt::While { span: s, .. }
+            | Stmt::For { span: s, .. }
+            | Stmt::Block(_, s)
+            | Stmt::Assign { span: s, .. }
+            | Stmt::Let { span: s, .. } => *s,
+        }
+    }
+
+    fn with_span(mut self, span: Span) -> Self {
+        let slot = match &mut self {
+            Stmt::Expr(_, s)
+            | Stmt::Return(_, s)
+            | Stmt::If { span: s, .. }
+            | Stmt::While { span: s, .. }
+            | Stmt::For { span: s, .. }
+            | Stmt::Block(_, s)
+            | Stmt::Assign { span: s, .. }
+            | Stmt::Let { span: s, .. } => s,
+        };
+        *slot = span;
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PrimType {
+    I64,
+    F64,
+    Bool,
+    Str,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Type {
+    Prim(PrimType),
+    Ident(String),
+    Tuple(Vec<Type>),
+    Array(Box<Type>),
+    Fn(Vec<Type>, Box<Type>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Param {
+    pub name: String,
+    /// Span of just the `name` identifier, for diagnostics and
+    /// go-to-definition that need to point at the parameter's name rather
+    /// than the whole `name: Type` pair.
+    pub name_span: Span,
+    pub ty: Type,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FnDecl {
+    pub name: String,
+    /// Span of just the `name` identifier, for diagnostics and
+    /// go-to-definition that need to point at the declaration's name
+    /// rather than the whole `fn ... { ... }`.
+    pub name_span: Span,
+    pub params: Vec<Param>,
+    pub ret: Option<Type>,
+    pub body: Block,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConstDecl {
+    pub name: String,
+    pub ty: Option<Type>,
+    pub value: Expr,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructDecl {
+    pub name: String,
+    pub fields: Vec<Param>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumDecl {
+    pub name: String,
+    pub variants: Vec<String>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Item {
+    Fn(FnDecl),
+    Const(ConstDecl),
+    Struct(StructDecl),
+    Enum(EnumDecl),
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Program {
+    pub items: Vec<Item>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span() -> Span {
+        Span::new(0, 0)
+    }
+
+    #[test]
+    fn literals_and_bare_variables_are_values() {
+        assert!(Expr::Int(1, span()).is_value());
+        assert!(Expr::Str("s".to_string(), span()).is_value());
+        assert!(Expr::Ident("x".to_string(), span()).is_value());
+    }
+
+    #[test]
+    fn a_record_literal_built_from_values_is_a_value() {
+        let rec = Expr::StructLit {
+            name: "Point".to_string(),
+            fields: vec![("x".to_string(), Expr::Int(1, span())), ("y".to_string(), Expr::Int(2, span()))],
+            span: span(),
+        };
+        assert!(rec.is_value());
+    }
+
+    #[test]
+    fn a_record_literal_with_a_call_in_a_field_is_not_a_value() {
+        let call = Expr::Call { callee: Box::new(Expr::Ident("f".to_string(), span())), args: Vec::new(), span: span() };
+        let rec = Expr::StructLit { name: "Point".to_string(), fields: vec![("x".to_string(), call)], span: span() };
+        assert!(!rec.is_value());
+    }
+
+    #[test]
+    fn a_call_is_not_a_value() {
+        let call = Expr::Call { callee: Box::new(Expr::Ident("f".to_string(), span())), args: Vec::new(), span: span() };
+        assert!(!call.is_value());
+    }
+
+    #[test]
+    fn a_variant_is_a_value_when_its_payload_is() {
+        let some_one = Expr::Variant {
+            enum_name: "Option".to_string(),
+            tag: "Some".to_string(),
+            value: Box::new(Expr::Int(1, span())),
+            span: span(),
+        };
+        assert!(some_one.is_value());
+
+        let call = Expr::Call { callee: Box::new(Expr::Ident("f".to_string(), span())), args: Vec::new(), span: span() };
+        let some_call = Expr::Variant { enum_name: "Option".to_string(), tag: "Some".to_string(), value: Box::new(call), span: span() };
+        assert!(!some_call.is_value());
+    }
+}
@@ -0,0 +1,32 @@
+use crate::error::CliError;
+use crate::input::Input;
+use crate::options::RunOptions;
+use crate::tokens::tokens_entry;
+use crate::vm::default_vm;
+
+/// A compiled program's `run_bc` hook: executes `bytecode` and reports the
+/// process exit code it should produce. [`default_vm`] is the built-in
+/// implementation; a host embedding the VM can supply its own (e.g. one
+/// backed by an AOT-compiled native path) instead.
+pub type RunFn = fn(&[u8], &RunOptions) -> Result<i32, CliError>;
+
+/// A `vitte` subcommand, ready to execute.
+pub enum Command {
+    /// Runs a compiled program. `run_bc` defaults to [`default_vm`] when
+    /// not set, so `vitte run` does something useful out of the box.
+    Run { bytecode: Vec<u8>, run_bc: Option<RunFn>, options: RunOptions },
+    /// `vitte inspect --tokens`: lexes `input` and dumps its tokens.
+    Tokens { input: Input },
+}
+
+impl Command {
+    pub fn execute(self) -> Result<i32, CliError> {
+        match self {
+            Command::Run { bytecode, run_bc, options } => {
+                let run_bc = run_bc.unwrap_or(default_vm);
+                run_bc(&bytecode, &options)
+            }
+            Command::Tokens { input } => tokens_entry(&input),
+        }
+    }
+}
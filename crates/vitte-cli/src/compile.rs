@@ -0,0 +1,103 @@
+use crate::diagnostics::{diagnostics_to_json, DiagnosticsFormat};
+use crate::error::CliError;
+use vitte_ast::{FnDecl, Item};
+use vitte_compiler::{CompilerOptions, Emitter, FunctionTable};
+use vitte_parser::Parser;
+use vitte_vitbc::Bytecode;
+
+/// Parses and compiles `src`'s `main` function into a VITBC container,
+/// ready for [`crate::default_vm`] (or a custom `run_bc` hook) to execute.
+///
+/// There's no multi-function linking yet — [`Bytecode`] only carries one
+/// function's code and constant pools — so only `main` is emitted; other
+/// top-level functions are just collected into the [`FunctionTable`] that
+/// resolves `main`'s own `Call` sites.
+pub fn compile_main(src: &str) -> Result<Vec<u8>, CliError> {
+    let mut parser = Parser::new(src).map_err(CliError::Lex)?;
+    let program = parser.parse_program().map_err(CliError::Parse)?;
+
+    let main = program
+        .items
+        .iter()
+        .find_map(|item| match item {
+            Item::Fn(f) if f.name == "main" => Some(f),
+            _ => None,
+        })
+        .ok_or(CliError::NoMainFunction)?;
+
+    compile_fn(main, &program, src)
+}
+
+/// A `vitte compile` (or `vitte check`) task: the source to compile and how
+/// to report diagnostics if it fails.
+pub struct CompileTask {
+    pub src: String,
+    pub diagnostics: DiagnosticsFormat,
+}
+
+/// Compiles `task.src`, reporting any diagnostics to stderr in
+/// `task.diagnostics`'s format and returning the process exit code: `0` on
+/// success, `1` if compilation failed. Errors other than a failed compile
+/// (e.g. a lex error) are propagated rather than reported, since they have
+/// no [`vitte_compiler::Diagnostic`] to format.
+pub fn compile_entry(task: &CompileTask) -> Result<i32, CliError> {
+    match compile_main(&task.src) {
+        Ok(_bytecode) => Ok(0),
+        Err(CliError::Compile(error)) => {
+            match task.diagnostics {
+                DiagnosticsFormat::Human => eprintln!("{error}"),
+                DiagnosticsFormat::Json => eprintln!("{}", diagnostics_to_json(&error.diagnostics)),
+            }
+            Ok(1)
+        }
+        Err(other) => Err(other),
+    }
+}
+
+fn compile_fn(f: &FnDecl, program: &vitte_ast::Program, src: &str) -> Result<Vec<u8>, CliError> {
+    let functions = FunctionTable::collect(program);
+    let options = CompilerOptions { emit_debug: true };
+    let compiled = Emitter::new().with_functions(functions).with_options(options).emit_fn(f, src);
+    if compiled.errors.has_errors() {
+        return Err(CliError::Compile(compiled.errors));
+    }
+
+    let bytecode = Bytecode {
+        ints: compiled.pools.ints.values().to_vec(),
+        floats: compiled.pools.floats.values().to_vec(),
+        strings: compiled.pools.strings.values().to_vec(),
+        data: Vec::new(),
+        code: vitte_compiler::encode(&compiled.code),
+        names: vec![f.name.clone()],
+        lines: compiled.lines,
+    };
+    Ok(bytecode.to_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostics::diagnostics_to_json;
+
+    #[test]
+    fn an_unknown_identifier_reports_an_error_diagnostic_as_json() {
+        let error = match compile_main("fn main() { missing; }") {
+            Err(CliError::Compile(error)) => error,
+            other => panic!("expected a compile error, got {other:?}"),
+        };
+        let json = diagnostics_to_json(&error.diagnostics);
+        assert!(json.contains("\"severity\":\"error\""), "{json}");
+    }
+
+    #[test]
+    fn compile_entry_exits_nonzero_on_a_failed_compile() {
+        let task = CompileTask { src: "fn main() { missing; }".to_string(), diagnostics: DiagnosticsFormat::Json };
+        assert_eq!(compile_entry(&task), Ok(1));
+    }
+
+    #[test]
+    fn compile_entry_exits_zero_on_success() {
+        let task = CompileTask { src: "fn main() { 1 + 2; }".to_string(), diagnostics: DiagnosticsFormat::Human };
+        assert_eq!(compile_entry(&task), Ok(0));
+    }
+}
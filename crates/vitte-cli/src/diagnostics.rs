@@ -0,0 +1,67 @@
+use vitte_compiler::Diagnostic;
+
+/// How [`crate::compile_entry`] reports a failed compile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DiagnosticsFormat {
+    /// `Display`-formats [`vitte_compiler::CompileError`] as prose, one
+    /// diagnostic per line.
+    #[default]
+    Human,
+    /// A JSON array of `{severity, message, line, column}` objects, for
+    /// tooling and CI to parse.
+    Json,
+}
+
+/// Serializes `diagnostics` as a JSON array of `{severity, message, line,
+/// column}` objects. Hand-rolled rather than pulling in a JSON crate, since
+/// the shape is fixed and small.
+pub fn diagnostics_to_json(diagnostics: &[Diagnostic]) -> String {
+    let mut out = String::from("[");
+    for (i, diagnostic) in diagnostics.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        out.push_str(&format!(
+            "{{\"severity\":\"{}\",\"message\":\"{}\",\"line\":{},\"column\":{}}}",
+            diagnostic.severity,
+            escape_json(&diagnostic.message()),
+            diagnostic.line,
+            diagnostic.column,
+        ));
+    }
+    out.push(']');
+    out
+}
+
+fn escape_json(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vitte_compiler::{CompileErrorKind, Severity};
+    use vitte_core::Span;
+
+    #[test]
+    fn serializes_one_diagnostic_per_array_entry() {
+        let diagnostics = vec![
+            Diagnostic::new(CompileErrorKind::UnknownLocal { name: "x".to_string() }, Span::new(0, 1), 1, 2),
+            Diagnostic::new(CompileErrorKind::UnsupportedCallee, Span::new(2, 3), 4, 5),
+        ];
+        let json = diagnostics_to_json(&diagnostics);
+        assert_eq!(json.matches("\"severity\":\"error\"").count(), 2);
+        assert!(json.contains("\"line\":1"));
+        assert!(json.contains("\"line\":4"));
+        assert!(matches!(diagnostics[0].severity, Severity::Error));
+    }
+}
@@ -0,0 +1,44 @@
+use std::fmt;
+use vitte_compiler::{CompileError, OpcodeDecodeError};
+use vitte_lexer::LexError;
+use vitte_parser::ParseError;
+use vitte_vitbc::VitbcError;
+
+/// Everything that can go wrong compiling or running a Vitte program from
+/// [`crate::Command::Run`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum CliError {
+    Lex(LexError),
+    Parse(ParseError),
+    Compile(CompileError),
+    /// `main` wasn't declared at the top level of the program.
+    NoMainFunction,
+    /// The bytes handed to [`crate::default_vm`] aren't a valid VITBC
+    /// container.
+    Bytecode(VitbcError),
+    /// The container parsed fine, but its `code` section isn't a valid
+    /// opcode stream.
+    Decode(OpcodeDecodeError),
+    /// A valid opcode stream did something the VM can't execute, e.g.
+    /// popping an empty stack or calling an unresolved function.
+    Runtime(String),
+    /// Reading an [`crate::Input`] (a file or stdin) failed.
+    Io(String),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Lex(error) => write!(f, "{error}"),
+            CliError::Parse(error) => write!(f, "{error}"),
+            CliError::Compile(error) => write!(f, "{error}"),
+            CliError::NoMainFunction => write!(f, "no `main` function found"),
+            CliError::Bytecode(error) => write!(f, "{error}"),
+            CliError::Decode(error) => write!(f, "{error}"),
+            CliError::Runtime(message) => write!(f, "runtime error: {message}"),
+            CliError::Io(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
@@ -0,0 +1,25 @@
+use std::io::Read;
+use std::path::PathBuf;
+
+use crate::error::CliError;
+
+/// Where a `vitte` subcommand reads its source text from.
+pub enum Input {
+    /// Read the whole file at `path`.
+    File(PathBuf),
+    /// Read until EOF on standard input, e.g. for piping `cat file | vitte inspect --tokens -`.
+    Stdin,
+}
+
+impl Input {
+    pub fn read_to_string(&self) -> Result<String, CliError> {
+        match self {
+            Input::File(path) => std::fs::read_to_string(path).map_err(|e| CliError::Io(e.to_string())),
+            Input::Stdin => {
+                let mut buf = String::new();
+                std::io::stdin().read_to_string(&mut buf).map_err(|e| CliError::Io(e.to_string()))?;
+                Ok(buf)
+            }
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//! Command dispatch for the `vitte` command-line tool, plus
+//! [`default_vm`]: a small stack-based interpreter for the VITBC opcodes
+//! `vitte-compiler` emits, used as [`Command::Run`]'s default `run_bc` hook
+//! so running a compiled program works without the host supplying its own
+//! VM.
+
+mod command;
+mod compile;
+mod diagnostics;
+mod error;
+mod input;
+mod options;
+mod tokens;
+mod vm;
+
+pub use command::{Command, RunFn};
+pub use compile::{compile_entry, compile_main, CompileTask};
+pub use diagnostics::DiagnosticsFormat;
+pub use error::CliError;
+pub use input::Input;
+pub use options::RunOptions;
+pub use tokens::tokens_entry;
+pub use vm::default_vm;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn running_a_simple_expression_completes_with_exit_code_zero() {
+        let bytecode = compile_main("fn main() { 1 + 2; }").expect("compiles");
+        let exit_code = default_vm(&bytecode, &RunOptions::default()).expect("runs to completion");
+        assert_eq!(exit_code, 0);
+    }
+
+    #[test]
+    fn command_run_uses_default_vm_when_no_hook_is_supplied() {
+        let bytecode = compile_main("fn main() { 1 + 2; }").expect("compiles");
+        let command = Command::Run { bytecode, run_bc: None, options: RunOptions::default() };
+        assert_eq!(command.execute(), Ok(0));
+    }
+
+    #[test]
+    fn a_program_that_returns_a_value_still_exits_zero() {
+        let bytecode = compile_main("fn main() { return 1 + 2; }").expect("compiles");
+        assert_eq!(default_vm(&bytecode, &RunOptions::default()), Ok(0));
+    }
+
+    #[test]
+    fn a_program_with_a_let_binding_and_branch_runs_to_completion() {
+        let src = "fn main() { let x = 1; if x < 2 { x; } else { 0; } }";
+        let bytecode = compile_main(src).expect("compiles");
+        assert_eq!(default_vm(&bytecode, &RunOptions::default()), Ok(0));
+    }
+
+    #[test]
+    fn compiling_a_program_without_main_reports_no_main_function() {
+        assert_eq!(compile_main("fn helper() {}"), Err(CliError::NoMainFunction));
+    }
+
+    #[test]
+    fn string_concatenation_runs_to_completion() {
+        let bytecode = compile_main(r#"fn main() { "a" + "b"; }"#).expect("compiles");
+        assert_eq!(default_vm(&bytecode, &RunOptions::default()), Ok(0));
+    }
+}
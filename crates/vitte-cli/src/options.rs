@@ -0,0 +1,7 @@
+/// Options threaded through to a [`crate::RunFn`] hook.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RunOptions {
+    /// Prints each instruction [`crate::default_vm`] executes to stderr as
+    /// it runs, for debugging a program that isn't doing what it should.
+    pub trace: bool,
+}
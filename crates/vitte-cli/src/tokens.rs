@@ -0,0 +1,124 @@
+use crate::error::CliError;
+use crate::input::Input;
+use vitte_core::LineMap;
+use vitte_lexer::{Keyword, Lexer, TokenKind};
+
+/// Lexes `input`'s source text and prints one token per line as
+/// `line:col  KIND  lexeme` to stdout, stopping after `Eof`. The backing
+/// implementation for `vitte inspect --tokens`.
+pub fn tokens_entry(input: &Input) -> Result<i32, CliError> {
+    for line in dump_tokens(&input.read_to_string()?)? {
+        println!("{line}");
+    }
+    Ok(0)
+}
+
+/// Builds the `dump_tokens` output lines without printing them, so callers
+/// (and tests) can inspect the formatted text directly.
+fn dump_tokens(src: &str) -> Result<Vec<String>, CliError> {
+    let line_map = LineMap::new(src);
+    let mut lines = Vec::new();
+    for token in Lexer::new(src) {
+        let token = token.map_err(CliError::Lex)?;
+        let (line, col) = line_map.line_col(token.span.start);
+        let lexeme = &src[token.span.start as usize..token.span.end as usize];
+        lines.push(format!("{line}:{col}  {}  {lexeme}", kind_name(&token.kind)));
+        if matches!(token.kind, TokenKind::Eof) {
+            break;
+        }
+    }
+    Ok(lines)
+}
+
+fn kind_name(kind: &TokenKind) -> &'static str {
+    match kind {
+        TokenKind::Ident(_) => "IDENT",
+        TokenKind::Kw(kw) => keyword_name(*kw),
+        TokenKind::Int(..) => "INT",
+        TokenKind::Float(..) => "FLOAT",
+        TokenKind::Str(_) => "STR",
+        TokenKind::Bytes(_) => "BYTES",
+        TokenKind::Byte(_) => "BYTE",
+        TokenKind::LineComment(_) => "LINE_COMMENT",
+        TokenKind::BlockComment(_) => "BLOCK_COMMENT",
+        TokenKind::Eq => "EQ",
+        TokenKind::EqEq => "EQEQ",
+        TokenKind::Bang => "BANG",
+        TokenKind::BangEq => "BANGEQ",
+        TokenKind::Lt => "LT",
+        TokenKind::Le => "LE",
+        TokenKind::Gt => "GT",
+        TokenKind::Ge => "GE",
+        TokenKind::AndAnd => "ANDAND",
+        TokenKind::OrOr => "OROR",
+        TokenKind::Amp => "AMP",
+        TokenKind::Pipe => "PIPE",
+        TokenKind::Caret => "CARET",
+        TokenKind::Tilde => "TILDE",
+        TokenKind::Shl => "SHL",
+        TokenKind::Shr => "SHR",
+        TokenKind::Arrow => "ARROW",
+        TokenKind::FatArrow => "FATARROW",
+        TokenKind::Plus => "PLUS",
+        TokenKind::PlusEq => "PLUSEQ",
+        TokenKind::Minus => "MINUS",
+        TokenKind::MinusEq => "MINUSEQ",
+        TokenKind::Star => "STAR",
+        TokenKind::StarEq => "STAREQ",
+        TokenKind::Slash => "SLASH",
+        TokenKind::SlashEq => "SLASHEQ",
+        TokenKind::Percent => "PERCENT",
+        TokenKind::PercentEq => "PERCENTEQ",
+        TokenKind::Dot => "DOT",
+        TokenKind::DotDot => "DOTDOT",
+        TokenKind::DotDotEq => "DOTDOTEQ",
+        TokenKind::Comma => "COMMA",
+        TokenKind::Semi => "SEMI",
+        TokenKind::Colon => "COLON",
+        TokenKind::LParen => "LPAREN",
+        TokenKind::RParen => "RPAREN",
+        TokenKind::LBrace => "LBRACE",
+        TokenKind::RBrace => "RBRACE",
+        TokenKind::LBracket => "LBRACKET",
+        TokenKind::RBracket => "RBRACKET",
+        TokenKind::Eof => "EOF",
+    }
+}
+
+fn keyword_name(kw: Keyword) -> &'static str {
+    match kw {
+        Keyword::Fn => "FN",
+        Keyword::Let => "LET",
+        Keyword::Const => "CONST",
+        Keyword::Struct => "STRUCT",
+        Keyword::Enum => "ENUM",
+        Keyword::If => "IF",
+        Keyword::Else => "ELSE",
+        Keyword::While => "WHILE",
+        Keyword::For => "FOR",
+        Keyword::In => "IN",
+        Keyword::Return => "RETURN",
+        Keyword::True => "TRUE",
+        Keyword::False => "FALSE",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dumping_a_let_statement_lists_every_token_in_order() {
+        let lines = dump_tokens("let x = 1;").expect("lexes");
+        let kinds: Vec<&str> = lines.iter().map(|line| line.split("  ").nth(1).unwrap()).collect();
+        assert_eq!(kinds, ["LET", "IDENT", "EQ", "INT", "SEMI", "EOF"]);
+    }
+
+    #[test]
+    fn each_line_reports_its_own_lexeme() {
+        let lines = dump_tokens("let x = 1;").expect("lexes");
+        assert_eq!(lines[0], "1:1  LET  let");
+        assert_eq!(lines[1], "1:5  IDENT  x");
+        assert_eq!(lines[3], "1:9  INT  1");
+    }
+}
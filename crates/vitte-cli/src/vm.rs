@@ -0,0 +1,187 @@
+use crate::error::CliError;
+use crate::options::RunOptions;
+use vitte_ast::{BinOp, UnOp};
+use vitte_compiler::Opcode;
+use vitte_vitbc::Bytecode;
+
+#[derive(Debug, Clone)]
+enum Value {
+    Int(i64),
+    Float(f64),
+    Str(String),
+    Bool(bool),
+}
+
+/// A minimal stack-based interpreter for a single compiled function's
+/// VITBC container — the default `run_bc` hook for [`crate::Command::Run`]
+/// when no custom one is supplied.
+///
+/// Supports every opcode [`vitte_compiler::Emitter`] currently emits:
+/// constant loads, locals, binary/unary ops, `Pop`, `Jump`/`JumpIfFalse`,
+/// and `Ret`/`RetVoid`. `Call` isn't resolvable yet since [`Bytecode`] only
+/// carries one function — calling it is a runtime error rather than a
+/// silent no-op, so a program that needs it fails loudly instead of
+/// producing a wrong result.
+pub fn default_vm(bytecode: &[u8], options: &RunOptions) -> Result<i32, CliError> {
+    let bc = Bytecode::from_bytes(bytecode).map_err(CliError::Bytecode)?;
+    let code = vitte_compiler::decode(&bc.code).map_err(CliError::Decode)?;
+
+    let mut stack: Vec<Value> = Vec::new();
+    let mut locals: Vec<Value> = Vec::new();
+    let mut pc: usize = 0;
+
+    while pc < code.len() {
+        let op = &code[pc];
+        if options.trace {
+            eprintln!("{pc:04}: {op:?}");
+        }
+
+        let mut next_pc = pc + 1;
+        match op {
+            Opcode::LoadIntConst(idx) => stack.push(Value::Int(const_at(&bc.ints, *idx)?)),
+            Opcode::LoadFloatConst(idx) => stack.push(Value::Float(const_at(&bc.floats, *idx)?)),
+            Opcode::LoadStrConst(idx) => stack.push(Value::Str(str_const_at(&bc.strings, *idx)?.clone())),
+            Opcode::PushBool(v) => stack.push(Value::Bool(*v)),
+            Opcode::LoadLocal(idx) => stack.push(local_at(&locals, *idx)?.clone()),
+            Opcode::StoreLocal(idx) => {
+                let value = pop(&mut stack)?;
+                store_local(&mut locals, *idx, value);
+            }
+            Opcode::BinOp(op) => {
+                let rhs = pop(&mut stack)?;
+                let lhs = pop(&mut stack)?;
+                stack.push(eval_binop(*op, lhs, rhs)?);
+            }
+            Opcode::UnOp(op) => {
+                let value = pop(&mut stack)?;
+                stack.push(eval_unop(*op, value)?);
+            }
+            Opcode::Pop => {
+                pop(&mut stack)?;
+            }
+            Opcode::Jump(offset) => next_pc = jump_target(pc, *offset)?,
+            Opcode::JumpIfFalse(offset) => {
+                if !truthy(pop(&mut stack)?)? {
+                    next_pc = jump_target(pc, *offset)?;
+                }
+            }
+            Opcode::Call(_) => return Err(CliError::Runtime("calls aren't supported by the default VM yet".to_string())),
+            Opcode::Ret => {
+                pop(&mut stack)?;
+                return Ok(0);
+            }
+            Opcode::RetVoid => return Ok(0),
+        }
+        pc = next_pc;
+    }
+
+    Ok(0)
+}
+
+fn const_at<T: Copy>(pool: &[T], idx: u32) -> Result<T, CliError> {
+    pool.get(idx as usize).copied().ok_or_else(|| CliError::Runtime(format!("constant index {idx} out of range")))
+}
+
+fn str_const_at(pool: &[String], idx: u32) -> Result<&String, CliError> {
+    pool.get(idx as usize).ok_or_else(|| CliError::Runtime(format!("constant index {idx} out of range")))
+}
+
+fn local_at(locals: &[Value], idx: u32) -> Result<&Value, CliError> {
+    locals.get(idx as usize).ok_or_else(|| CliError::Runtime(format!("local slot {idx} read before being stored")))
+}
+
+fn store_local(locals: &mut Vec<Value>, idx: u32, value: Value) {
+    let idx = idx as usize;
+    if idx >= locals.len() {
+        locals.resize_with(idx + 1, || Value::Int(0));
+    }
+    locals[idx] = value;
+}
+
+fn pop(stack: &mut Vec<Value>) -> Result<Value, CliError> {
+    stack.pop().ok_or_else(|| CliError::Runtime("popped an empty stack".to_string()))
+}
+
+fn jump_target(pc: usize, offset: i32) -> Result<usize, CliError> {
+    let target = pc as i64 + 1 + offset as i64;
+    usize::try_from(target).map_err(|_| CliError::Runtime("jump target out of range".to_string()))
+}
+
+fn truthy(value: Value) -> Result<bool, CliError> {
+    match value {
+        Value::Bool(b) => Ok(b),
+        other => Err(CliError::Runtime(format!("expected a bool, found {other:?}"))),
+    }
+}
+
+fn eval_unop(op: UnOp, value: Value) -> Result<Value, CliError> {
+    match (op, value) {
+        (UnOp::Neg, Value::Int(v)) => Ok(Value::Int(-v)),
+        (UnOp::Neg, Value::Float(v)) => Ok(Value::Float(-v)),
+        (UnOp::Not, Value::Bool(v)) => Ok(Value::Bool(!v)),
+        (op, value) => Err(CliError::Runtime(format!("{op:?} doesn't apply to {value:?}"))),
+    }
+}
+
+fn eval_binop(op: BinOp, lhs: Value, rhs: Value) -> Result<Value, CliError> {
+    match (lhs, rhs) {
+        (Value::Int(a), Value::Int(b)) => eval_binop_int(op, a, b),
+        (Value::Float(a), Value::Float(b)) => eval_binop_float(op, a, b),
+        (Value::Bool(a), Value::Bool(b)) => eval_binop_bool(op, a, b),
+        (Value::Str(a), Value::Str(b)) => eval_binop_str(op, a, b),
+        (lhs, rhs) => Err(CliError::Runtime(format!("{op:?} doesn't apply to {lhs:?} and {rhs:?}"))),
+    }
+}
+
+fn eval_binop_int(op: BinOp, a: i64, b: i64) -> Result<Value, CliError> {
+    Ok(match op {
+        BinOp::Add => Value::Int(a + b),
+        BinOp::Sub => Value::Int(a - b),
+        BinOp::Mul => Value::Int(a * b),
+        BinOp::Div => Value::Int(a / b),
+        BinOp::Rem => Value::Int(a % b),
+        BinOp::Eq => Value::Bool(a == b),
+        BinOp::Ne => Value::Bool(a != b),
+        BinOp::Lt => Value::Bool(a < b),
+        BinOp::Le => Value::Bool(a <= b),
+        BinOp::Gt => Value::Bool(a > b),
+        BinOp::Ge => Value::Bool(a >= b),
+        BinOp::And | BinOp::Or => return Err(CliError::Runtime(format!("{op:?} doesn't apply to integers"))),
+    })
+}
+
+fn eval_binop_float(op: BinOp, a: f64, b: f64) -> Result<Value, CliError> {
+    Ok(match op {
+        BinOp::Add => Value::Float(a + b),
+        BinOp::Sub => Value::Float(a - b),
+        BinOp::Mul => Value::Float(a * b),
+        BinOp::Div => Value::Float(a / b),
+        BinOp::Rem => Value::Float(a % b),
+        BinOp::Eq => Value::Bool(a == b),
+        BinOp::Ne => Value::Bool(a != b),
+        BinOp::Lt => Value::Bool(a < b),
+        BinOp::Le => Value::Bool(a <= b),
+        BinOp::Gt => Value::Bool(a > b),
+        BinOp::Ge => Value::Bool(a >= b),
+        BinOp::And | BinOp::Or => return Err(CliError::Runtime(format!("{op:?} doesn't apply to floats"))),
+    })
+}
+
+fn eval_binop_str(op: BinOp, a: String, b: String) -> Result<Value, CliError> {
+    Ok(match op {
+        BinOp::Add => Value::Str(a + &b),
+        BinOp::Eq => Value::Bool(a == b),
+        BinOp::Ne => Value::Bool(a != b),
+        other => return Err(CliError::Runtime(format!("{other:?} doesn't apply to strings"))),
+    })
+}
+
+fn eval_binop_bool(op: BinOp, a: bool, b: bool) -> Result<Value, CliError> {
+    Ok(match op {
+        BinOp::And => Value::Bool(a && b),
+        BinOp::Or => Value::Bool(a || b),
+        BinOp::Eq => Value::Bool(a == b),
+        BinOp::Ne => Value::Bool(a != b),
+        other => return Err(CliError::Runtime(format!("{other:?} doesn't apply to bools"))),
+    })
+}
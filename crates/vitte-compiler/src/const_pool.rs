@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Interns values of type `T`, handing back a stable `u32` index. Interning
+/// the same value twice returns the same index instead of growing the pool.
+#[derive(Debug, Clone, Default)]
+pub struct ConstPool<T> {
+    values: Vec<T>,
+    index: HashMap<T, u32>,
+}
+
+impl<T: Clone + Eq + Hash> ConstPool<T> {
+    pub fn new() -> Self {
+        Self { values: Vec::new(), index: HashMap::new() }
+    }
+
+    /// Interns `value`, returning its index in the pool.
+    pub fn intern(&mut self, value: T) -> u32 {
+        if let Some(&idx) = self.index.get(&value) {
+            return idx;
+        }
+        let idx = self.values.len() as u32;
+        self.index.insert(value.clone(), idx);
+        self.values.push(value);
+        idx
+    }
+
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+/// A constant pool for `f64`, which isn't `Eq`/`Hash`; values are deduped
+/// by their exact bit pattern, so `0.0` and `-0.0` intern separately and
+/// `NaN`s are never folded together by value.
+#[derive(Debug, Clone, Default)]
+pub struct FloatPool {
+    values: Vec<f64>,
+    index: HashMap<u64, u32>,
+}
+
+impl FloatPool {
+    pub fn new() -> Self {
+        Self { values: Vec::new(), index: HashMap::new() }
+    }
+
+    pub fn intern(&mut self, value: f64) -> u32 {
+        let bits = value.to_bits();
+        if let Some(&idx) = self.index.get(&bits) {
+            return idx;
+        }
+        let idx = self.values.len() as u32;
+        self.index.insert(bits, idx);
+        self.values.push(value);
+        idx
+    }
+
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+/// The constant pools a function's bytecode references by index.
+#[derive(Debug, Clone, Default)]
+pub struct ConstPools {
+    pub ints: ConstPool<i64>,
+    pub floats: FloatPool,
+    pub strings: ConstPool<String>,
+}
+
+impl ConstPools {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interning_the_same_value_twice_returns_the_same_index() {
+        let mut pool = ConstPool::new();
+        assert_eq!(pool.intern(42), 0);
+        assert_eq!(pool.intern(7), 1);
+        assert_eq!(pool.intern(42), 0);
+        assert_eq!(pool.len(), 2);
+    }
+
+    #[test]
+    fn float_pool_distinguishes_positive_and_negative_zero() {
+        let mut pool = FloatPool::new();
+        let pos = pool.intern(0.0);
+        let neg = pool.intern(-0.0);
+        assert_ne!(pos, neg);
+        assert_eq!(pool.intern(0.0), pos);
+    }
+}
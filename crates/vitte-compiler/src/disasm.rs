@@ -0,0 +1,140 @@
+use crate::encoding::decode;
+use crate::opcode::Opcode;
+use std::fmt::Write as _;
+use vitte_vitbc::{Bytecode, VitbcError};
+
+/// Parses `bytecode` as a VITBC container and renders its `code` section as
+/// one line per instruction: a zero-padded byte offset, the opcode
+/// mnemonic, and its operands. A `Call` operand is resolved to a name from
+/// the container's `names` section when one exists at that index, falling
+/// back to the raw index otherwise (there's no multi-function linking yet,
+/// so `names` may only cover the function being disassembled — see
+/// `vitte_cli::compile_fn`).
+///
+/// A `code` section that doesn't decode (something `Bytecode::from_bytes`'s
+/// own checksum didn't already catch) is reported as
+/// `VitbcError::Truncated { section: "CODE" }`, the closest of this
+/// format's own error kinds to "this section's payload doesn't parse".
+pub fn disasm(bytecode: &[u8]) -> Result<String, VitbcError> {
+    let bc = Bytecode::from_bytes(bytecode)?;
+    let code = decode(&bc.code).map_err(|_| VitbcError::Truncated { section: "CODE" })?;
+
+    let mut out = String::new();
+    let mut offset = 0usize;
+    for op in &code {
+        let _ = writeln!(out, "{offset:04}  {}", format_instruction(op, &bc));
+        offset += encode_len(op);
+    }
+    Ok(out)
+}
+
+fn format_instruction(op: &Opcode, bc: &Bytecode) -> String {
+    match op {
+        Opcode::LoadIntConst(idx) => format!("LoadIntConst {idx}"),
+        Opcode::LoadFloatConst(idx) => format!("LoadFloatConst {idx}"),
+        Opcode::LoadStrConst(idx) => format!("LoadStrConst {idx}"),
+        Opcode::PushBool(v) => format!("PushBool {v}"),
+        Opcode::LoadLocal(idx) => format!("LoadLocal {idx}"),
+        Opcode::StoreLocal(idx) => format!("StoreLocal {idx}"),
+        Opcode::BinOp(binop) => format!("BinOp {binop:?}"),
+        Opcode::UnOp(unop) => format!("UnOp {unop:?}"),
+        Opcode::Pop => "Pop".to_string(),
+        Opcode::Jump(offset) => format!("Jump {offset}"),
+        Opcode::JumpIfFalse(offset) => format!("JumpIfFalse {offset}"),
+        Opcode::Call(idx) => match bc.names.get(*idx as usize) {
+            Some(name) => format!("Call {name}"),
+            None => format!("Call {idx}"),
+        },
+        Opcode::Ret => "Ret".to_string(),
+        Opcode::RetVoid => "RetVoid".to_string(),
+    }
+}
+
+/// The byte length `crate::encoding::encode` gives a single instruction,
+/// reusing that function instead of duplicating its per-opcode sizes.
+fn encode_len(op: &Opcode) -> usize {
+    crate::encoding::encode(std::slice::from_ref(op)).len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CompilerOptions, Emitter, FunctionTable};
+    use vitte_ast::{BinOp, Block, Expr, FnDecl, Item, Param, PrimType, Program, Stmt, Type};
+    use vitte_core::Span;
+
+    fn span() -> Span {
+        Span::new(0, 0)
+    }
+
+    fn add_decl() -> FnDecl {
+        FnDecl {
+            name: "add".to_string(),
+            name_span: span(),
+            params: vec![
+                Param { name: "a".to_string(), name_span: span(), ty: Type::Prim(PrimType::I64) },
+                Param { name: "b".to_string(), name_span: span(), ty: Type::Prim(PrimType::I64) },
+            ],
+            ret: Some(Type::Prim(PrimType::I64)),
+            body: Block {
+                stmts: vec![Stmt::Return(
+                    Some(Expr::Binary {
+                        op: BinOp::Add,
+                        lhs: Box::new(Expr::Ident("a".to_string(), span())),
+                        rhs: Box::new(Expr::Ident("b".to_string(), span())),
+                        span: span(),
+                    }),
+                    span(),
+                )],
+                span: span(),
+            },
+            span: span(),
+        }
+    }
+
+    fn main_decl() -> FnDecl {
+        let call = Expr::Call {
+            callee: Box::new(Expr::Ident("add".to_string(), span())),
+            args: vec![Expr::Int(1, span()), Expr::Int(2, span())],
+            span: span(),
+        };
+        FnDecl {
+            name: "main".to_string(),
+            name_span: span(),
+            params: Vec::new(),
+            ret: None,
+            body: Block { stmts: vec![Stmt::Expr(call, span())], span: span() },
+            span: span(),
+        }
+    }
+
+    #[test]
+    fn disassembling_a_compiled_call_lists_its_mnemonics_in_order() {
+        let program = Program { items: vec![Item::Fn(add_decl()), Item::Fn(main_decl())] };
+        let functions = FunctionTable::collect(&program);
+        let compiled = Emitter::new().with_functions(functions).with_options(CompilerOptions::default()).emit_fn(&main_decl(), "");
+        assert!(!compiled.errors.has_errors(), "{:?}", compiled.errors);
+
+        let bytecode = Bytecode {
+            ints: compiled.pools.ints.values().to_vec(),
+            floats: compiled.pools.floats.values().to_vec(),
+            strings: compiled.pools.strings.values().to_vec(),
+            data: Vec::new(),
+            code: crate::encode(&compiled.code),
+            names: vec!["add".to_string(), "main".to_string()],
+            lines: Vec::new(),
+        };
+
+        let text = disasm(&bytecode.to_bytes()).expect("valid container");
+        let mnemonics: Vec<&str> = text.lines().map(|line| line.split_whitespace().nth(1).unwrap()).collect();
+        assert_eq!(mnemonics, vec!["LoadIntConst", "LoadIntConst", "Call", "Pop", "RetVoid"]);
+        assert!(text.contains("Call add"), "{text}");
+    }
+
+    #[test]
+    fn a_corrupted_container_is_rejected_before_decoding_its_code() {
+        let mut bytes = Bytecode::default().to_bytes();
+        bytes[0] = b'X';
+        assert_eq!(disasm(&bytes), Err(VitbcError::BadMagic));
+    }
+}
@@ -0,0 +1,536 @@
+use crate::const_pool::ConstPools;
+use crate::error::{CompileError, CompileErrorKind, Diagnostic};
+use crate::functions::FunctionTable;
+use crate::opcode::Opcode;
+use crate::options::CompilerOptions;
+use std::collections::HashMap;
+use vitte_ast::{Block, Expr, FnDecl, Stmt, WithSpan};
+use vitte_core::{LineMap, Span};
+use vitte_vitbc::LineEntry;
+
+/// The result of lowering one function: its instruction stream, the
+/// constant pools its `Load*Const` opcodes index into, a debug line table
+/// (populated only when [`CompilerOptions::emit_debug`] is set), and any
+/// diagnostics raised along the way (e.g. references to unknown names).
+#[derive(Debug, Default)]
+pub struct CompiledFn {
+    pub code: Vec<Opcode>,
+    pub pools: ConstPools,
+    pub lines: Vec<LineEntry>,
+    pub errors: CompileError,
+}
+
+/// Lowers AST nodes into a flat [`Opcode`] stream.
+///
+/// Control-flow constructs are emitted with forward references patched in
+/// once their target is known: a placeholder jump is pushed, its index is
+/// kept around, and [`Emitter::patch_jump`] rewrites it once the rest of
+/// the construct has been emitted. Literals are interned into [`ConstPools`]
+/// as they're encountered, so identical constants share one pool slot.
+/// Locals get a per-function slot map, allocated in declaration order
+/// (parameters first, then each `let`) and reset for every new function.
+/// Calls resolve against a [`FunctionTable`] built once for the whole
+/// module and shared across every function's `Emitter`.
+#[derive(Debug, Default)]
+pub struct Emitter {
+    code: Vec<Opcode>,
+    pools: ConstPools,
+    locals: HashMap<String, u32>,
+    next_slot: u32,
+    functions: FunctionTable,
+    options: CompilerOptions,
+    line_map: Option<LineMap>,
+    lines: Vec<LineEntry>,
+    errors: CompileError,
+}
+
+impl Emitter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolves `Expr::Call` targets against `functions` instead of
+    /// reporting every call as unknown.
+    pub fn with_functions(mut self, functions: FunctionTable) -> Self {
+        self.functions = functions;
+        self
+    }
+
+    pub fn with_options(mut self, options: CompilerOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Lowers a function body. `src` is the source text the function's
+    /// spans were computed against; every diagnostic is resolved to a
+    /// `line:column` in it, and it feeds the debug line table when
+    /// [`CompilerOptions::emit_debug`] is set.
+    pub fn emit_fn(mut self, f: &FnDecl, src: &str) -> CompiledFn {
+        self.line_map = Some(LineMap::new(src));
+        for param in &f.params {
+            self.declare_local(&param.name);
+        }
+        self.emit_block(&f.body);
+        self.code.push(Opcode::RetVoid);
+        CompiledFn { code: self.code, pools: self.pools, lines: self.lines, errors: self.errors }
+    }
+
+    /// Records a debug line-table entry mapping the current end of the
+    /// instruction stream back to `span`'s source position. A no-op unless
+    /// [`CompilerOptions::emit_debug`] is set.
+    fn record_line(&mut self, span: Span) {
+        if !self.options.emit_debug {
+            return;
+        }
+        let (line, column) = self.line_col(span);
+        self.lines.push(LineEntry { offset: self.code.len() as u32, line, column });
+    }
+
+    /// Resolves `span` against the source passed to [`Emitter::emit_fn`].
+    /// Only called from within `emit_fn`, where `line_map` is always set.
+    fn line_col(&self, span: Span) -> (u32, u32) {
+        self.line_map.as_ref().expect("line_map set at the start of emit_fn").line_col(span.start)
+    }
+
+    /// Allocates the next free slot for `name`, or returns its existing
+    /// slot if it was already declared (e.g. shadowing reuses the name but
+    /// not the slot — each `let` gets a fresh one).
+    fn declare_local(&mut self, name: &str) -> u32 {
+        let slot = self.next_slot;
+        self.next_slot += 1;
+        self.locals.insert(name.to_string(), slot);
+        slot
+    }
+
+    /// Records a [`CompileErrorKind`] as a [`Diagnostic`] at `span`,
+    /// resolving its `line:column` from the current function's source.
+    fn push_error(&mut self, kind: CompileErrorKind, span: Span) {
+        let (line, column) = self.line_col(span);
+        self.errors.push(Diagnostic::new(kind, span, line, column));
+    }
+
+    /// Looks up `name`'s slot, recording a [`CompileErrorKind::UnknownLocal`]
+    /// diagnostic (and returning `None`) if it was never declared.
+    fn resolve_local(&mut self, name: &str, span: Span) -> Option<u32> {
+        match self.locals.get(name) {
+            Some(&slot) => Some(slot),
+            None => {
+                self.push_error(CompileErrorKind::UnknownLocal { name: name.to_string() }, span);
+                None
+            }
+        }
+    }
+
+    fn emit_block(&mut self, block: &Block) {
+        for stmt in &block.stmts {
+            self.emit_stmt(stmt);
+        }
+    }
+
+    fn emit_stmt(&mut self, stmt: &Stmt) {
+        self.record_line(stmt.span());
+        match stmt {
+            Stmt::Expr(expr, _) => {
+                self.emit_expr(expr);
+                self.code.push(Opcode::Pop);
+            }
+            Stmt::Return(value, _) => match value {
+                Some(expr) => {
+                    self.emit_expr(expr);
+                    self.code.push(Opcode::Ret);
+                }
+                None => self.code.push(Opcode::RetVoid),
+            },
+            Stmt::If { cond, then_branch, else_branch, .. } => {
+                self.emit_expr(cond);
+                let jump_to_else = self.emit_placeholder_jump_if_false();
+                self.emit_block(then_branch);
+                match else_branch {
+                    Some(else_stmt) => {
+                        let jump_to_end = self.emit_placeholder_jump();
+                        self.patch_jump(jump_to_else);
+                        self.emit_stmt(else_stmt);
+                        self.patch_jump(jump_to_end);
+                    }
+                    None => self.patch_jump(jump_to_else),
+                }
+            }
+            Stmt::While { cond, body, .. } => {
+                let loop_start = self.code.len();
+                self.emit_expr(cond);
+                let jump_to_end = self.emit_placeholder_jump_if_false();
+                self.emit_block(body);
+                self.emit_jump_to(loop_start);
+                self.patch_jump(jump_to_end);
+            }
+            Stmt::Block(block, _) => self.emit_block(block),
+            Stmt::Let { name, value, .. } => {
+                self.emit_expr(value);
+                let slot = self.declare_local(name);
+                self.code.push(Opcode::StoreLocal(slot));
+            }
+            // Assignment and `for` loops aren't lowered yet; they fall
+            // through as no-ops rather than panicking so the rest of a
+            // function still compiles.
+            Stmt::Assign { .. } | Stmt::For { .. } => {}
+        }
+    }
+
+    fn emit_expr(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Int(v, _) => {
+                let idx = self.pools.ints.intern(*v);
+                self.code.push(Opcode::LoadIntConst(idx));
+            }
+            Expr::Float(v, _) => {
+                let idx = self.pools.floats.intern(*v);
+                self.code.push(Opcode::LoadFloatConst(idx));
+            }
+            Expr::Str(v, _) => {
+                let idx = self.pools.strings.intern(v.clone());
+                self.code.push(Opcode::LoadStrConst(idx));
+            }
+            Expr::Bool(v, _) => self.code.push(Opcode::PushBool(*v)),
+            Expr::Ident(name, _) => {
+                if let Some(slot) = self.resolve_local(name, expr.span()) {
+                    self.code.push(Opcode::LoadLocal(slot));
+                }
+            }
+            Expr::Unary { op, expr, .. } => {
+                self.emit_expr(expr);
+                self.code.push(Opcode::UnOp(*op));
+            }
+            Expr::Binary { op, lhs, rhs, .. } => {
+                self.emit_expr(lhs);
+                self.emit_expr(rhs);
+                self.code.push(Opcode::BinOp(*op));
+            }
+            Expr::Group(inner, _) => self.emit_expr(inner),
+            Expr::Call { callee, args, .. } => {
+                for arg in args {
+                    self.emit_expr(arg);
+                }
+                match callee.as_ref() {
+                    Expr::Ident(name, span) => match self.functions.resolve(name) {
+                        Some(idx) => self.code.push(Opcode::Call(idx)),
+                        None => self.push_error(CompileErrorKind::UnknownFunction { name: name.clone() }, *span),
+                    },
+                    // e.g. `foo().bar()` — resolving a non-identifier
+                    // callee isn't supported yet.
+                    other => self.push_error(CompileErrorKind::UnsupportedCallee, other.span()),
+                }
+            }
+            // Field/index access, struct literals, variant construction,
+            // and ranges aren't lowered yet.
+            Expr::Field { .. }
+            | Expr::Index { .. }
+            | Expr::StructLit { .. }
+            | Expr::Variant { .. }
+            | Expr::Range { .. } => {}
+        }
+    }
+
+    /// Pushes a placeholder `JumpIfFalse` and returns its index for later
+    /// patching.
+    fn emit_placeholder_jump_if_false(&mut self) -> usize {
+        self.code.push(Opcode::JumpIfFalse(0));
+        self.code.len() - 1
+    }
+
+    /// Pushes a placeholder `Jump` and returns its index for later patching.
+    fn emit_placeholder_jump(&mut self) -> usize {
+        self.code.push(Opcode::Jump(0));
+        self.code.len() - 1
+    }
+
+    /// Rewrites the jump at `site` so it targets the current end of the
+    /// instruction stream.
+    fn patch_jump(&mut self, site: usize) {
+        let target = self.code.len();
+        self.set_jump_target(site, target);
+    }
+
+    /// Emits a `Jump` targeting `target`, an already-known instruction
+    /// index (used for the backward edge of a loop).
+    fn emit_jump_to(&mut self, target: usize) {
+        let site = self.emit_placeholder_jump();
+        self.set_jump_target(site, target);
+    }
+
+    fn set_jump_target(&mut self, site: usize, target: usize) {
+        let offset = target as i64 - (site as i64 + 1);
+        let offset = i32::try_from(offset).expect("jump offset fits in i32");
+        match &mut self.code[site] {
+            Opcode::Jump(o) | Opcode::JumpIfFalse(o) => *o = offset,
+            other => panic!("patch_jump called on non-jump instruction {other:?}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vitte_ast::{BinOp, Param, Type as AstType};
+    use vitte_core::Span;
+
+    fn span() -> Span {
+        Span::new(0, 0)
+    }
+
+    fn ident(name: &str) -> Expr {
+        Expr::Ident(name.to_string(), span())
+    }
+
+    fn int(v: i64) -> Expr {
+        Expr::Int(v, span())
+    }
+
+    /// `fn count(i: i64) { while i < 3 { i; } }`
+    fn counting_while_fn() -> FnDecl {
+        let cond = Expr::Binary { op: BinOp::Lt, lhs: Box::new(ident("i")), rhs: Box::new(int(3)), span: span() };
+        let body = Block { stmts: vec![Stmt::Expr(ident("i"), span())], span: span() };
+        let while_stmt = Stmt::While { cond, body, span: span() };
+        FnDecl {
+            name: "count".to_string(),
+            name_span: span(),
+            params: vec![Param { name: "i".to_string(), name_span: span(), ty: AstType::Prim(vitte_ast::PrimType::I64) }],
+            ret: None::<AstType>,
+            body: Block { stmts: vec![while_stmt], span: span() },
+            span: span(),
+        }
+    }
+
+    #[test]
+    fn while_loop_jump_if_false_targets_past_the_loop_body() {
+        let result = Emitter::new().emit_fn(&counting_while_fn(), "");
+        let code = result.code;
+        assert!(!result.errors.has_errors());
+
+        let jump_if_false_sites: Vec<usize> =
+            code.iter().enumerate().filter(|(_, op)| matches!(op, Opcode::JumpIfFalse(_))).map(|(i, _)| i).collect();
+        assert_eq!(jump_if_false_sites.len(), 1, "exactly one loop condition check");
+        let site = jump_if_false_sites[0];
+
+        let Opcode::JumpIfFalse(offset) = code[site] else { unreachable!() };
+        let target = (site as i64 + 1 + offset as i64) as usize;
+        // The final instruction is the function's trailing `RetVoid`; the
+        // jump-if-false must land exactly there, past the whole loop body
+        // and its backward jump.
+        assert_eq!(target, code.len() - 1);
+        assert_eq!(code[target], Opcode::RetVoid);
+    }
+
+    #[test]
+    fn while_loop_emits_one_backward_jump_to_the_condition() {
+        let code = Emitter::new().emit_fn(&counting_while_fn(), "").code;
+
+        let backward_jumps: Vec<(usize, i32)> = code
+            .iter()
+            .enumerate()
+            .filter_map(|(i, op)| match op {
+                Opcode::Jump(offset) => Some((i, *offset)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(backward_jumps.len(), 1);
+        let (site, offset) = backward_jumps[0];
+        assert!(offset < 0, "the loop's jump back to its condition must be backward");
+
+        let target = (site as i64 + 1 + offset as i64) as usize;
+        // The condition is the very first thing the loop emits; `i` is the
+        // function's only parameter, so it resolves to slot 0.
+        assert_eq!(code[target], Opcode::LoadLocal(0));
+    }
+
+    /// `fn expr() { 1 + 1 + "a" + "a"; }`
+    fn repeated_literals_fn() -> FnDecl {
+        fn str_lit(s: &str) -> Expr {
+            Expr::Str(s.to_string(), span())
+        }
+        fn add(lhs: Expr, rhs: Expr) -> Expr {
+            Expr::Binary { op: BinOp::Add, lhs: Box::new(lhs), rhs: Box::new(rhs), span: span() }
+        }
+
+        let expr = add(add(add(int(1), int(1)), str_lit("a")), str_lit("a"));
+        FnDecl {
+            name: "expr".to_string(),
+            name_span: span(),
+            params: Vec::<Param>::new(),
+            ret: None::<AstType>,
+            body: Block { stmts: vec![Stmt::Expr(expr, span())], span: span() },
+            span: span(),
+        }
+    }
+
+    #[test]
+    fn identical_literals_share_one_constant_pool_slot() {
+        let result = Emitter::new().emit_fn(&repeated_literals_fn(), "");
+        assert!(!result.errors.has_errors());
+        let (code, pools) = (result.code, result.pools);
+
+        assert_eq!(pools.ints.len(), 1);
+        assert_eq!(pools.strings.len(), 1);
+
+        let int_loads = code.iter().filter(|op| matches!(op, Opcode::LoadIntConst(0))).count();
+        assert_eq!(int_loads, 2, "both `1` literals load the same pool slot");
+        let str_loads = code.iter().filter(|op| matches!(op, Opcode::LoadStrConst(0))).count();
+        assert_eq!(str_loads, 2, "both `\"a\"` literals load the same pool slot");
+    }
+
+    /// `fn f() { let x = 1; let y = x + 2; }`
+    fn two_lets_fn() -> FnDecl {
+        let let_x = Stmt::Let { name: "x".to_string(), name_span: span(), ty: None, value: int(1), span: span() };
+        let y_value = Expr::Binary { op: BinOp::Add, lhs: Box::new(ident("x")), rhs: Box::new(int(2)), span: span() };
+        let let_y = Stmt::Let { name: "y".to_string(), name_span: span(), ty: None, value: y_value, span: span() };
+        FnDecl {
+            name: "f".to_string(),
+            name_span: span(),
+            params: Vec::<Param>::new(),
+            ret: None::<AstType>,
+            body: Block { stmts: vec![let_x, let_y], span: span() },
+            span: span(),
+        }
+    }
+
+    #[test]
+    fn lets_allocate_increasing_local_slots() {
+        let result = Emitter::new().emit_fn(&two_lets_fn(), "");
+        let code = result.code;
+        assert!(!result.errors.has_errors());
+
+        assert!(code.contains(&Opcode::StoreLocal(0)), "`x` gets slot 0");
+        assert!(code.contains(&Opcode::LoadLocal(0)), "`x + 2` reads slot 0");
+        assert!(code.contains(&Opcode::StoreLocal(1)), "`y` gets slot 1");
+    }
+
+    #[test]
+    fn referencing_an_undeclared_name_reports_an_unknown_local() {
+        let f = FnDecl {
+            name: "f".to_string(),
+            name_span: span(),
+            params: Vec::<Param>::new(),
+            ret: None::<AstType>,
+            body: Block { stmts: vec![Stmt::Expr(ident("missing"), span())], span: span() },
+            span: span(),
+        };
+        let result = Emitter::new().emit_fn(&f, "");
+        assert_eq!(result.errors.diagnostics.len(), 1);
+        assert_eq!(result.errors.diagnostics[0].kind, CompileErrorKind::UnknownLocal { name: "missing".to_string() });
+    }
+
+    fn call(callee: &str) -> Expr {
+        Expr::Call { callee: Box::new(ident(callee)), args: Vec::new(), span: span() }
+    }
+
+    /// `fn first() {} fn second() { first(); second(); }`
+    fn two_functions_program() -> vitte_ast::Program {
+        let first = FnDecl {
+            name: "first".to_string(),
+            name_span: span(),
+            params: Vec::<Param>::new(),
+            ret: None::<AstType>,
+            body: Block { stmts: Vec::new(), span: span() },
+            span: span(),
+        };
+        let second_body =
+            Block { stmts: vec![Stmt::Expr(call("first"), span()), Stmt::Expr(call("second"), span())], span: span() };
+        let second = FnDecl {
+            name: "second".to_string(),
+            name_span: span(),
+            params: Vec::<Param>::new(),
+            ret: None::<AstType>,
+            body: second_body,
+            span: span(),
+        };
+        vitte_ast::Program { items: vec![vitte_ast::Item::Fn(first), vitte_ast::Item::Fn(second)] }
+    }
+
+    #[test]
+    fn calls_resolve_to_the_callees_function_table_index() {
+        let program = two_functions_program();
+        let functions = crate::FunctionTable::collect(&program);
+        let second = match &program.items[1] {
+            vitte_ast::Item::Fn(f) => f,
+            _ => unreachable!(),
+        };
+
+        let result = Emitter::new().with_functions(functions).emit_fn(second, "");
+        let code = result.code;
+        assert!(!result.errors.has_errors());
+        assert!(code.contains(&Opcode::Call(0)), "call to `first` resolves to index 0");
+        assert!(code.contains(&Opcode::Call(1)), "call to `second` resolves to its own index, 1");
+    }
+
+    #[test]
+    fn calling_an_unknown_function_reports_a_diagnostic() {
+        let f = FnDecl {
+            name: "f".to_string(),
+            name_span: span(),
+            params: Vec::<Param>::new(),
+            ret: None::<AstType>,
+            body: Block { stmts: vec![Stmt::Expr(call("missing"), span())], span: span() },
+            span: span(),
+        };
+        let result = Emitter::new().emit_fn(&f, "");
+        assert_eq!(result.errors.diagnostics.len(), 1);
+        assert_eq!(result.errors.diagnostics[0].kind, CompileErrorKind::UnknownFunction { name: "missing".to_string() });
+    }
+
+    #[test]
+    fn formatting_an_unknown_local_error_includes_its_line_and_column() {
+        let src = "fn f() {\n    missing;\n}\n";
+        let f = FnDecl {
+            name: "f".to_string(),
+            name_span: span(),
+            params: Vec::<Param>::new(),
+            ret: None::<AstType>,
+            body: Block { stmts: vec![Stmt::Expr(Expr::Ident("missing".to_string(), span_of(src, "missing")), span_of(src, "missing;"))], span: span() },
+            span: span(),
+        };
+        let result = Emitter::new().emit_fn(&f, src);
+        assert!(result.errors.has_errors());
+
+        let rendered = result.errors.to_string();
+        assert!(rendered.contains("unknown local `missing`"), "{rendered}");
+        assert!(rendered.contains("2:5"), "{rendered}");
+    }
+
+    fn span_of(src: &str, needle: &str) -> Span {
+        let start = src.find(needle).expect("needle present in src") as u32;
+        Span::new(start, start + needle.len() as u32)
+    }
+
+    #[test]
+    fn emit_debug_records_a_line_entry_per_statement_starting_at_the_functions_first_line() {
+        let src = "fn f() {\n    let x = 1;\n    let y = 2;\n}\n";
+        let let_x = Stmt::Let {
+            name: "x".to_string(),
+            name_span: span_of(src, "x"),
+            ty: None,
+            value: Expr::Int(1, span_of(src, "1")),
+            span: span_of(src, "let x = 1;"),
+        };
+        let let_y = Stmt::Let {
+            name: "y".to_string(),
+            name_span: span_of(src, "y"),
+            ty: None,
+            value: Expr::Int(2, span_of(src, "2")),
+            span: span_of(src, "let y = 2;"),
+        };
+        let f = FnDecl {
+            name: "f".to_string(),
+            name_span: span(),
+            params: Vec::<Param>::new(),
+            ret: None::<AstType>,
+            body: Block { stmts: vec![let_x, let_y], span: span_of(src, "{\n    let x = 1;\n    let y = 2;\n}") },
+            span: span_of(src, "fn f() {\n    let x = 1;\n    let y = 2;\n}"),
+        };
+
+        let options = crate::CompilerOptions { emit_debug: true };
+        let result = Emitter::new().with_options(options).emit_fn(&f, src);
+        assert!(!result.errors.has_errors());
+
+        assert_eq!(result.lines.len(), f.body.stmts.len(), "one line entry per statement");
+        assert_eq!(result.lines[0].line, 2, "the first statement is on the function's first body line");
+    }
+}
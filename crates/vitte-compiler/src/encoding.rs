@@ -0,0 +1,196 @@
+use crate::opcode::Opcode;
+use std::fmt;
+use vitte_ast::{BinOp, UnOp};
+
+/// Everything that can go wrong decoding a byte stream written by
+/// [`encode`] back into [`Opcode`]s — e.g. when it's been truncated or
+/// corrupted in transit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OpcodeDecodeError {
+    Truncated,
+    UnknownOpcode(u8),
+    UnknownBinOp(u8),
+    UnknownUnOp(u8),
+}
+
+impl fmt::Display for OpcodeDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OpcodeDecodeError::Truncated => write!(f, "truncated opcode stream"),
+            OpcodeDecodeError::UnknownOpcode(tag) => write!(f, "unknown opcode tag {tag}"),
+            OpcodeDecodeError::UnknownBinOp(tag) => write!(f, "unknown binary op tag {tag}"),
+            OpcodeDecodeError::UnknownUnOp(tag) => write!(f, "unknown unary op tag {tag}"),
+        }
+    }
+}
+
+impl std::error::Error for OpcodeDecodeError {}
+
+/// Serializes a function's [`Opcode`] stream into the raw bytes that go in
+/// a `vitte_vitbc::Bytecode::code` field: one tag byte per instruction,
+/// followed by its fixed-width operands, little-endian.
+pub fn encode(code: &[Opcode]) -> Vec<u8> {
+    let mut out = Vec::new();
+    for op in code {
+        match op {
+            Opcode::LoadIntConst(idx) => push_u32_op(&mut out, 0, *idx),
+            Opcode::LoadFloatConst(idx) => push_u32_op(&mut out, 1, *idx),
+            Opcode::LoadStrConst(idx) => push_u32_op(&mut out, 2, *idx),
+            Opcode::PushBool(v) => out.extend([3, *v as u8]),
+            Opcode::LoadLocal(idx) => push_u32_op(&mut out, 4, *idx),
+            Opcode::StoreLocal(idx) => push_u32_op(&mut out, 5, *idx),
+            Opcode::BinOp(binop) => out.extend([6, encode_binop(*binop)]),
+            Opcode::UnOp(unop) => out.extend([7, encode_unop(*unop)]),
+            Opcode::Pop => out.push(8),
+            Opcode::Jump(offset) => push_i32_op(&mut out, 9, *offset),
+            Opcode::JumpIfFalse(offset) => push_i32_op(&mut out, 10, *offset),
+            Opcode::Call(idx) => push_u32_op(&mut out, 11, *idx),
+            Opcode::Ret => out.push(12),
+            Opcode::RetVoid => out.push(13),
+        }
+    }
+    out
+}
+
+/// Parses bytes written by [`encode`] back into an [`Opcode`] stream.
+pub fn decode(bytes: &[u8]) -> Result<Vec<Opcode>, OpcodeDecodeError> {
+    let mut code = Vec::new();
+    let mut pos = 0;
+    while pos < bytes.len() {
+        let tag = bytes[pos];
+        pos += 1;
+        let op = match tag {
+            0 => Opcode::LoadIntConst(read_u32(bytes, &mut pos)?),
+            1 => Opcode::LoadFloatConst(read_u32(bytes, &mut pos)?),
+            2 => Opcode::LoadStrConst(read_u32(bytes, &mut pos)?),
+            3 => Opcode::PushBool(read_u8(bytes, &mut pos)? != 0),
+            4 => Opcode::LoadLocal(read_u32(bytes, &mut pos)?),
+            5 => Opcode::StoreLocal(read_u32(bytes, &mut pos)?),
+            6 => Opcode::BinOp(decode_binop(read_u8(bytes, &mut pos)?)?),
+            7 => Opcode::UnOp(decode_unop(read_u8(bytes, &mut pos)?)?),
+            8 => Opcode::Pop,
+            9 => Opcode::Jump(read_i32(bytes, &mut pos)?),
+            10 => Opcode::JumpIfFalse(read_i32(bytes, &mut pos)?),
+            11 => Opcode::Call(read_u32(bytes, &mut pos)?),
+            12 => Opcode::Ret,
+            13 => Opcode::RetVoid,
+            other => return Err(OpcodeDecodeError::UnknownOpcode(other)),
+        };
+        code.push(op);
+    }
+    Ok(code)
+}
+
+fn push_u32_op(out: &mut Vec<u8>, tag: u8, value: u32) {
+    out.push(tag);
+    out.extend_from_slice(&value.to_le_bytes());
+}
+
+fn push_i32_op(out: &mut Vec<u8>, tag: u8, value: i32) {
+    out.push(tag);
+    out.extend_from_slice(&value.to_le_bytes());
+}
+
+fn read_u8(bytes: &[u8], pos: &mut usize) -> Result<u8, OpcodeDecodeError> {
+    let b = *bytes.get(*pos).ok_or(OpcodeDecodeError::Truncated)?;
+    *pos += 1;
+    Ok(b)
+}
+
+fn read_u32(bytes: &[u8], pos: &mut usize) -> Result<u32, OpcodeDecodeError> {
+    let slice = bytes.get(*pos..*pos + 4).ok_or(OpcodeDecodeError::Truncated)?;
+    *pos += 4;
+    Ok(u32::from_le_bytes(slice.try_into().expect("slice of len 4")))
+}
+
+fn read_i32(bytes: &[u8], pos: &mut usize) -> Result<i32, OpcodeDecodeError> {
+    read_u32(bytes, pos).map(|v| v as i32)
+}
+
+fn encode_binop(op: BinOp) -> u8 {
+    match op {
+        BinOp::Add => 0,
+        BinOp::Sub => 1,
+        BinOp::Mul => 2,
+        BinOp::Div => 3,
+        BinOp::Rem => 4,
+        BinOp::Eq => 5,
+        BinOp::Ne => 6,
+        BinOp::Lt => 7,
+        BinOp::Le => 8,
+        BinOp::Gt => 9,
+        BinOp::Ge => 10,
+        BinOp::And => 11,
+        BinOp::Or => 12,
+    }
+}
+
+fn decode_binop(tag: u8) -> Result<BinOp, OpcodeDecodeError> {
+    match tag {
+        0 => Ok(BinOp::Add),
+        1 => Ok(BinOp::Sub),
+        2 => Ok(BinOp::Mul),
+        3 => Ok(BinOp::Div),
+        4 => Ok(BinOp::Rem),
+        5 => Ok(BinOp::Eq),
+        6 => Ok(BinOp::Ne),
+        7 => Ok(BinOp::Lt),
+        8 => Ok(BinOp::Le),
+        9 => Ok(BinOp::Gt),
+        10 => Ok(BinOp::Ge),
+        11 => Ok(BinOp::And),
+        12 => Ok(BinOp::Or),
+        other => Err(OpcodeDecodeError::UnknownBinOp(other)),
+    }
+}
+
+fn encode_unop(op: UnOp) -> u8 {
+    match op {
+        UnOp::Neg => 0,
+        UnOp::Not => 1,
+    }
+}
+
+fn decode_unop(tag: u8) -> Result<UnOp, OpcodeDecodeError> {
+    match tag {
+        0 => Ok(UnOp::Neg),
+        1 => Ok(UnOp::Not),
+        other => Err(OpcodeDecodeError::UnknownUnOp(other)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_every_opcode_variant() {
+        let code = vec![
+            Opcode::LoadIntConst(1),
+            Opcode::LoadFloatConst(2),
+            Opcode::LoadStrConst(3),
+            Opcode::PushBool(true),
+            Opcode::LoadLocal(4),
+            Opcode::StoreLocal(5),
+            Opcode::BinOp(BinOp::Add),
+            Opcode::UnOp(UnOp::Not),
+            Opcode::Pop,
+            Opcode::Jump(-3),
+            Opcode::JumpIfFalse(7),
+            Opcode::Call(9),
+            Opcode::Ret,
+            Opcode::RetVoid,
+        ];
+        assert_eq!(decode(&encode(&code)).unwrap(), code);
+    }
+
+    #[test]
+    fn rejects_an_unknown_opcode_tag() {
+        assert_eq!(decode(&[255]), Err(OpcodeDecodeError::UnknownOpcode(255)));
+    }
+
+    #[test]
+    fn rejects_a_truncated_operand() {
+        assert_eq!(decode(&[0, 1, 2]), Err(OpcodeDecodeError::Truncated));
+    }
+}
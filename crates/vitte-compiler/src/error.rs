@@ -0,0 +1,118 @@
+use std::fmt;
+use vitte_core::Span;
+
+/// How serious a [`Diagnostic`] is. Only `Error` exists today; `Warning`
+/// is the obvious next addition once the emitter can recover past a
+/// problem instead of just leaving a gap in the bytecode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CompileErrorKind {
+    /// `Expr::Ident` referenced a name with no matching local slot.
+    UnknownLocal { name: String },
+    /// `Expr::Call` targeted a name with no matching function declaration.
+    UnknownFunction { name: String },
+    /// `Expr::Call`'s callee wasn't a bare identifier (e.g. `foo().bar()`),
+    /// which the emitter can't yet resolve to a call target.
+    UnsupportedCallee,
+}
+
+impl CompileErrorKind {
+    fn message(&self) -> String {
+        match self {
+            CompileErrorKind::UnknownLocal { name } => format!("unknown local `{name}`"),
+            CompileErrorKind::UnknownFunction { name } => format!("call to unknown function `{name}`"),
+            CompileErrorKind::UnsupportedCallee => "unsupported call target".to_string(),
+        }
+    }
+}
+
+/// A single compile-time problem, carrying both its raw source [`Span`]
+/// and the 1-indexed `line:column` it was resolved to when raised.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub kind: CompileErrorKind,
+    pub span: Span,
+    pub line: u32,
+    pub column: u32,
+}
+
+impl Diagnostic {
+    pub fn new(kind: CompileErrorKind, span: Span, line: u32, column: u32) -> Self {
+        Self { severity: Severity::Error, kind, span, line, column }
+    }
+
+    /// The human-readable message, without the severity/position prefix
+    /// [`Diagnostic`]'s `Display` impl adds — e.g. for embedding in a
+    /// machine-readable diagnostics format.
+    pub fn message(&self) -> String {
+        self.kind.message()
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} at {}:{}", self.severity, self.kind.message(), self.line, self.column)
+    }
+}
+
+/// Every [`Diagnostic`] raised while compiling one function.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CompileError {
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl CompileError {
+    pub fn push(&mut self, diagnostic: Diagnostic) {
+        self.diagnostics.push(diagnostic);
+    }
+
+    pub fn has_errors(&self) -> bool {
+        !self.diagnostics.is_empty()
+    }
+}
+
+impl fmt::Display for CompileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, diagnostic) in self.diagnostics.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{diagnostic}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for CompileError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_includes_the_resolved_line_and_column() {
+        let diagnostic =
+            Diagnostic::new(CompileErrorKind::UnknownLocal { name: "missing".to_string() }, Span::new(20, 27), 3, 5);
+        let rendered = diagnostic.to_string();
+        assert!(rendered.contains("unknown local `missing`"), "{rendered}");
+        assert!(rendered.contains("3:5"), "{rendered}");
+    }
+
+    #[test]
+    fn an_error_with_no_diagnostics_reports_no_errors() {
+        assert!(!CompileError::default().has_errors());
+    }
+}
@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+use vitte_ast::{Item, Program};
+
+/// Maps function names to the index their `Opcode::Call` targets reference,
+/// assigned in declaration order.
+#[derive(Debug, Clone, Default)]
+pub struct FunctionTable {
+    index: HashMap<String, u32>,
+}
+
+impl FunctionTable {
+    /// Collects every `fn` declared at the top level of `program`.
+    pub fn collect(program: &Program) -> Self {
+        let mut index = HashMap::new();
+        for item in &program.items {
+            if let Item::Fn(f) = item {
+                let next = index.len() as u32;
+                index.entry(f.name.clone()).or_insert(next);
+            }
+        }
+        Self { index }
+    }
+
+    pub fn resolve(&self, name: &str) -> Option<u32> {
+        self.index.get(name).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vitte_ast::{Block, FnDecl};
+    use vitte_core::Span;
+
+    fn empty_fn(name: &str) -> FnDecl {
+        FnDecl {
+            name: name.to_string(),
+            name_span: Span::new(0, 0),
+            params: Vec::new(),
+            ret: None,
+            body: Block { stmts: Vec::new(), span: Span::new(0, 0) },
+            span: Span::new(0, 0),
+        }
+    }
+
+    #[test]
+    fn functions_are_indexed_in_declaration_order() {
+        let program = Program { items: vec![Item::Fn(empty_fn("first")), Item::Fn(empty_fn("second"))] };
+        let table = FunctionTable::collect(&program);
+        assert_eq!(table.resolve("first"), Some(0));
+        assert_eq!(table.resolve("second"), Some(1));
+        assert_eq!(table.resolve("missing"), None);
+    }
+}
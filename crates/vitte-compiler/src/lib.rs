@@ -0,0 +1,20 @@
+//! Lowers a `vitte-ast` tree into a flat stream of bytecode instructions
+//! that `vitte-vitbc` can later serialize.
+
+mod const_pool;
+mod disasm;
+mod emitter;
+mod encoding;
+mod error;
+mod functions;
+mod opcode;
+mod options;
+
+pub use const_pool::{ConstPool, ConstPools, FloatPool};
+pub use disasm::disasm;
+pub use emitter::{CompiledFn, Emitter};
+pub use encoding::{decode, encode, OpcodeDecodeError};
+pub use error::{CompileError, CompileErrorKind, Diagnostic, Severity};
+pub use functions::FunctionTable;
+pub use opcode::Opcode;
+pub use options::CompilerOptions;
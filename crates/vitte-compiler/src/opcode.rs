@@ -0,0 +1,34 @@
+use vitte_ast::{BinOp, UnOp};
+
+/// A single bytecode instruction produced by [`crate::Emitter`].
+///
+/// `Jump` and `JumpIfFalse` carry a relative offset, counted from the
+/// instruction immediately *after* the jump itself — a negative offset
+/// jumps backward (as in a loop's back edge), a positive one forward.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Opcode {
+    /// Pushes `ints[idx]` from the function's constant pool.
+    LoadIntConst(u32),
+    /// Pushes `floats[idx]` from the function's constant pool.
+    LoadFloatConst(u32),
+    /// Pushes `strings[idx]` from the function's constant pool.
+    LoadStrConst(u32),
+    PushBool(bool),
+    /// Loads the function-local slot `idx` allocated for a `let` binding.
+    LoadLocal(u32),
+    /// Pops the top of the stack into the function-local slot `idx`.
+    StoreLocal(u32),
+    BinOp(BinOp),
+    UnOp(UnOp),
+    /// Discards the value on top of the stack.
+    Pop,
+    Jump(i32),
+    JumpIfFalse(i32),
+    /// Calls the function at index `idx` in the module's function table,
+    /// consuming its arguments (already pushed in order) from the stack.
+    Call(u32),
+    /// Returns the value on top of the stack.
+    Ret,
+    /// Returns with no value.
+    RetVoid,
+}
@@ -0,0 +1,10 @@
+/// Knobs controlling what [`crate::Emitter`] produces alongside the
+/// bytecode itself.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CompilerOptions {
+    /// When set, the emitter records a [`vitte_vitbc::LineEntry`] for every
+    /// statement it lowers, at the cost of walking `vitte_core::LineMap`
+    /// for each one. Off by default so callers that don't need debug info
+    /// don't pay for it.
+    pub emit_debug: bool,
+}
@@ -0,0 +1,7 @@
+//! Primitives shared across the Vitte toolchain: source positions and spans.
+
+mod line_map;
+mod span;
+
+pub use line_map::LineMap;
+pub use span::{Pos, Span};
@@ -0,0 +1,95 @@
+use crate::Pos;
+
+/// Maps byte offsets into a source file to 1-indexed `(line, column)` pairs.
+///
+/// The plain [`LineMap::line_col`] counts columns in bytes, which is wrong
+/// for any line containing multibyte characters or tabs. Use
+/// [`LineMap::line_col_utf16`] for LSP (which speaks UTF-16 columns) or
+/// [`LineMap::line_col_scalar`] to count columns in Unicode scalar values.
+#[derive(Debug, Clone)]
+pub struct LineMap {
+    /// Byte offset of the start of each line; `line_starts[0] == 0`.
+    line_starts: Vec<Pos>,
+}
+
+impl LineMap {
+    pub fn new(src: &str) -> Self {
+        let mut line_starts = vec![0];
+        for (i, b) in src.bytes().enumerate() {
+            if b == b'\n' {
+                line_starts.push(i as Pos + 1);
+            }
+        }
+        Self { line_starts }
+    }
+
+    /// Returns the 0-indexed line number containing `pos`.
+    fn line_index(&self, pos: Pos) -> usize {
+        match self.line_starts.binary_search(&pos) {
+            Ok(i) => i,
+            Err(i) => i - 1,
+        }
+    }
+
+    /// 1-indexed `(line, column)`, counting the column in bytes. Wrong for
+    /// multibyte characters and tabs; prefer [`Self::line_col_scalar`] or
+    /// [`Self::line_col_utf16`].
+    pub fn line_col(&self, pos: Pos) -> (u32, u32) {
+        let line = self.line_index(pos);
+        let line_start = self.line_starts[line];
+        (line as u32 + 1, pos - line_start + 1)
+    }
+
+    /// 1-indexed `(line, column)`, counting the column in Unicode scalar
+    /// values (`char`s) rather than bytes.
+    pub fn line_col_scalar(&self, src: &str, pos: Pos) -> (u32, u32) {
+        let line = self.line_index(pos);
+        let line_start = self.line_starts[line] as usize;
+        let col = src[line_start..pos as usize].chars().count() as u32 + 1;
+        (line as u32 + 1, col)
+    }
+
+    /// 1-indexed `(line, column)`, counting the column in UTF-16 code units,
+    /// as required by the Language Server Protocol.
+    pub fn line_col_utf16(&self, src: &str, pos: Pos) -> (u32, u32) {
+        let line = self.line_index(pos);
+        let line_start = self.line_starts[line] as usize;
+        let col = src[line_start..pos as usize].chars().map(char::len_utf16).sum::<usize>() as u32 + 1;
+        (line as u32 + 1, col)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn byte_column_is_wrong_for_multibyte_but_documented() {
+        let src = "é=1";
+        let lm = LineMap::new(src);
+        // 'é' is 2 bytes in UTF-8, so the byte offset of '=' is 2, but it is
+        // the second *character* on the line.
+        let eq_pos = src.find('=').unwrap() as Pos;
+        assert_eq!(lm.line_col(eq_pos), (1, 3));
+        assert_eq!(lm.line_col_scalar(src, eq_pos), (1, 2));
+        assert_eq!(lm.line_col_utf16(src, eq_pos), (1, 2));
+    }
+
+    #[test]
+    fn tab_indented_line() {
+        let src = "\tx = 1";
+        let lm = LineMap::new(src);
+        let x_pos = src.find('x').unwrap() as Pos;
+        // a tab counts as exactly one column, like any other character
+        assert_eq!(lm.line_col_scalar(src, x_pos), (1, 2));
+        assert_eq!(lm.line_col_utf16(src, x_pos), (1, 2));
+    }
+
+    #[test]
+    fn second_line_offsets() {
+        let src = "abc\ndéf";
+        let lm = LineMap::new(src);
+        let pos = src.rfind('f').unwrap() as Pos;
+        assert_eq!(lm.line_col_scalar(src, pos), (2, 3));
+    }
+}
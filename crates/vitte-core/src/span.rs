@@ -0,0 +1,61 @@
+/// A byte offset into a source file.
+pub type Pos = u32;
+
+/// A half-open `[start, end)` byte range into a source file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+pub struct Span {
+    pub start: Pos,
+    pub end: Pos,
+}
+
+impl Span {
+    /// A zero-width span at offset 0, used as a placeholder before a real
+    /// span is known.
+    pub const DUMMY: Span = Span { start: 0, end: 0 };
+
+    pub fn new(start: Pos, end: Pos) -> Self {
+        Self { start, end }
+    }
+
+    pub fn len(&self) -> u32 {
+        self.end.saturating_sub(self.start)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// The smallest span covering both `a` and `b`.
+    pub fn merge(a: Span, b: Span) -> Span {
+        Span::new(a.start.min(b.start), a.end.max(b.end))
+    }
+
+    /// Whether `pos` falls within `self`, treating `self` as the half-open
+    /// range `[start, end)` its doc comment describes.
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.start <= pos && pos < self.end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_covers_both_spans() {
+        let a = Span::new(4, 8);
+        let b = Span::new(2, 6);
+        assert_eq!(Span::merge(a, b), Span::new(2, 8));
+        // Order shouldn't matter.
+        assert_eq!(Span::merge(b, a), Span::new(2, 8));
+    }
+
+    #[test]
+    fn contains_includes_the_start_but_excludes_the_end() {
+        let span = Span::new(5, 10);
+        assert!(!span.contains(4));
+        assert!(span.contains(5));
+        assert!(span.contains(9));
+        assert!(!span.contains(10));
+    }
+}
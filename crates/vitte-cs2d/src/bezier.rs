@@ -0,0 +1,207 @@
+use crate::Vec2;
+
+/// Number of segments [`Bezier2::arc_length`] and [`Bezier3::arc_length`]
+/// divide the curve into by default, if the caller doesn't care to tune it.
+pub const DEFAULT_ARC_LENGTH_SAMPLES: usize = 32;
+
+/// A quadratic Bézier curve with control points `p0`, `p1`, `p2`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bezier2 {
+    pub p0: Vec2,
+    pub p1: Vec2,
+    pub p2: Vec2,
+}
+
+impl Bezier2 {
+    pub fn new(p0: Vec2, p1: Vec2, p2: Vec2) -> Self {
+        Self { p0, p1, p2 }
+    }
+
+    /// The point at parameter `t`, clamped to `[0, 1]` isn't required by the
+    /// caller — `t` outside that range extrapolates the curve.
+    pub fn eval(&self, t: f64) -> Vec2 {
+        let u = 1.0 - t;
+        self.p0 * (u * u) + self.p1 * (2.0 * u * t) + self.p2 * (t * t)
+    }
+
+    /// The curve's tangent (not normalized) at parameter `t`.
+    pub fn derivative(&self, t: f64) -> Vec2 {
+        let u = 1.0 - t;
+        (self.p1 - self.p0) * (2.0 * u) + (self.p2 - self.p1) * (2.0 * t)
+    }
+
+    /// Approximate arc length, via `samples` straight-line chords.
+    pub fn arc_length(&self, samples: usize) -> f64 {
+        arc_length(samples, |t| self.eval(t))
+    }
+}
+
+/// A cubic Bézier curve with control points `p0`, `p1`, `p2`, `p3`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bezier3 {
+    pub p0: Vec2,
+    pub p1: Vec2,
+    pub p2: Vec2,
+    pub p3: Vec2,
+}
+
+impl Bezier3 {
+    pub fn new(p0: Vec2, p1: Vec2, p2: Vec2, p3: Vec2) -> Self {
+        Self { p0, p1, p2, p3 }
+    }
+
+    pub fn eval(&self, t: f64) -> Vec2 {
+        let u = 1.0 - t;
+        self.p0 * (u * u * u) + self.p1 * (3.0 * u * u * t) + self.p2 * (3.0 * u * t * t) + self.p3 * (t * t * t)
+    }
+
+    /// The curve's tangent (not normalized) at parameter `t`.
+    pub fn derivative(&self, t: f64) -> Vec2 {
+        let u = 1.0 - t;
+        (self.p1 - self.p0) * (3.0 * u * u) + (self.p2 - self.p1) * (6.0 * u * t) + (self.p3 - self.p2) * (3.0 * t * t)
+    }
+
+    /// Approximate arc length, via `samples` straight-line chords.
+    pub fn arc_length(&self, samples: usize) -> f64 {
+        arc_length(samples, |t| self.eval(t))
+    }
+
+    /// Adaptively subdivides the curve into a polyline whose deviation from
+    /// the true curve stays under `tolerance`, measured as each segment's
+    /// midpoint-to-chord distance. Recurses until flat enough or a depth
+    /// limit is hit, to bound pathological `tolerance` values.
+    pub fn flatten(&self, tolerance: f64) -> Vec<Vec2> {
+        let mut points = vec![self.p0];
+        flatten_recursive(*self, tolerance, 0, &mut points);
+        points
+    }
+}
+
+const MAX_FLATTEN_DEPTH: u32 = 24;
+
+fn flatten_recursive(curve: Bezier3, tolerance: f64, depth: u32, out: &mut Vec<Vec2>) {
+    if depth >= MAX_FLATTEN_DEPTH || is_flat_enough(&curve, tolerance) {
+        out.push(curve.p3);
+        return;
+    }
+    let (left, right) = subdivide(&curve);
+    flatten_recursive(left, tolerance, depth + 1, out);
+    flatten_recursive(right, tolerance, depth + 1, out);
+}
+
+/// Whether `curve` deviates from its `p0`-`p3` chord by less than
+/// `tolerance`, checked at the two interior control points (a standard,
+/// cheap proxy for the curve's actual maximum deviation).
+fn is_flat_enough(curve: &Bezier3, tolerance: f64) -> bool {
+    point_line_distance(curve.p1, curve.p0, curve.p3) <= tolerance
+        && point_line_distance(curve.p2, curve.p0, curve.p3) <= tolerance
+}
+
+fn point_line_distance(p: Vec2, a: Vec2, b: Vec2) -> f64 {
+    let ab = b - a;
+    let len = ab.length();
+    if len == 0.0 {
+        return p.distance(a);
+    }
+    ((ab.x * (p.y - a.y) - ab.y * (p.x - a.x)) / len).abs()
+}
+
+/// Splits `curve` at `t = 0.5` via De Casteljau's algorithm.
+fn subdivide(curve: &Bezier3) -> (Bezier3, Bezier3) {
+    let p01 = midpoint(curve.p0, curve.p1);
+    let p12 = midpoint(curve.p1, curve.p2);
+    let p23 = midpoint(curve.p2, curve.p3);
+    let p012 = midpoint(p01, p12);
+    let p123 = midpoint(p12, p23);
+    let p0123 = midpoint(p012, p123);
+    (Bezier3::new(curve.p0, p01, p012, p0123), Bezier3::new(p0123, p123, p23, curve.p3))
+}
+
+fn midpoint(a: Vec2, b: Vec2) -> Vec2 {
+    (a + b) * 0.5
+}
+
+fn arc_length(samples: usize, eval: impl Fn(f64) -> Vec2) -> f64 {
+    if samples == 0 {
+        return 0.0;
+    }
+    let mut length = 0.0;
+    let mut prev = eval(0.0);
+    for i in 1..=samples {
+        let t = i as f64 / samples as f64;
+        let point = eval(t);
+        length += point.distance(prev);
+        prev = point;
+    }
+    length
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bezier2_endpoints_match_control_points() {
+        let curve = Bezier2::new(Vec2::new(0.0, 0.0), Vec2::new(5.0, 10.0), Vec2::new(10.0, 0.0));
+        assert_eq!(curve.eval(0.0), curve.p0);
+        assert_eq!(curve.eval(1.0), curve.p2);
+    }
+
+    #[test]
+    fn bezier2_arc_length_of_a_straight_layout_is_the_chord_length() {
+        let curve = Bezier2::new(Vec2::new(0.0, 0.0), Vec2::new(5.0, 0.0), Vec2::new(10.0, 0.0));
+        let length = curve.arc_length(DEFAULT_ARC_LENGTH_SAMPLES);
+        assert!((length - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn bezier3_endpoints_match_control_points() {
+        let curve = Bezier3::new(Vec2::new(0.0, 0.0), Vec2::new(2.0, 5.0), Vec2::new(8.0, 5.0), Vec2::new(10.0, 0.0));
+        assert_eq!(curve.eval(0.0), curve.p0);
+        assert_eq!(curve.eval(1.0), curve.p3);
+    }
+
+    #[test]
+    fn bezier3_arc_length_of_a_straight_layout_is_the_chord_length() {
+        let curve = Bezier3::new(Vec2::new(0.0, 0.0), Vec2::new(3.0, 0.0), Vec2::new(6.0, 0.0), Vec2::new(10.0, 0.0));
+        let length = curve.arc_length(DEFAULT_ARC_LENGTH_SAMPLES);
+        assert!((length - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn flatten_of_a_straight_layout_is_just_the_two_endpoints() {
+        let curve = Bezier3::new(Vec2::new(0.0, 0.0), Vec2::new(3.0, 0.0), Vec2::new(6.0, 0.0), Vec2::new(10.0, 0.0));
+        let points = curve.flatten(0.01);
+        assert_eq!(points, vec![curve.p0, curve.p3]);
+    }
+
+    fn point_segment_distance(p: Vec2, a: Vec2, b: Vec2) -> f64 {
+        let ab = b - a;
+        let len_sq = ab.length_sq();
+        if len_sq == 0.0 {
+            return p.distance(a);
+        }
+        let t = ((p - a).dot(ab) / len_sq).clamp(0.0, 1.0);
+        p.distance(a + ab * t)
+    }
+
+    #[test]
+    fn flatten_stays_close_to_the_curve_for_a_curved_layout() {
+        let curve = Bezier3::new(Vec2::new(0.0, 0.0), Vec2::new(0.0, 10.0), Vec2::new(10.0, 10.0), Vec2::new(10.0, 0.0));
+        let tolerance = 0.05;
+        let points = curve.flatten(tolerance);
+        assert!(points.len() > 2, "a curved layout should need more than just its endpoints");
+        assert_eq!(*points.first().unwrap(), curve.p0);
+        assert_eq!(*points.last().unwrap(), curve.p3);
+
+        // Every sampled point on the true curve should land close to some
+        // chord of the flattened polyline — `is_flat_enough`'s control-point
+        // proxy is a cheap approximation of max deviation, not exact, so
+        // give it some slack rather than asserting `<= tolerance`.
+        let max_deviation = (0..=200)
+            .map(|i| curve.eval(i as f64 / 200.0))
+            .map(|p| points.windows(2).map(|w| point_segment_distance(p, w[0], w[1])).fold(f64::INFINITY, f64::min))
+            .fold(0.0, f64::max);
+        assert!(max_deviation < tolerance * 4.0, "deviation {max_deviation} exceeds tolerance budget");
+    }
+}
@@ -0,0 +1,16 @@
+use crate::Segment;
+
+/// A line segment swept by a radius — a stadium shape. Common for character
+/// controllers, since unlike a rect it doesn't catch on floor seams. A
+/// zero-length `seg` degenerates to a circle of radius `r`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Capsule {
+    pub seg: Segment,
+    pub r: f64,
+}
+
+impl Capsule {
+    pub fn new(seg: Segment, r: f64) -> Self {
+        Self { seg, r }
+    }
+}
@@ -0,0 +1,13 @@
+use crate::Vec2;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Circle {
+    pub center: Vec2,
+    pub radius: f64,
+}
+
+impl Circle {
+    pub fn new(center: Vec2, radius: f64) -> Self {
+        Self { center, radius }
+    }
+}
@@ -0,0 +1,625 @@
+//! Narrow-phase collision tests between the shapes in this crate.
+
+use crate::{Capsule, Circle, Line, Polygon, Rect, Segment, Vec2, EPS};
+
+/// Parallel-determinant threshold for [`segment_intersection`], tighter
+/// than the crate-wide [`EPS`]: this one only needs to rule out a
+/// genuinely singular system, not absorb everyday rounding error, and a
+/// looser bound here would call more near-parallel segment pairs
+/// "intersecting" than actually are.
+const PARALLEL_EPS: f64 = 1e-12;
+
+fn overlap(a: (f64, f64), b: (f64, f64)) -> f64 {
+    a.1.min(b.1) - a.0.max(b.0)
+}
+
+/// Result of a ray hitting a shape: the entry `time` in units of the ray's
+/// (possibly un-normalized) direction, the contact `normal`, and `delta`, the
+/// displacement from the ray origin to the hit point (`dir * time`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hit {
+    pub time: f64,
+    pub normal: Vec2,
+    pub delta: Vec2,
+}
+
+/// Casts a ray from `origin` along `dir` (need not be normalized) against an
+/// axis-aligned `r`, via the slab method. Returns the entry hit, or `None` if
+/// the ray misses or points away from the rect. A ray starting inside `r`
+/// reports `time: 0.0` with a zero `normal` (there is no meaningful entry
+/// face).
+pub fn ray_rect(origin: Vec2, dir: Vec2, r: &Rect) -> Option<Hit> {
+    let mut t_near = f64::NEG_INFINITY;
+    let mut t_far = f64::INFINITY;
+    let mut normal = Vec2::ZERO;
+
+    let axes: [(f64, f64, f64, f64); 2] = [(origin.x, dir.x, r.min_x(), r.max_x()), (origin.y, dir.y, r.min_y(), r.max_y())];
+    for (axis, (o, d, min, max)) in axes.into_iter().enumerate() {
+        if d.abs() < f64::EPSILON {
+            if o < min || o > max {
+                return None;
+            }
+            continue;
+        }
+        let t1 = (min - o) / d;
+        let t2 = (max - o) / d;
+        let (near, far, hit_min_face) = if t1 <= t2 { (t1, t2, true) } else { (t2, t1, false) };
+        if near > t_near {
+            t_near = near;
+            let sign = if hit_min_face { -1.0 } else { 1.0 };
+            normal = if axis == 0 { Vec2::new(sign, 0.0) } else { Vec2::new(0.0, sign) };
+        }
+        t_far = t_far.min(far);
+        if t_near > t_far {
+            return None;
+        }
+    }
+    if t_far < 0.0 {
+        return None;
+    }
+    let time = t_near.max(0.0);
+    let normal = if t_near < 0.0 { Vec2::ZERO } else { normal };
+    Some(Hit { time, normal, delta: dir * time })
+}
+
+/// Casts a ray from `origin` along `dir` (need not be normalized) against
+/// `c`. Returns the entry hit, or `None` if the ray misses the circle or
+/// points away from it. A ray starting inside `c` reports `time: 0.0` with a
+/// zero `normal`.
+pub fn ray_circle(origin: Vec2, dir: Vec2, c: &Circle) -> Option<Hit> {
+    let to_origin = origin - c.center;
+    let a = dir.dot(dir);
+    let inside = to_origin.length_sq() <= c.radius * c.radius;
+    if a < f64::EPSILON {
+        return if inside { Some(Hit { time: 0.0, normal: Vec2::ZERO, delta: Vec2::ZERO }) } else { None };
+    }
+    let b = 2.0 * to_origin.dot(dir);
+    let cc = to_origin.length_sq() - c.radius * c.radius;
+    let discriminant = b * b - 4.0 * a * cc;
+    if discriminant < 0.0 {
+        return None;
+    }
+    let sqrt_d = discriminant.sqrt();
+    let t_near = (-b - sqrt_d) / (2.0 * a);
+    let t_far = (-b + sqrt_d) / (2.0 * a);
+    if t_far < 0.0 {
+        return None;
+    }
+    let time = t_near.max(0.0);
+    let normal = if t_near < 0.0 { Vec2::ZERO } else { (origin + dir * time - c.center).normalized().unwrap_or(Vec2::ZERO) };
+    Some(Hit { time, normal, delta: dir * time })
+}
+
+/// Sweeps `moving` along `vel` and finds the first time in `[0, 1]` at which
+/// it touches the stationary `target`. Equivalent to casting a ray from
+/// `moving.center` against a circle of radius `moving.radius + target.radius`
+/// centered at `target.center`, which also catches tunneling that a
+/// before/after overlap test would miss.
+pub fn sweep_circle(moving: &Circle, vel: Vec2, target: &Circle) -> Option<Hit> {
+    let combined = Circle::new(target.center, moving.radius + target.radius);
+    let hit = ray_circle(moving.center, vel, &combined)?;
+    if hit.time > 1.0 {
+        return None;
+    }
+    Some(hit)
+}
+
+/// Sweeps `moving` along `vel` against the stationary `target` rect, using
+/// the expanded-rect-plus-rounded-corners construction (the Minkowski sum of
+/// a rect and a circle). Flat-edge hits come from a ray cast against `target`
+/// inflated by `moving.radius`; corner hits come from sweeping `moving`
+/// against a zero-radius circle at each corner. The earliest valid hit wins.
+pub fn sweep_circle_rect(moving: &Circle, vel: Vec2, target: &Rect) -> Option<Hit> {
+    let expanded = Rect::new(
+        target.min_x() - moving.radius,
+        target.min_y() - moving.radius,
+        target.w + 2.0 * moving.radius,
+        target.h + 2.0 * moving.radius,
+    );
+
+    let mut best = ray_rect(moving.center, vel, &expanded).filter(|hit| {
+        if hit.time > 1.0 {
+            return false;
+        }
+        let point = moving.center + hit.delta;
+        (hit.normal.x != 0.0 && point.y >= target.min_y() && point.y <= target.max_y())
+            || (hit.normal.y != 0.0 && point.x >= target.min_x() && point.x <= target.max_x())
+    });
+
+    let corners = [
+        Vec2::new(target.min_x(), target.min_y()),
+        Vec2::new(target.max_x(), target.min_y()),
+        Vec2::new(target.max_x(), target.max_y()),
+        Vec2::new(target.min_x(), target.max_y()),
+    ];
+    for corner in corners {
+        if let Some(hit) = sweep_circle(moving, vel, &Circle::new(corner, 0.0)) {
+            if best.as_ref().is_none_or(|b| hit.time < b.time) {
+                best = Some(hit);
+            }
+        }
+    }
+    best
+}
+
+/// Separating Axis Theorem test between two convex polygons. Returns `false`
+/// for a degenerate polygon (fewer than 3 verts) rather than panicking.
+pub fn poly_poly(a: &Polygon, b: &Polygon) -> bool {
+    if a.verts.len() < 3 || b.verts.len() < 3 {
+        return false;
+    }
+    a.axes().into_iter().chain(b.axes()).all(|axis| overlap(a.project(axis), b.project(axis)) > 0.0)
+}
+
+/// Minimum translation vector that separates `a` from `b`, pointing from `b`
+/// towards `a`. `None` if the polygons don't overlap (or either is
+/// degenerate).
+pub fn poly_poly_mtv(a: &Polygon, b: &Polygon) -> Option<Vec2> {
+    if a.verts.len() < 3 || b.verts.len() < 3 {
+        return None;
+    }
+    let mut smallest_overlap = f64::INFINITY;
+    let mut smallest_axis = Vec2::ZERO;
+    for axis in a.axes().into_iter().chain(b.axes()) {
+        let depth = overlap(a.project(axis), b.project(axis));
+        if depth <= 0.0 {
+            return None;
+        }
+        if depth < smallest_overlap {
+            smallest_overlap = depth;
+            smallest_axis = axis;
+        }
+    }
+    let direction = a.centroid() - b.centroid();
+    let axis = if direction.dot(smallest_axis) < 0.0 { smallest_axis * -1.0 } else { smallest_axis };
+    Some(axis * smallest_overlap)
+}
+
+/// Whether `p` lies inside (or on the boundary of) a convex, CCW-wound
+/// polygon. Degenerate polygons contain nothing.
+pub fn contains_pt(poly: &Polygon, p: Vec2) -> bool {
+    let n = poly.verts.len();
+    if n < 3 {
+        return false;
+    }
+    (0..n).all(|i| {
+        let a = poly.verts[i];
+        let b = poly.verts[(i + 1) % n];
+        let edge = b - a;
+        let to_p = p - a;
+        edge.x * to_p.y - edge.y * to_p.x >= 0.0
+    })
+}
+
+fn point_segment_distance(p: Vec2, a: Vec2, b: Vec2) -> f64 {
+    let ab = b - a;
+    let len_sq = ab.length_sq();
+    if len_sq == 0.0 {
+        return p.distance(a);
+    }
+    let t = ((p - a).dot(ab) / len_sq).clamp(0.0, 1.0);
+    p.distance(a + ab * t)
+}
+
+/// Whether a circle overlaps a convex polygon: true if the center is inside
+/// the polygon, or any edge passes within `radius` of the center.
+pub fn poly_circle(poly: &Polygon, center: Vec2, radius: f64) -> bool {
+    let n = poly.verts.len();
+    if n < 3 {
+        return false;
+    }
+    if contains_pt(poly, center) {
+        return true;
+    }
+    (0..n).any(|i| point_segment_distance(center, poly.verts[i], poly.verts[(i + 1) % n]) <= radius)
+}
+
+fn point_on_segment(p: Vec2, a: Vec2, b: Vec2) -> bool {
+    let edge = b - a;
+    let cross = edge.x * (p.y - a.y) - edge.y * (p.x - a.x);
+    if cross.abs() > EPS {
+        return false;
+    }
+    let along = (p - a).dot(edge);
+    (0.0..=edge.length_sq()).contains(&along)
+}
+
+/// Whether `p` lies inside (or on the boundary of) `verts`, via the
+/// even-odd ray-casting rule. Unlike [`contains_pt`], this works for
+/// concave polygons. Points exactly on an edge always count as inside.
+pub fn point_in_poly(p: Vec2, verts: &[Vec2]) -> bool {
+    let n = verts.len();
+    if n < 3 {
+        return false;
+    }
+    if (0..n).any(|i| point_on_segment(p, verts[i], verts[(i + 1) % n])) {
+        return true;
+    }
+    let mut inside = false;
+    let mut j = n - 1;
+    for i in 0..n {
+        let vi = verts[i];
+        let vj = verts[j];
+        if (vi.y > p.y) != (vj.y > p.y) {
+            let x_intersect = vi.x + (p.y - vi.y) / (vj.y - vi.y) * (vj.x - vi.x);
+            if p.x < x_intersect {
+                inside = !inside;
+            }
+        }
+        j = i;
+    }
+    inside
+}
+
+fn segment_intersection(p1: Vec2, p2: Vec2, p3: Vec2, p4: Vec2) -> Option<f64> {
+    let d1 = p2 - p1;
+    let d2 = p4 - p3;
+    let denom = d1.x * d2.y - d1.y * d2.x;
+    if denom.abs() < PARALLEL_EPS {
+        return None;
+    }
+    let t = ((p3.x - p1.x) * d2.y - (p3.y - p1.y) * d2.x) / denom;
+    let u = ((p3.x - p1.x) * d1.y - (p3.y - p1.y) * d1.x) / denom;
+    if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u) { Some(t) } else { None }
+}
+
+/// Where infinite lines `a` and `b` cross, or `None` if they're parallel
+/// (including coincident). Unlike [`segment_poly`]'s segment-segment test,
+/// there are no endpoints to stay within.
+pub fn line_line(a: &Line, b: &Line) -> Option<Vec2> {
+    let denom = a.dir.x * b.dir.y - a.dir.y * b.dir.x;
+    if denom.abs() < EPS {
+        return None;
+    }
+    let diff = b.point - a.point;
+    let t = (diff.x * b.dir.y - diff.y * b.dir.x) / denom;
+    Some(a.point + a.dir * t)
+}
+
+/// Where rays `(o1, d1)` and `(o2, d2)` cross, as the `(t, u)` parameters
+/// along each ray's direction. `None` if the rays are parallel or the
+/// crossing point lies behind either origin (a negative parameter).
+pub fn ray_ray(o1: Vec2, d1: Vec2, o2: Vec2, d2: Vec2) -> Option<(f64, f64)> {
+    let denom = d1.x * d2.y - d1.y * d2.x;
+    if denom.abs() < EPS {
+        return None;
+    }
+    let diff = o2 - o1;
+    let t = (diff.x * d2.y - diff.y * d2.x) / denom;
+    let u = (diff.x * d1.y - diff.y * d1.x) / denom;
+    if t >= 0.0 && u >= 0.0 { Some((t, u)) } else { None }
+}
+
+/// The nearest point (smallest `t` in `[0, 1]`) at which `seg` crosses the
+/// boundary of `verts`, or `None` if it never does.
+pub fn segment_poly(seg: &Segment, verts: &[Vec2]) -> Option<f64> {
+    let n = verts.len();
+    if n < 3 {
+        return None;
+    }
+    (0..n)
+        .filter_map(|i| segment_intersection(seg.a, seg.b, verts[i], verts[(i + 1) % n]))
+        .fold(None, |nearest, t| match nearest {
+            Some(m) if m <= t => Some(m),
+            _ => Some(t),
+        })
+}
+
+fn segment_segment_distance(a1: Vec2, a2: Vec2, b1: Vec2, b2: Vec2) -> f64 {
+    if segment_intersection(a1, a2, b1, b2).is_some() {
+        return 0.0;
+    }
+    point_segment_distance(a1, b1, b2)
+        .min(point_segment_distance(a2, b1, b2))
+        .min(point_segment_distance(b1, a1, a2))
+        .min(point_segment_distance(b2, a1, a2))
+}
+
+/// Whether two capsules overlap: their skeleton segments come within the
+/// sum of their radii. Degenerate (zero-length) capsules fall out of this
+/// naturally, since [`point_segment_distance`] against a zero-length
+/// segment is just point-to-point distance.
+pub fn capsule_capsule(a: &Capsule, b: &Capsule) -> bool {
+    segment_segment_distance(a.seg.a, a.seg.b, b.seg.a, b.seg.b) <= a.r + b.r
+}
+
+/// Whether a capsule overlaps a circle: the capsule's skeleton segment comes
+/// within `capsule.r + circle.radius` of the circle's center.
+pub fn capsule_circle(capsule: &Capsule, circle: &Circle) -> bool {
+    point_segment_distance(circle.center, capsule.seg.a, capsule.seg.b) <= capsule.r + circle.radius
+}
+
+/// Whether a capsule overlaps an axis-aligned rect: either an endpoint of
+/// the capsule's skeleton lies inside the rect, or the skeleton comes
+/// within `capsule.r` of one of the rect's four edges.
+pub fn capsule_rect(capsule: &Capsule, rect: &Rect) -> bool {
+    if rect.contains_point(capsule.seg.a.x, capsule.seg.a.y) || rect.contains_point(capsule.seg.b.x, capsule.seg.b.y) {
+        return true;
+    }
+    let corners = [
+        Vec2::new(rect.min_x(), rect.min_y()),
+        Vec2::new(rect.max_x(), rect.min_y()),
+        Vec2::new(rect.max_x(), rect.max_y()),
+        Vec2::new(rect.min_x(), rect.max_y()),
+    ];
+    (0..4).any(|i| segment_segment_distance(capsule.seg.a, capsule.seg.b, corners[i], corners[(i + 1) % 4]) <= capsule.r)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangle(offset: Vec2) -> Polygon {
+        Polygon::new(vec![Vec2::new(0.0, 0.0) + offset, Vec2::new(2.0, 0.0) + offset, Vec2::new(1.0, 2.0) + offset])
+    }
+
+    #[test]
+    fn overlapping_triangles_collide() {
+        let a = triangle(Vec2::ZERO);
+        let b = triangle(Vec2::new(1.0, 1.0));
+        assert!(poly_poly(&a, &b));
+    }
+
+    #[test]
+    fn clearly_separated_triangles_do_not_collide() {
+        let a = triangle(Vec2::ZERO);
+        let b = triangle(Vec2::new(100.0, 100.0));
+        assert!(!poly_poly(&a, &b));
+    }
+
+    #[test]
+    fn degenerate_polygon_never_collides() {
+        let point = Polygon::new(vec![Vec2::ZERO, Vec2::new(1.0, 0.0)]);
+        let tri = triangle(Vec2::ZERO);
+        assert!(!poly_poly(&point, &tri));
+    }
+
+    #[test]
+    fn mtv_points_from_b_towards_a() {
+        let a = triangle(Vec2::new(1.0, 0.0));
+        let b = triangle(Vec2::ZERO);
+        let mtv = poly_poly_mtv(&a, &b).expect("triangles overlap");
+        let direction = a.centroid() - b.centroid();
+        assert!(mtv.dot(direction) > 0.0);
+    }
+
+    #[test]
+    fn circle_inside_polygon_collides() {
+        let square = Polygon::new(vec![Vec2::new(0.0, 0.0), Vec2::new(10.0, 0.0), Vec2::new(10.0, 10.0), Vec2::new(0.0, 10.0)]);
+        assert!(poly_circle(&square, Vec2::new(5.0, 5.0), 1.0));
+    }
+
+    #[test]
+    fn circle_far_from_polygon_does_not_collide() {
+        let square = Polygon::new(vec![Vec2::new(0.0, 0.0), Vec2::new(10.0, 0.0), Vec2::new(10.0, 10.0), Vec2::new(0.0, 10.0)]);
+        assert!(!poly_circle(&square, Vec2::new(500.0, 500.0), 1.0));
+    }
+
+    #[test]
+    fn ray_rect_direct_hit() {
+        let r = Rect::new(10.0, 0.0, 10.0, 10.0);
+        let hit = ray_rect(Vec2::new(0.0, 5.0), Vec2::new(1.0, 0.0), &r).expect("ray should hit");
+        assert!((hit.time - 10.0).abs() < 1e-9);
+        assert_eq!(hit.normal, Vec2::new(-1.0, 0.0));
+        assert_eq!(hit.delta, Vec2::new(10.0, 0.0));
+    }
+
+    #[test]
+    fn ray_rect_grazing_miss() {
+        let r = Rect::new(10.0, 0.0, 10.0, 10.0);
+        // Points parallel to the rect's y-slab but passing just above it.
+        let hit = ray_rect(Vec2::new(0.0, 20.0), Vec2::new(1.0, 0.0), &r);
+        assert!(hit.is_none());
+    }
+
+    #[test]
+    fn ray_rect_origin_inside() {
+        let r = Rect::new(0.0, 0.0, 10.0, 10.0);
+        let hit = ray_rect(Vec2::new(5.0, 5.0), Vec2::new(1.0, 0.0), &r).expect("origin is inside the rect");
+        assert_eq!(hit.time, 0.0);
+        assert_eq!(hit.normal, Vec2::ZERO);
+    }
+
+    #[test]
+    fn ray_circle_direct_hit() {
+        let c = Circle::new(Vec2::new(10.0, 0.0), 2.0);
+        let hit = ray_circle(Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0), &c).expect("ray should hit");
+        assert!((hit.time - 8.0).abs() < 1e-9);
+        assert!((hit.normal.x + 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn ray_circle_grazing_miss() {
+        let c = Circle::new(Vec2::new(10.0, 0.0), 2.0);
+        let hit = ray_circle(Vec2::new(0.0, 5.0), Vec2::new(1.0, 0.0), &c);
+        assert!(hit.is_none());
+    }
+
+    #[test]
+    fn ray_circle_origin_inside() {
+        let c = Circle::new(Vec2::new(0.0, 0.0), 5.0);
+        let hit = ray_circle(Vec2::new(1.0, 0.0), Vec2::new(1.0, 0.0), &c).expect("origin is inside the circle");
+        assert_eq!(hit.time, 0.0);
+        assert_eq!(hit.normal, Vec2::ZERO);
+    }
+
+    // A concave arrow/chevron pointing right, with a notch cut into its
+    // left side at (2, 2).
+    fn arrow() -> Vec<Vec2> {
+        vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(4.0, 2.0),
+            Vec2::new(2.0, 4.0),
+            Vec2::new(0.0, 4.0),
+            Vec2::new(2.0, 2.0),
+        ]
+    }
+
+    #[test]
+    fn point_in_poly_handles_concave_arrow() {
+        let verts = arrow();
+        assert!(point_in_poly(Vec2::new(2.0, 1.0), &verts), "this point sits in the solid body of the arrow");
+        assert!(!point_in_poly(Vec2::new(1.0, 2.5), &verts), "this point sits in the notch, outside the arrow");
+    }
+
+    #[test]
+    fn point_in_poly_counts_edge_points_as_inside() {
+        let verts = arrow();
+        assert!(point_in_poly(Vec2::new(1.0, 0.0), &verts));
+    }
+
+    #[test]
+    fn segment_poly_finds_nearest_entry_on_arrow() {
+        let verts = arrow();
+        let seg = Segment::new(Vec2::new(-2.0, 1.0), Vec2::new(2.5, 1.0));
+        let t = segment_poly(&seg, &verts).expect("segment clips the notch edge");
+        assert!((t - (2.0 / 3.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn segment_intersection_still_resolves_a_denom_between_parallel_eps_and_eps() {
+        // denom here is 1e-10: below the crate-wide EPS (1e-9) but well
+        // above PARALLEL_EPS (1e-12), so this must still be treated as a
+        // real (if nearly parallel) crossing, not bailed out as parallel.
+        let t = segment_intersection(Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0), Vec2::new(0.5, 0.0), Vec2::new(1.5, 1e-10));
+        assert_eq!(t, Some(0.5));
+    }
+
+    #[test]
+    fn segment_poly_misses_when_segment_never_crosses_the_boundary() {
+        let verts = arrow();
+        let seg = Segment::new(Vec2::new(10.0, 10.0), Vec2::new(20.0, 20.0));
+        assert_eq!(segment_poly(&seg, &verts), None);
+    }
+
+    #[test]
+    fn sweep_circle_head_on_impact() {
+        let moving = Circle::new(Vec2::new(0.0, 0.0), 1.0);
+        let target = Circle::new(Vec2::new(10.0, 0.0), 1.0);
+        // Would travel to x = 20 if unobstructed; the two circles actually
+        // touch once their centers are `2.0` apart, at x = 8.
+        let hit = sweep_circle(&moving, Vec2::new(20.0, 0.0), &target).expect("head-on sweep should hit");
+        assert!((hit.time - 0.4).abs() < 1e-9);
+        assert!((hit.normal.x + 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn sweep_circle_tunneling_case_is_still_caught() {
+        // A naive "overlap at start vs overlap at end" test would miss this:
+        // the circle is far from the target both before and after the
+        // step, but passes straight through it in between.
+        let moving = Circle::new(Vec2::new(-50.0, 0.0), 1.0);
+        let target = Circle::new(Vec2::new(0.0, 0.0), 1.0);
+        let hit = sweep_circle(&moving, Vec2::new(100.0, 0.0), &target).expect("fast-moving circle should still register a hit");
+        assert!(hit.time > 0.0 && hit.time < 1.0);
+    }
+
+    #[test]
+    fn sweep_circle_rect_edge_hit() {
+        let moving = Circle::new(Vec2::new(-5.0, 5.0), 1.0);
+        let target = Rect::new(0.0, 0.0, 10.0, 10.0);
+        let hit = sweep_circle_rect(&moving, Vec2::new(10.0, 0.0), &target).expect("should clip the left edge");
+        assert_eq!(hit.normal, Vec2::new(-1.0, 0.0));
+    }
+
+    #[test]
+    fn sweep_circle_rect_corner_clip() {
+        // Aimed to miss the expanded flat edges but clip the top-left
+        // rounded corner of the Minkowski sum.
+        let moving = Circle::new(Vec2::new(-5.0, -5.0), 1.0);
+        let target = Rect::new(0.0, 0.0, 10.0, 10.0);
+        let hit = sweep_circle_rect(&moving, Vec2::new(5.0, 5.0), &target).expect("should clip the corner");
+        let corner = Vec2::new(0.0, 0.0);
+        let contact = moving.center + hit.delta;
+        assert!((contact.distance(corner) - moving.radius).abs() < 1e-6);
+    }
+
+    #[test]
+    fn overlapping_capsules_collide() {
+        let a = Capsule::new(Segment::new(Vec2::new(0.0, 0.0), Vec2::new(10.0, 0.0)), 1.0);
+        let b = Capsule::new(Segment::new(Vec2::new(5.0, 1.5), Vec2::new(5.0, 5.0)), 1.0);
+        assert!(capsule_capsule(&a, &b));
+    }
+
+    #[test]
+    fn clearly_separated_capsules_do_not_collide() {
+        let a = Capsule::new(Segment::new(Vec2::new(0.0, 0.0), Vec2::new(10.0, 0.0)), 1.0);
+        let b = Capsule::new(Segment::new(Vec2::new(5.0, 100.0), Vec2::new(5.0, 105.0)), 1.0);
+        assert!(!capsule_capsule(&a, &b));
+    }
+
+    #[test]
+    fn a_capsule_just_touching_a_circle_collides() {
+        let capsule = Capsule::new(Segment::new(Vec2::new(0.0, 0.0), Vec2::new(10.0, 0.0)), 1.0);
+        // The circle's center sits `1.0 + 2.0 = 3.0` above the capsule's
+        // skeleton, exactly the sum of the two radii.
+        let circle = Circle::new(Vec2::new(5.0, 3.0), 2.0);
+        assert!(capsule_circle(&capsule, &circle));
+    }
+
+    #[test]
+    fn a_capsule_just_short_of_a_circle_does_not_collide() {
+        let capsule = Capsule::new(Segment::new(Vec2::new(0.0, 0.0), Vec2::new(10.0, 0.0)), 1.0);
+        let circle = Circle::new(Vec2::new(5.0, 3.1), 2.0);
+        assert!(!capsule_circle(&capsule, &circle));
+    }
+
+    #[test]
+    fn a_zero_length_capsule_matches_the_equivalent_circle() {
+        let point = Vec2::new(5.0, 5.0);
+        let capsule = Capsule::new(Segment::new(point, point), 2.0);
+        let circle = Circle::new(Vec2::new(6.0, 5.0), 2.5);
+        assert_eq!(capsule_circle(&capsule, &circle), circle_circle_overlap(point, 2.0, circle));
+    }
+
+    fn circle_circle_overlap(center: Vec2, radius: f64, other: Circle) -> bool {
+        center.distance(other.center) <= radius + other.radius
+    }
+
+    #[test]
+    fn a_capsule_overlapping_a_rect_collides() {
+        let capsule = Capsule::new(Segment::new(Vec2::new(-5.0, 5.0), Vec2::new(5.0, 5.0)), 1.0);
+        let rect = Rect::new(0.0, 0.0, 10.0, 10.0);
+        assert!(capsule_rect(&capsule, &rect));
+    }
+
+    #[test]
+    fn a_capsule_far_from_a_rect_does_not_collide() {
+        let capsule = Capsule::new(Segment::new(Vec2::new(-50.0, 5.0), Vec2::new(-40.0, 5.0)), 1.0);
+        let rect = Rect::new(0.0, 0.0, 10.0, 10.0);
+        assert!(!capsule_rect(&capsule, &rect));
+    }
+
+    #[test]
+    fn crossing_lines_meet_at_the_expected_point() {
+        let a = Line::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0));
+        let b = Line::new(Vec2::new(5.0, -5.0), Vec2::new(0.0, 1.0));
+        let point = line_line(&a, &b).expect("lines should cross");
+        assert!(point.approx_eq(Vec2::new(5.0, 0.0), 1e-9));
+    }
+
+    #[test]
+    fn parallel_lines_never_meet() {
+        let a = Line::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0));
+        let b = Line::new(Vec2::new(0.0, 1.0), Vec2::new(2.0, 0.0));
+        assert_eq!(line_line(&a, &b), None);
+    }
+
+    #[test]
+    fn ray_ray_finds_an_intersection_ahead_of_both_origins() {
+        let (t, u) = ray_ray(Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0), Vec2::new(5.0, -5.0), Vec2::new(0.0, 1.0))
+            .expect("rays should cross ahead of both origins");
+        assert!((t - 5.0).abs() < 1e-9);
+        assert!((u - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn ray_ray_behind_one_origin_does_not_count() {
+        // The lines through these rays cross at (5, 0), which is ahead of
+        // the first ray but behind the second, since its direction points
+        // away from the crossing point.
+        let hit = ray_ray(Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0), Vec2::new(5.0, 5.0), Vec2::new(0.0, 1.0));
+        assert_eq!(hit, None);
+    }
+}
@@ -0,0 +1,90 @@
+use crate::Vec2;
+
+/// Computes the convex hull of `points` via Andrew's monotone chain
+/// algorithm, returning the hull vertices in CCW order — ready to hand
+/// straight to [`crate::Polygon::new`] for SAT collision tests. Duplicate
+/// points collapse to one; collinear points lying on an edge are excluded,
+/// leaving only the turning vertices. Inputs with fewer than 3 distinct
+/// points are returned as-is.
+pub fn convex_hull(points: &[Vec2]) -> Vec<Vec2> {
+    let mut sorted: Vec<Vec2> = points.to_vec();
+    sorted.sort_by(|a, b| a.x.partial_cmp(&b.x).unwrap().then(a.y.partial_cmp(&b.y).unwrap()));
+    sorted.dedup();
+
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    let mut lower: Vec<Vec2> = Vec::new();
+    for &p in &sorted {
+        while lower.len() >= 2 && cross(lower[lower.len() - 2], lower[lower.len() - 1], p) <= 0.0 {
+            lower.pop();
+        }
+        lower.push(p);
+    }
+
+    let mut upper: Vec<Vec2> = Vec::new();
+    for &p in sorted.iter().rev() {
+        while upper.len() >= 2 && cross(upper[upper.len() - 2], upper[upper.len() - 1], p) <= 0.0 {
+            upper.pop();
+        }
+        upper.push(p);
+    }
+
+    lower.pop();
+    upper.pop();
+    lower.extend(upper);
+    lower
+}
+
+/// The (signed) z-component of `(a - o) x (b - o)`: positive for a
+/// counter-clockwise turn at `a`, negative for clockwise, zero if collinear.
+fn cross(o: Vec2, a: Vec2, b: Vec2) -> f64 {
+    (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{collide, Polygon};
+
+    #[test]
+    fn a_square_with_interior_points_returns_just_the_corners() {
+        let points = vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(10.0, 0.0),
+            Vec2::new(10.0, 10.0),
+            Vec2::new(0.0, 10.0),
+            Vec2::new(5.0, 5.0),
+            Vec2::new(3.0, 7.0),
+        ];
+        let hull = convex_hull(&points);
+        assert_eq!(hull.len(), 4);
+        for corner in [Vec2::new(0.0, 0.0), Vec2::new(10.0, 0.0), Vec2::new(10.0, 10.0), Vec2::new(0.0, 10.0)] {
+            assert!(hull.contains(&corner), "{corner:?} missing from hull {hull:?}");
+        }
+    }
+
+    #[test]
+    fn collinear_points_exclude_the_interior_ones() {
+        let points = vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0), Vec2::new(2.0, 0.0), Vec2::new(3.0, 0.0)];
+        let hull = convex_hull(&points);
+        assert_eq!(hull, vec![Vec2::new(0.0, 0.0), Vec2::new(3.0, 0.0)]);
+    }
+
+    #[test]
+    fn degenerate_inputs_are_returned_as_is() {
+        assert_eq!(convex_hull(&[]), Vec::<Vec2>::new());
+        assert_eq!(convex_hull(&[Vec2::new(1.0, 1.0)]), vec![Vec2::new(1.0, 1.0)]);
+        let two = vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0)];
+        assert_eq!(convex_hull(&two), two);
+    }
+
+    #[test]
+    fn hull_feeds_directly_into_a_polygon_for_sat_collision() {
+        let points = vec![Vec2::new(0.0, 0.0), Vec2::new(10.0, 0.0), Vec2::new(10.0, 10.0), Vec2::new(0.0, 10.0), Vec2::new(5.0, 5.0)];
+        let poly = Polygon::new(convex_hull(&points));
+        let overlapping = Polygon::new(vec![Vec2::new(5.0, 5.0), Vec2::new(15.0, 5.0), Vec2::new(15.0, 15.0), Vec2::new(5.0, 15.0)]);
+        assert!(collide::poly_poly(&poly, &overlapping));
+    }
+}
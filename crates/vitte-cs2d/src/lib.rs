@@ -0,0 +1,36 @@
+//! 2D collision and spatial-query primitives for Vitte games.
+
+pub mod collide;
+mod bezier;
+mod capsule;
+mod circle;
+mod hull;
+mod line;
+mod mat3;
+mod path;
+mod polygon;
+mod quadtree;
+mod rect;
+mod rng;
+mod segment;
+mod spatial_hash;
+mod sweep_prune;
+mod transform2d;
+mod vec2;
+
+pub use bezier::{Bezier2, Bezier3, DEFAULT_ARC_LENGTH_SAMPLES};
+pub use capsule::Capsule;
+pub use circle::Circle;
+pub use hull::convex_hull;
+pub use line::Line;
+pub use mat3::Mat3;
+pub use path::{AStarOptions, Grid};
+pub use polygon::Polygon;
+pub use quadtree::QuadTree;
+pub use rect::Rect;
+pub use rng::Lcg;
+pub use segment::Segment;
+pub use spatial_hash::SpatialHashGrid;
+pub use sweep_prune::SweepPrune;
+pub use transform2d::Transform2D;
+pub use vec2::{Vec2, EPS};
@@ -0,0 +1,16 @@
+use crate::Vec2;
+
+/// An infinite line through `point` in direction `dir` (need not be
+/// normalized). Unlike [`crate::Segment`], a `Line` has no endpoints, so
+/// intersection tests against it ignore parameter bounds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Line {
+    pub point: Vec2,
+    pub dir: Vec2,
+}
+
+impl Line {
+    pub fn new(point: Vec2, dir: Vec2) -> Self {
+        Self { point, dir }
+    }
+}
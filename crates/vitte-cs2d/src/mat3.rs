@@ -0,0 +1,74 @@
+use crate::Vec2;
+
+/// A 3x3 matrix used to represent 2D affine transforms. Every matrix this
+/// crate constructs keeps an implicit `[0, 0, 1]` bottom row, though the
+/// storage itself doesn't enforce that.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mat3 {
+    pub m: [[f64; 3]; 3],
+}
+
+impl Mat3 {
+    pub const IDENTITY: Mat3 = Mat3 { m: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]] };
+
+    pub fn from_translation(t: Vec2) -> Self {
+        Mat3 { m: [[1.0, 0.0, t.x], [0.0, 1.0, t.y], [0.0, 0.0, 1.0]] }
+    }
+
+    pub fn from_rotation(radians: f64) -> Self {
+        let (s, c) = radians.sin_cos();
+        Mat3 { m: [[c, -s, 0.0], [s, c, 0.0], [0.0, 0.0, 1.0]] }
+    }
+
+    pub fn from_scale(s: Vec2) -> Self {
+        Mat3 { m: [[s.x, 0.0, 0.0], [0.0, s.y, 0.0], [0.0, 0.0, 1.0]] }
+    }
+
+    pub fn mul(&self, other: &Mat3) -> Mat3 {
+        let mut out = [[0.0; 3]; 3];
+        for (i, row) in out.iter_mut().enumerate() {
+            for (j, cell) in row.iter_mut().enumerate() {
+                *cell = (0..3).map(|k| self.m[i][k] * other.m[k][j]).sum();
+            }
+        }
+        Mat3 { m: out }
+    }
+
+    /// Decomposes an affine TRS matrix (as built by [`crate::Transform2D::matrix`])
+    /// into translation, rotation (radians), and scale, assuming the bottom
+    /// row is `[0, 0, 1]`. A negative determinant (a reflection) is folded
+    /// into the y scale component, so `scale.y` may come back negative.
+    pub fn decompose(&self) -> (Vec2, f64, Vec2) {
+        let (m00, m01) = (self.m[0][0], self.m[0][1]);
+        let (m10, m11) = (self.m[1][0], self.m[1][1]);
+        let translation = Vec2::new(self.m[0][2], self.m[1][2]);
+
+        let det = m00 * m11 - m01 * m10;
+        let sx = (m00 * m00 + m10 * m10).sqrt();
+        let mut sy = (m01 * m01 + m11 * m11).sqrt();
+        if det < 0.0 {
+            sy = -sy;
+        }
+        let rotation = m10.atan2(m00);
+        (translation, rotation, Vec2::new(sx, sy))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identity_decomposes_to_zero_rotation_and_unit_scale() {
+        let (t, r, s) = Mat3::IDENTITY.decompose();
+        assert_eq!(t, Vec2::ZERO);
+        assert_eq!(r, 0.0);
+        assert_eq!(s, Vec2::new(1.0, 1.0));
+    }
+
+    #[test]
+    fn mul_by_identity_is_a_no_op() {
+        let m = Mat3::from_translation(Vec2::new(3.0, 4.0));
+        assert_eq!(m.mul(&Mat3::IDENTITY), m);
+    }
+}
@@ -0,0 +1,349 @@
+//! Grid pathfinding.
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// Options controlling [`Grid::astar_opts`].
+#[derive(Debug, Clone, Default)]
+pub struct AStarOptions {
+    /// Allow 8-connectivity (with an octile heuristic) instead of just the
+    /// four orthogonal neighbors. Diagonal moves that would cut across a
+    /// blocked corner are never taken, regardless of this flag.
+    pub diagonal: bool,
+    /// Per-cell entry cost, indexed the same way as the grid (`y * width +
+    /// x`). Cells missing from a short vector cost `1`.
+    pub weights: Option<Vec<u32>>,
+    /// Stop and report failure once this many nodes have been expanded,
+    /// instead of searching the whole grid.
+    pub max_expanded: Option<usize>,
+}
+
+#[derive(Copy, Clone, PartialEq)]
+struct OpenNode {
+    cost: f64,
+    idx: usize,
+}
+
+impl Eq for OpenNode {}
+
+impl PartialOrd for OpenNode {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OpenNode {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed so `BinaryHeap` (a max-heap) pops the lowest cost first.
+        other.cost.total_cmp(&self.cost)
+    }
+}
+
+/// A rectangular grid of walkable/blocked cells with A* pathfinding.
+#[derive(Debug, Clone)]
+pub struct Grid {
+    pub width: usize,
+    pub height: usize,
+    blocked: Vec<bool>,
+}
+
+impl Grid {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self { width, height, blocked: vec![false; width * height] }
+    }
+
+    pub fn set_blocked(&mut self, x: usize, y: usize, blocked: bool) {
+        let idx = self.index(x, y);
+        self.blocked[idx] = blocked;
+    }
+
+    pub fn is_blocked(&self, x: usize, y: usize) -> bool {
+        self.blocked[self.index(x, y)]
+    }
+
+    fn index(&self, x: usize, y: usize) -> usize {
+        y * self.width + x
+    }
+
+    fn in_bounds(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// Shortest path over 4-connected, unit-cost cells. A thin wrapper over
+    /// [`Grid::astar_opts`] with default options.
+    pub fn astar(&self, sx: usize, sy: usize, tx: usize, ty: usize) -> Option<Vec<(usize, usize)>> {
+        self.astar_opts(sx, sy, tx, ty, &AStarOptions::default())
+    }
+
+    /// Shortest path from `(sx, sy)` to `(tx, ty)`, honoring `opts`. Returns
+    /// `None` if the endpoints are out of bounds, blocked, or no path is
+    /// found within `opts.max_expanded` node expansions.
+    pub fn astar_opts(&self, sx: usize, sy: usize, tx: usize, ty: usize, opts: &AStarOptions) -> Option<Vec<(usize, usize)>> {
+        if !self.in_bounds(sx, sy) || !self.in_bounds(tx, ty) || self.is_blocked(sx, sy) || self.is_blocked(tx, ty) {
+            return None;
+        }
+
+        let n = self.width * self.height;
+        let start = self.index(sx, sy);
+        let goal = self.index(tx, ty);
+
+        let mut g_cost = vec![f64::INFINITY; n];
+        let mut came_from = vec![usize::MAX; n];
+        let mut visited = vec![false; n];
+        let mut open = BinaryHeap::new();
+
+        g_cost[start] = 0.0;
+        open.push(OpenNode { cost: self.heuristic(sx, sy, tx, ty, opts.diagonal), idx: start });
+
+        let mut expanded = 0usize;
+        while let Some(OpenNode { idx, .. }) = open.pop() {
+            if visited[idx] {
+                continue;
+            }
+            visited[idx] = true;
+            if idx == goal {
+                return Some(self.reconstruct_path(&came_from, goal));
+            }
+
+            expanded += 1;
+            if opts.max_expanded.is_some_and(|max| expanded > max) {
+                return None;
+            }
+
+            let (x, y) = (idx % self.width, idx / self.width);
+            for (nx, ny, diagonal) in self.neighbors(x, y, opts.diagonal) {
+                let n_idx = self.index(nx, ny);
+                if visited[n_idx] {
+                    continue;
+                }
+                let weight = opts.weights.as_ref().and_then(|w| w.get(n_idx)).copied().unwrap_or(1) as f64;
+                let step_cost = if diagonal { std::f64::consts::SQRT_2 } else { 1.0 } * weight;
+                let tentative = g_cost[idx] + step_cost;
+                if tentative < g_cost[n_idx] {
+                    g_cost[n_idx] = tentative;
+                    came_from[n_idx] = idx;
+                    let priority = tentative + self.heuristic(nx, ny, tx, ty, opts.diagonal);
+                    open.push(OpenNode { cost: priority, idx: n_idx });
+                }
+            }
+        }
+        None
+    }
+
+    fn heuristic(&self, x: usize, y: usize, tx: usize, ty: usize, diagonal: bool) -> f64 {
+        let dx = (x as isize - tx as isize).unsigned_abs() as f64;
+        let dy = (y as isize - ty as isize).unsigned_abs() as f64;
+        if diagonal {
+            let diag = std::f64::consts::SQRT_2;
+            dx.max(dy) - dx.min(dy) + diag * dx.min(dy)
+        } else {
+            dx + dy
+        }
+    }
+
+    fn offset(&self, x: usize, y: usize, dx: isize, dy: isize) -> Option<(usize, usize)> {
+        let nx = x as isize + dx;
+        let ny = y as isize + dy;
+        if nx < 0 || ny < 0 {
+            return None;
+        }
+        let (nx, ny) = (nx as usize, ny as usize);
+        self.in_bounds(nx, ny).then_some((nx, ny))
+    }
+
+    /// In-bounds, unblocked neighbors of `(x, y)`, each tagged with whether
+    /// the move is diagonal. Diagonal moves that would cut across a blocked
+    /// corner (both orthogonal cells adjacent to the move are blocked) are
+    /// never yielded.
+    fn neighbors(&self, x: usize, y: usize, diagonal: bool) -> Vec<(usize, usize, bool)> {
+        let mut result = Vec::new();
+        for (dx, dy) in [(1, 0), (-1, 0), (0, 1), (0, -1)] {
+            if let Some((nx, ny)) = self.offset(x, y, dx, dy) {
+                if !self.is_blocked(nx, ny) {
+                    result.push((nx, ny, false));
+                }
+            }
+        }
+        if diagonal {
+            for (dx, dy) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+                let Some((nx, ny)) = self.offset(x, y, dx, dy) else { continue };
+                if self.is_blocked(nx, ny) {
+                    continue;
+                }
+                let (ox1, oy1) = (x as isize + dx, y as isize);
+                let (ox2, oy2) = (x as isize, y as isize + dy);
+                if ox1 < 0 || oy2 < 0 {
+                    continue;
+                }
+                let (corner_a, corner_b) = ((ox1 as usize, oy1 as usize), (ox2 as usize, oy2 as usize));
+                if !self.in_bounds(corner_a.0, corner_a.1) || !self.in_bounds(corner_b.0, corner_b.1) {
+                    continue;
+                }
+                if self.is_blocked(corner_a.0, corner_a.1) || self.is_blocked(corner_b.0, corner_b.1) {
+                    continue;
+                }
+                result.push((nx, ny, true));
+            }
+        }
+        result
+    }
+
+    /// Whether every cell on the Bresenham line from `(x0, y0)` to `(x1,
+    /// y1)` (inclusive of both endpoints) is walkable. Used for AI
+    /// visibility checks and by [`Grid::smooth_path`] to skip unnecessary
+    /// waypoints.
+    pub fn line_of_sight(&self, x0: usize, y0: usize, x1: usize, y1: usize) -> bool {
+        if !self.in_bounds(x0, y0) || !self.in_bounds(x1, y1) {
+            return false;
+        }
+        let (mut x, mut y) = (x0 as isize, y0 as isize);
+        let (x1, y1) = (x1 as isize, y1 as isize);
+        let dx = (x1 - x).abs();
+        let dy = -(y1 - y).abs();
+        let sx = if x < x1 { 1 } else { -1 };
+        let sy = if y < y1 { 1 } else { -1 };
+        let mut err = dx + dy;
+
+        loop {
+            if self.is_blocked(x as usize, y as usize) {
+                return false;
+            }
+            if x == x1 && y == y1 {
+                return true;
+            }
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                x += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                y += sy;
+            }
+        }
+    }
+
+    /// Removes waypoints from `path` that a straight, unobstructed line can
+    /// skip over, turning a zig-zagging A* result into something closer to
+    /// the shortest walkable route. Keeps the first and last waypoint.
+    pub fn smooth_path(&self, path: &[(usize, usize)]) -> Vec<(usize, usize)> {
+        if path.len() <= 2 {
+            return path.to_vec();
+        }
+        let mut smoothed = vec![path[0]];
+        let mut anchor = 0;
+        let mut probe = 2;
+        while probe < path.len() {
+            let (ax, ay) = path[anchor];
+            let (px, py) = path[probe];
+            if !self.line_of_sight(ax, ay, px, py) {
+                smoothed.push(path[probe - 1]);
+                anchor = probe - 1;
+            }
+            probe += 1;
+        }
+        smoothed.push(path[path.len() - 1]);
+        smoothed
+    }
+
+    fn reconstruct_path(&self, came_from: &[usize], goal: usize) -> Vec<(usize, usize)> {
+        let mut indices = vec![goal];
+        let mut current = goal;
+        while came_from[current] != usize::MAX {
+            current = came_from[current];
+            indices.push(current);
+        }
+        indices.reverse();
+        indices.into_iter().map(|idx| (idx % self.width, idx / self.width)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unit_cost_path_on_open_grid() {
+        let grid = Grid::new(3, 3);
+        let path = grid.astar(0, 0, 2, 0).expect("open grid has a path");
+        assert_eq!(path, vec![(0, 0), (1, 0), (2, 0)]);
+    }
+
+    #[test]
+    fn diagonal_movement_takes_a_shortcut() {
+        let grid = Grid::new(3, 3);
+        let orthogonal = grid.astar(0, 0, 2, 2).expect("orthogonal path exists");
+        let diagonal = grid.astar_opts(0, 0, 2, 2, &AStarOptions { diagonal: true, ..Default::default() }).expect("diagonal path exists");
+        assert_eq!(orthogonal.len(), 5);
+        assert_eq!(diagonal.len(), 3);
+    }
+
+    #[test]
+    fn weighted_detour_avoids_expensive_region() {
+        let mut weights = vec![1u32; 9];
+        weights[4] = 100; // center cell, (1, 1)
+        let grid = Grid::new(3, 3);
+        let opts = AStarOptions { weights: Some(weights), ..Default::default() };
+        let path = grid.astar_opts(0, 1, 2, 1, &opts).expect("path exists");
+        assert!(!path.contains(&(1, 1)), "path should detour around the expensive center cell: {path:?}");
+    }
+
+    #[test]
+    fn diagonals_cannot_cut_a_blocked_corner() {
+        let mut grid = Grid::new(3, 3);
+        grid.set_blocked(1, 0, true);
+        grid.set_blocked(0, 1, true);
+        let opts = AStarOptions { diagonal: true, ..Default::default() };
+        // (0, 0) -> (1, 1) is diagonally adjacent and itself unblocked, but
+        // both cells flanking that corner are blocked, so it must not be
+        // reachable via the direct diagonal hop.
+        assert_eq!(grid.astar_opts(0, 0, 1, 1, &opts), None);
+    }
+
+    #[test]
+    fn max_expanded_budget_aborts_search() {
+        let grid = Grid::new(20, 20);
+        let opts = AStarOptions { max_expanded: Some(1), ..Default::default() };
+        assert_eq!(grid.astar_opts(0, 0, 19, 19, &opts), None);
+    }
+
+    #[test]
+    fn a_clear_diagonal_has_line_of_sight() {
+        let grid = Grid::new(10, 10);
+        assert!(grid.line_of_sight(0, 0, 9, 9));
+    }
+
+    #[test]
+    fn a_wall_blocks_line_of_sight() {
+        let mut grid = Grid::new(10, 10);
+        for y in 0..10 {
+            grid.set_blocked(5, y, true);
+        }
+        assert!(!grid.line_of_sight(0, 5, 9, 5));
+    }
+
+    #[test]
+    fn smoothing_shortens_a_zig_zag_path_when_sightlines_allow() {
+        let grid = Grid::new(5, 5);
+        // A staircase a real A* search might return on an open grid, even
+        // though a straight diagonal line is entirely walkable.
+        let zig_zag = vec![(0, 0), (1, 0), (1, 1), (2, 1), (2, 2), (3, 2), (3, 3), (4, 3), (4, 4)];
+        let smoothed = grid.smooth_path(&zig_zag);
+        assert!(smoothed.len() < zig_zag.len());
+        assert_eq!(smoothed.first(), Some(&(0, 0)));
+        assert_eq!(smoothed.last(), Some(&(4, 4)));
+    }
+
+    #[test]
+    fn smoothing_keeps_a_detour_around_a_wall() {
+        let mut grid = Grid::new(5, 5);
+        for y in 0..4 {
+            grid.set_blocked(2, y, true);
+        }
+        let path = grid.astar(0, 0, 4, 0).expect("path exists around the wall");
+        let smoothed = grid.smooth_path(&path);
+        assert!(smoothed.iter().all(|&(x, y)| !grid.is_blocked(x, y)));
+        assert_eq!(smoothed.first(), Some(&(0, 0)));
+        assert_eq!(smoothed.last(), Some(&(4, 0)));
+    }
+}
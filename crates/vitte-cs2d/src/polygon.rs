@@ -0,0 +1,59 @@
+use crate::Vec2;
+
+/// A convex, counter-clockwise-wound polygon. Collision routines for it live
+/// in [`crate::collide`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polygon {
+    pub verts: Vec<Vec2>,
+}
+
+impl Polygon {
+    pub fn new(verts: Vec<Vec2>) -> Self {
+        Self { verts }
+    }
+
+    /// Outward-facing unit normals of each edge, in the same order as the
+    /// edges themselves. Degenerate polygons (fewer than 3 verts) yield no
+    /// axes at all.
+    pub(crate) fn axes(&self) -> Vec<Vec2> {
+        let n = self.verts.len();
+        if n < 3 {
+            return Vec::new();
+        }
+        (0..n)
+            .map(|i| {
+                let edge = self.verts[(i + 1) % n] - self.verts[i];
+                Vec2::new(-edge.y, edge.x).normalized().unwrap_or(Vec2::ZERO)
+            })
+            .collect()
+    }
+
+    pub(crate) fn project(&self, axis: Vec2) -> (f64, f64) {
+        self.verts.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+            let p = v.dot(axis);
+            (min.min(p), max.max(p))
+        })
+    }
+
+    pub(crate) fn centroid(&self) -> Vec2 {
+        let sum = self.verts.iter().fold(Vec2::ZERO, |acc, v| acc + *v);
+        sum * (1.0 / self.verts.len() as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn square_axes_are_axis_aligned() {
+        let square = Polygon::new(vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0), Vec2::new(1.0, 1.0), Vec2::new(0.0, 1.0)]);
+        assert_eq!(square.axes().len(), 4);
+    }
+
+    #[test]
+    fn degenerate_polygon_has_no_axes() {
+        let line = Polygon::new(vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0)]);
+        assert!(line.axes().is_empty());
+    }
+}
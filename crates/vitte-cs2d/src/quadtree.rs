@@ -0,0 +1,145 @@
+use crate::Rect;
+
+struct Node<T> {
+    bounds: Rect,
+    depth: usize,
+    items: Vec<(Rect, T)>,
+    children: Option<Box<[Node<T>; 4]>>,
+}
+
+impl<T: Copy> Node<T> {
+    fn new(bounds: Rect, depth: usize) -> Self {
+        Self { bounds, depth, items: Vec::new(), children: None }
+    }
+
+    fn quadrants(&self) -> [Rect; 4] {
+        let hw = self.bounds.w / 2.0;
+        let hh = self.bounds.h / 2.0;
+        let x = self.bounds.x;
+        let y = self.bounds.y;
+        [
+            Rect::new(x, y, hw, hh),
+            Rect::new(x + hw, y, hw, hh),
+            Rect::new(x, y + hh, hw, hh),
+            Rect::new(x + hw, y + hh, hw, hh),
+        ]
+    }
+
+    fn subdivide(&mut self) {
+        let quadrants = self.quadrants();
+        let depth = self.depth + 1;
+        let mut children = Box::new(quadrants.map(|q| Node::new(q, depth)));
+        let items = std::mem::take(&mut self.items);
+        for (rect, value) in items {
+            match children.iter_mut().find(|c| c.bounds.contains_rect(&rect)) {
+                Some(child) => child.items.push((rect, value)),
+                None => self.items.push((rect, value)),
+            }
+        }
+        self.children = Some(children);
+    }
+
+    fn insert(&mut self, rect: Rect, value: T, max_depth: usize, capacity: usize) {
+        if let Some(children) = &mut self.children {
+            if let Some(child) = children.iter_mut().find(|c| c.bounds.contains_rect(&rect)) {
+                child.insert(rect, value, max_depth, capacity);
+                return;
+            }
+            // Straddles a child boundary: stays at this node.
+            self.items.push((rect, value));
+            return;
+        }
+
+        self.items.push((rect, value));
+        if self.items.len() > capacity && self.depth < max_depth {
+            self.subdivide();
+        }
+    }
+
+    fn query<'a>(&'a self, area: &Rect, out: &mut Vec<&'a T>) {
+        if !self.bounds.intersects(area) {
+            return;
+        }
+        for (rect, value) in &self.items {
+            if rect.intersects(area) {
+                out.push(value);
+            }
+        }
+        if let Some(children) = &self.children {
+            for child in children.iter() {
+                child.query(area, out);
+            }
+        }
+    }
+}
+
+/// A region-query tree that subdivides nodes on insert once they exceed
+/// `capacity`, down to `max_depth`. Items that straddle a child boundary are
+/// kept at the parent rather than duplicated into every overlapping child.
+pub struct QuadTree<T> {
+    root: Node<T>,
+    max_depth: usize,
+    capacity: usize,
+}
+
+impl<T: Copy> QuadTree<T> {
+    pub fn new(bounds: Rect, max_depth: usize, capacity: usize) -> Self {
+        Self { root: Node::new(bounds, 0), max_depth, capacity }
+    }
+
+    pub fn insert(&mut self, id: T, bounds: Rect) {
+        self.root.insert(bounds, id, self.max_depth, self.capacity);
+    }
+
+    pub fn query(&self, area: &Rect) -> Vec<&T> {
+        let mut out = Vec::new();
+        self.root.query(area, &mut out);
+        out
+    }
+
+    pub fn clear(&mut self) {
+        self.root = Node::new(self.root.bounds, 0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clustered_tree() -> QuadTree<u32> {
+        let mut tree = QuadTree::new(Rect::new(0.0, 0.0, 1000.0, 1000.0), 8, 16);
+        for i in 0..1000u32 {
+            // Cluster everything into a 50x50 region near the origin.
+            let x = (i % 50) as f64;
+            let y = (i / 50) as f64;
+            tree.insert(i, Rect::new(x, y, 1.0, 1.0));
+        }
+        // One far-away outlier that should never show up in a near-origin query.
+        tree.insert(9999, Rect::new(900.0, 900.0, 1.0, 1.0));
+        tree
+    }
+
+    #[test]
+    fn small_query_returns_only_nearby_ids() {
+        let tree = clustered_tree();
+        let found = tree.query(&Rect::new(0.0, 0.0, 5.0, 5.0));
+        assert!(!found.is_empty());
+        assert!(found.iter().all(|&&id| id != 9999));
+    }
+
+    #[test]
+    fn query_is_deterministic_across_runs() {
+        let tree = clustered_tree();
+        let area = Rect::new(10.0, 10.0, 20.0, 20.0);
+        let first: Vec<u32> = tree.query(&area).into_iter().copied().collect();
+        let second: Vec<u32> = tree.query(&area).into_iter().copied().collect();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn clear_empties_the_tree() {
+        let mut tree = clustered_tree();
+        tree.clear();
+        assert!(tree.query(&Rect::new(0.0, 0.0, 1000.0, 1000.0)).is_empty());
+    }
+}
@@ -0,0 +1,67 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rect {
+    pub x: f64,
+    pub y: f64,
+    pub w: f64,
+    pub h: f64,
+}
+
+impl Rect {
+    pub fn new(x: f64, y: f64, w: f64, h: f64) -> Self {
+        Self { x, y, w, h }
+    }
+
+    pub fn min_x(&self) -> f64 {
+        self.x
+    }
+    pub fn min_y(&self) -> f64 {
+        self.y
+    }
+    pub fn max_x(&self) -> f64 {
+        self.x + self.w
+    }
+    pub fn max_y(&self) -> f64 {
+        self.y + self.h
+    }
+
+    pub fn contains_point(&self, x: f64, y: f64) -> bool {
+        x >= self.min_x() && x <= self.max_x() && y >= self.min_y() && y <= self.max_y()
+    }
+
+    /// Whether `other` fits entirely within `self`.
+    pub fn contains_rect(&self, other: &Rect) -> bool {
+        other.min_x() >= self.min_x() && other.max_x() <= self.max_x() && other.min_y() >= self.min_y() && other.max_y() <= self.max_y()
+    }
+
+    pub fn intersects(&self, other: &Rect) -> bool {
+        self.min_x() < other.max_x() && self.max_x() > other.min_x() && self.min_y() < other.max_y() && self.max_y() > other.min_y()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overlapping_rects_intersect() {
+        let a = Rect::new(0.0, 0.0, 10.0, 10.0);
+        let b = Rect::new(5.0, 5.0, 10.0, 10.0);
+        assert!(a.intersects(&b));
+    }
+
+    #[test]
+    fn touching_edges_do_not_intersect() {
+        let a = Rect::new(0.0, 0.0, 10.0, 10.0);
+        let b = Rect::new(10.0, 0.0, 10.0, 10.0);
+        assert!(!a.intersects(&b));
+    }
+
+    #[test]
+    fn contains_rect() {
+        let outer = Rect::new(0.0, 0.0, 100.0, 100.0);
+        let inner = Rect::new(10.0, 10.0, 5.0, 5.0);
+        let straddling = Rect::new(95.0, 95.0, 10.0, 10.0);
+        assert!(outer.contains_rect(&inner));
+        assert!(!outer.contains_rect(&straddling));
+    }
+}
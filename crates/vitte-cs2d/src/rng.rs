@@ -0,0 +1,107 @@
+/// A fast, non-cryptographic xorshift64* pseudo-random generator. Every
+/// nonzero seed cycles through all `2^64 - 1` nonzero states before
+/// repeating.
+pub struct Lcg {
+    state: u64,
+}
+
+impl Lcg {
+    /// A seed of `0` would get stuck at `0` forever, so it's remapped to a
+    /// fixed nonzero constant instead.
+    pub fn new(seed: u64) -> Self {
+        Self { state: if seed == 0 { 0x9E37_79B9_7F4A_7C15 } else { seed } }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// A float in `[0, 1]`. Because this divides by `u64::MAX`, rounding at
+    /// `f32` precision means it can return exactly `1.0`; use
+    /// [`Lcg::next_f32_open`] when that endpoint must be excluded.
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u64() as f64 / u64::MAX as f64) as f32
+    }
+
+    /// A float strictly in `[0, 1)`, built from a 24-bit mantissa that can
+    /// never reach the divisor.
+    pub fn next_f32_open(&mut self) -> f32 {
+        let bits = (self.next_u64() >> 40) as u32;
+        bits as f32 / (1u32 << 24) as f32
+    }
+
+    /// An unbiased integer in `[lo, hi)`, via rejection sampling against the
+    /// largest multiple of the span that fits in a `u64`.
+    pub fn range_i32(&mut self, lo: i32, hi: i32) -> i32 {
+        assert!(lo < hi, "range_i32: lo must be < hi");
+        let span = (hi as i64 - lo as i64) as u64;
+        let zone = u64::MAX - (u64::MAX % span);
+        loop {
+            let x = self.next_u64();
+            if x < zone {
+                return lo + (x % span) as i32;
+            }
+        }
+    }
+
+    /// Shuffles `items` in place using Fisher-Yates.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.range_i32(0, (i + 1) as i32) as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_five_outputs_of_seed_42_are_pinned() {
+        let mut rng = Lcg::new(42);
+        let outputs: Vec<u64> = (0..5).map(|_| rng.next_u64()).collect();
+        assert_eq!(
+            outputs,
+            vec![620241905386665794, 10789630473491264163, 14863822668605138130, 3436753063373280456, 14372772721324857530]
+        );
+    }
+
+    #[test]
+    fn zero_seed_is_remapped_to_a_nonzero_state() {
+        let mut rng = Lcg::new(0);
+        assert_ne!(rng.next_u64(), 0);
+    }
+
+    #[test]
+    fn next_f32_open_never_reaches_one() {
+        let mut rng = Lcg::new(7);
+        for _ in 0..10_000 {
+            assert!(rng.next_f32_open() < 1.0);
+        }
+    }
+
+    #[test]
+    fn range_i32_stays_within_bounds() {
+        let mut rng = Lcg::new(123);
+        for _ in 0..10_000 {
+            let n = rng.range_i32(-5, 5);
+            assert!((-5..5).contains(&n));
+        }
+    }
+
+    #[test]
+    fn shuffle_preserves_the_multiset() {
+        let mut rng = Lcg::new(99);
+        let mut items: Vec<i32> = (0..20).collect();
+        rng.shuffle(&mut items);
+        let mut sorted = items.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, (0..20).collect::<Vec<_>>());
+    }
+}
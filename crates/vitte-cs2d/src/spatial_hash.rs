@@ -0,0 +1,142 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use crate::{Rect, Vec2};
+
+/// A uniform grid mapping cells to the items whose bounds overlap them.
+/// Cheap to build and query but wasteful when items cluster unevenly across
+/// the world; [`crate::QuadTree`] adapts to that case instead.
+pub struct SpatialHashGrid<T> {
+    cell_size: f64,
+    cells: HashMap<(i64, i64), Vec<(Rect, T)>>,
+}
+
+impl<T: Copy + Eq + Hash> SpatialHashGrid<T> {
+    pub fn new(cell_size: f64) -> Self {
+        Self { cell_size, cells: HashMap::new() }
+    }
+
+    fn cell_of(&self, x: f64, y: f64) -> (i64, i64) {
+        ((x / self.cell_size).floor() as i64, (y / self.cell_size).floor() as i64)
+    }
+
+    fn cells_for(&self, rect: &Rect) -> impl Iterator<Item = (i64, i64)> {
+        let (min_cx, min_cy) = self.cell_of(rect.min_x(), rect.min_y());
+        let (max_cx, max_cy) = self.cell_of(rect.max_x(), rect.max_y());
+        (min_cx..=max_cx).flat_map(move |cx| (min_cy..=max_cy).map(move |cy| (cx, cy)))
+    }
+
+    pub fn insert(&mut self, rect: Rect, value: T) {
+        for cell in self.cells_for(&rect) {
+            self.cells.entry(cell).or_default().push((rect, value));
+        }
+    }
+
+    /// Removes `id`, previously inserted as a single point, from its cell.
+    pub fn remove_point(&mut self, id: &T, point: Vec2) {
+        let cell = self.cell_of(point.x, point.y);
+        self.remove_from_cell(cell, id);
+    }
+
+    /// Removes `id` from every bucket `rect` covers. Buckets left empty are
+    /// pruned so they don't linger as dead entries in the map.
+    pub fn remove_rect(&mut self, id: &T, rect: Rect) {
+        for cell in self.cells_for(&rect).collect::<Vec<_>>() {
+            self.remove_from_cell(cell, id);
+        }
+    }
+
+    fn remove_from_cell(&mut self, cell: (i64, i64), id: &T) {
+        let Some(bucket) = self.cells.get_mut(&cell) else { return };
+        bucket.retain(|(_, v)| v != id);
+        if bucket.is_empty() {
+            self.cells.remove(&cell);
+        }
+    }
+
+    /// Moves `id` from `old` to `new`, touching only the cells `old` and
+    /// `new` actually cover rather than rebuilding the whole grid.
+    pub fn reinsert_rect(&mut self, id: &T, old: Rect, new: Rect) {
+        let old_cells: HashSet<_> = self.cells_for(&old).collect();
+        let new_cells: HashSet<_> = self.cells_for(&new).collect();
+        for cell in old_cells.union(&new_cells) {
+            self.remove_from_cell(*cell, id);
+            if new_cells.contains(cell) {
+                self.cells.entry(*cell).or_default().push((new, *id));
+            }
+        }
+    }
+
+    pub fn query(&self, area: &Rect) -> Vec<&T> {
+        let mut seen = HashSet::new();
+        let mut out = Vec::new();
+        for cell in self.cells_for(area) {
+            let Some(bucket) = self.cells.get(&cell) else { continue };
+            for (rect, id) in bucket {
+                if rect.intersects(area) && seen.insert(*id) {
+                    out.push(id);
+                }
+            }
+        }
+        out
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_finds_only_overlapping_items() {
+        let mut grid = SpatialHashGrid::new(10.0);
+        grid.insert(Rect::new(0.0, 0.0, 5.0, 5.0), "near");
+        grid.insert(Rect::new(500.0, 500.0, 5.0, 5.0), "far");
+        let found = grid.query(&Rect::new(0.0, 0.0, 10.0, 10.0));
+        assert_eq!(found, vec![&"near"]);
+    }
+
+    #[test]
+    fn clear_removes_everything() {
+        let mut grid = SpatialHashGrid::new(10.0);
+        grid.insert(Rect::new(0.0, 0.0, 5.0, 5.0), 1);
+        grid.clear();
+        assert!(grid.query(&Rect::new(0.0, 0.0, 5.0, 5.0)).is_empty());
+    }
+
+    #[test]
+    fn remove_point_deletes_from_the_owning_cell() {
+        let mut grid = SpatialHashGrid::new(10.0);
+        grid.insert(Rect::new(0.0, 0.0, 1.0, 1.0), 1u32);
+        grid.remove_point(&1, Vec2::new(0.5, 0.5));
+        assert!(grid.query(&Rect::new(0.0, 0.0, 1.0, 1.0)).is_empty());
+    }
+
+    #[test]
+    fn reinsert_rect_moves_an_entity_by_one_cell() {
+        let mut grid = SpatialHashGrid::new(10.0);
+        let other = Rect::new(90.0, 90.0, 1.0, 1.0);
+        grid.insert(other, 2u32);
+        let old = Rect::new(0.0, 0.0, 1.0, 1.0);
+        grid.insert(old, 1u32);
+        let new = Rect::new(10.0, 0.0, 1.0, 1.0);
+
+        grid.reinsert_rect(&1, old, new);
+
+        assert!(grid.query(&old).is_empty(), "old cell should no longer contain the moved entity");
+        assert_eq!(grid.query(&new), vec![&1]);
+        assert_eq!(grid.query(&other), vec![&2], "unrelated bucket must be untouched by the move");
+    }
+
+    #[test]
+    fn empty_buckets_are_pruned_after_removal() {
+        let mut grid = SpatialHashGrid::new(10.0);
+        let rect = Rect::new(0.0, 0.0, 1.0, 1.0);
+        grid.insert(rect, 1u32);
+        grid.remove_rect(&1, rect);
+        assert_eq!(grid.cells.len(), 0);
+    }
+}
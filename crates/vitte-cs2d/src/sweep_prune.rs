@@ -0,0 +1,128 @@
+use crate::Rect;
+
+/// A sweep-and-prune broad phase: sorts bodies along the x axis and scans
+/// once to find every pair whose x extents overlap. Cheaper than
+/// [`crate::SpatialHashGrid`] when bodies are spread thinly along one axis
+/// rather than clustered in a 2D region, since there's no cell grid to size.
+///
+/// `pairs()` only checks the x axis, so its output is a superset of the
+/// actually-overlapping pairs — confirm each with a full test (e.g.
+/// [`crate::Rect::intersects`]) before treating it as a real collision.
+pub struct SweepPrune<Id> {
+    bodies: Vec<(Id, Rect)>,
+    /// Indices into `bodies`, kept sorted by `min_x`.
+    order: Vec<usize>,
+}
+
+impl<Id: Copy + Eq> SweepPrune<Id> {
+    pub fn new(bodies: &[(Id, Rect)]) -> Self {
+        let bodies = bodies.to_vec();
+        let mut order: Vec<usize> = (0..bodies.len()).collect();
+        order.sort_by(|&a, &b| bodies[a].1.min_x().partial_cmp(&bodies[b].1.min_x()).unwrap());
+        Self { bodies, order }
+    }
+
+    /// Updates `id`'s rect in place, then restores sort order via insertion
+    /// sort. Cheap when `id` only moved a little, since `order` starts
+    /// nearly sorted — the common case frame to frame.
+    pub fn update(&mut self, id: Id, rect: Rect) {
+        let Some(idx) = self.bodies.iter().position(|(existing, _)| *existing == id) else { return };
+        self.bodies[idx].1 = rect;
+        self.resort();
+    }
+
+    fn resort(&mut self) {
+        for i in 1..self.order.len() {
+            let mut j = i;
+            while j > 0 && self.bodies[self.order[j - 1]].1.min_x() > self.bodies[self.order[j]].1.min_x() {
+                self.order.swap(j - 1, j);
+                j -= 1;
+            }
+        }
+    }
+
+    /// Candidate overlapping pairs, found via the x-axis sweep alone.
+    pub fn pairs(&self) -> Vec<(Id, Id)> {
+        let mut out = Vec::new();
+        let mut active: Vec<usize> = Vec::new();
+        for &idx in &self.order {
+            let rect = self.bodies[idx].1;
+            active.retain(|&other| self.bodies[other].1.max_x() >= rect.min_x());
+            for &other in &active {
+                out.push((self.bodies[other].0, self.bodies[idx].0));
+            }
+            active.push(idx);
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Lcg;
+    use std::collections::HashSet;
+
+    fn brute_force_pairs(bodies: &[(u32, Rect)]) -> HashSet<(u32, u32)> {
+        let mut out = HashSet::new();
+        for i in 0..bodies.len() {
+            for j in (i + 1)..bodies.len() {
+                let (a, b) = (&bodies[i], &bodies[j]);
+                if a.1.intersects(&b.1) {
+                    out.insert(if a.0 < b.0 { (a.0, b.0) } else { (b.0, a.0) });
+                }
+            }
+        }
+        out
+    }
+
+    fn random_bodies(seed: u64, n: usize) -> Vec<(u32, Rect)> {
+        let mut rng = Lcg::new(seed);
+        (0..n as u32)
+            .map(|id| {
+                let x = rng.range_i32(0, 100) as f64;
+                let y = rng.range_i32(0, 100) as f64;
+                let w = rng.range_i32(1, 20) as f64;
+                let h = rng.range_i32(1, 20) as f64;
+                (id, Rect::new(x, y, w, h))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn pair_set_matches_a_brute_force_check() {
+        let bodies = random_bodies(7, 50);
+        let sweep = SweepPrune::new(&bodies);
+        let candidates: HashSet<(u32, u32)> =
+            sweep.pairs().into_iter().map(|(a, b)| if a < b { (a, b) } else { (b, a) }).collect();
+
+        let bodies_map: std::collections::HashMap<u32, Rect> = bodies.iter().copied().collect();
+        let confirmed: HashSet<(u32, u32)> = candidates
+            .into_iter()
+            .filter(|&(a, b)| bodies_map[&a].intersects(&bodies_map[&b]))
+            .collect();
+
+        assert_eq!(confirmed, brute_force_pairs(&bodies));
+    }
+
+    #[test]
+    fn update_keeps_pairs_correct_after_a_move() {
+        let mut bodies = random_bodies(11, 20);
+        let mut sweep = SweepPrune::new(&bodies);
+
+        let moved_id = bodies[0].0;
+        let new_rect = Rect::new(500.0, 500.0, 5.0, 5.0);
+        bodies[0].1 = new_rect;
+        sweep.update(moved_id, new_rect);
+
+        let candidates: HashSet<(u32, u32)> =
+            sweep.pairs().into_iter().map(|(a, b)| if a < b { (a, b) } else { (b, a) }).collect();
+        let bodies_map: std::collections::HashMap<u32, Rect> = bodies.iter().copied().collect();
+        let confirmed: HashSet<(u32, u32)> = candidates
+            .into_iter()
+            .filter(|&(a, b)| bodies_map[&a].intersects(&bodies_map[&b]))
+            .collect();
+
+        assert_eq!(confirmed, brute_force_pairs(&bodies));
+    }
+}
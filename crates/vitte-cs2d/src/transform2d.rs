@@ -0,0 +1,43 @@
+use crate::{Mat3, Vec2};
+
+/// A 2D affine transform: translation, rotation (radians), and a
+/// (possibly-negative, for reflections) per-axis scale. [`Mat3::decompose`]
+/// is the inverse of [`Transform2D::matrix`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform2D {
+    pub translation: Vec2,
+    pub rotation: f64,
+    pub scale: Vec2,
+}
+
+impl Transform2D {
+    pub fn new(translation: Vec2, rotation: f64, scale: Vec2) -> Self {
+        Self { translation, rotation, scale }
+    }
+
+    /// Composes `T · R · S` into a single [`Mat3`].
+    pub fn matrix(&self) -> Mat3 {
+        Mat3::from_translation(self.translation).mul(&Mat3::from_rotation(self.rotation)).mul(&Mat3::from_scale(self.scale))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decomposing_a_composed_trs_reproduces_its_inputs() {
+        let original = Transform2D::new(Vec2::new(5.0, -3.0), std::f64::consts::FRAC_PI_4, Vec2::new(2.0, 3.0));
+        let (translation, rotation, scale) = original.matrix().decompose();
+        assert!((translation - original.translation).length() < 1e-9);
+        assert!((rotation - original.rotation).abs() < 1e-9);
+        assert!((scale - original.scale).length() < 1e-9);
+    }
+
+    #[test]
+    fn negative_scale_is_recovered_via_the_determinant_sign() {
+        let original = Transform2D::new(Vec2::new(0.0, 0.0), 0.3, Vec2::new(2.0, -2.0));
+        let (_, _, scale) = original.matrix().decompose();
+        assert!((scale - original.scale).length() < 1e-9);
+    }
+}
@@ -0,0 +1,189 @@
+use std::ops::{Add, Mul, Sub};
+
+/// Default tolerance for approximate comparisons throughout this crate
+/// (segment/polygon edge tests, ray-cast epsilon checks, [`Vec2::approx_eq`]).
+/// `1e-9` comfortably absorbs `f64` rounding error from the chained
+/// multiply/sqrt/atan2 sequences these geometry routines use without masking
+/// real differences at the scales games typically work at.
+pub const EPS: f64 = 1e-9;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Vec2 {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2 { x: 0.0, y: 0.0 };
+
+    pub fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+
+    pub fn dot(self, other: Vec2) -> f64 {
+        self.x * other.x + self.y * other.y
+    }
+
+    pub fn length_sq(self) -> f64 {
+        self.dot(self)
+    }
+
+    pub fn length(self) -> f64 {
+        self.length_sq().sqrt()
+    }
+
+    pub fn distance(self, other: Vec2) -> f64 {
+        (self - other).length()
+    }
+
+    /// Returns `None` for a zero-length vector, where direction is undefined.
+    pub fn normalized(self) -> Option<Vec2> {
+        let len = self.length();
+        if len == 0.0 {
+            None
+        } else {
+            Some(Vec2::new(self.x / len, self.y / len))
+        }
+    }
+
+    /// The direction of this vector, in radians, measured from the positive
+    /// x axis (`atan2(y, x)`).
+    pub fn angle(self) -> f64 {
+        self.y.atan2(self.x)
+    }
+
+    /// The direction, in radians, from `self` towards `other`.
+    pub fn angle_to(self, other: Vec2) -> f64 {
+        (other - self).angle()
+    }
+
+    /// Builds a unit vector pointing `radians` from the positive x axis.
+    pub fn from_angle(radians: f64) -> Vec2 {
+        Vec2::new(radians.cos(), radians.sin())
+    }
+
+    /// The signed angle, in radians, to rotate `a` onto `b`.
+    pub fn angle_between(a: Vec2, b: Vec2) -> f64 {
+        (a.x * b.y - a.y * b.x).atan2(a.dot(b))
+    }
+
+    /// The component of `self` that lies along `axis`. Zero if `axis` is a
+    /// zero vector.
+    pub fn project_onto(self, axis: Vec2) -> Vec2 {
+        let len_sq = axis.length_sq();
+        if len_sq == 0.0 {
+            return Vec2::ZERO;
+        }
+        axis * (self.dot(axis) / len_sq)
+    }
+
+    /// Reflects `self` off a surface with the given `normal`. `normal` need
+    /// not be normalized.
+    pub fn reflect(self, normal: Vec2) -> Vec2 {
+        let Some(n) = normal.normalized() else {
+            return self;
+        };
+        self - n * (2.0 * self.dot(n))
+    }
+
+    /// Whether `self` and `other` are within `eps` of each other on both
+    /// axes. Use [`EPS`] for a reasonable default tolerance.
+    pub fn approx_eq(self, other: Vec2, eps: f64) -> bool {
+        (self.x - other.x).abs() <= eps && (self.y - other.y).abs() <= eps
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+    fn add(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+    fn sub(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Mul<f64> for Vec2 {
+    type Output = Vec2;
+    fn mul(self, rhs: f64) -> Vec2 {
+        Vec2::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn length_of_3_4_is_5() {
+        assert_eq!(Vec2::new(3.0, 4.0).length(), 5.0);
+    }
+
+    #[test]
+    fn zero_vector_has_no_normalized_form() {
+        assert_eq!(Vec2::ZERO.normalized(), None);
+    }
+
+    #[test]
+    fn normalized_vector_has_unit_length() {
+        let n = Vec2::new(3.0, 4.0).normalized().unwrap();
+        assert!((n.length() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn add_sub_scale() {
+        let a = Vec2::new(1.0, 2.0);
+        let b = Vec2::new(3.0, 4.0);
+        assert_eq!(a + b, Vec2::new(4.0, 6.0));
+        assert_eq!(b - a, Vec2::new(2.0, 2.0));
+        assert_eq!(a * 2.0, Vec2::new(2.0, 4.0));
+    }
+
+    #[test]
+    fn from_angle_quarter_turn_is_unit_y() {
+        let v = Vec2::from_angle(std::f64::consts::FRAC_PI_2);
+        assert!((v.x).abs() < 1e-9);
+        assert!((v.y - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn reflect_off_vertical_normal_flips_x() {
+        let v = Vec2::new(1.0, 1.0);
+        let reflected = v.reflect(Vec2::new(1.0, 0.0));
+        assert!((reflected.x + 1.0).abs() < 1e-9);
+        assert!((reflected.y - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn project_diagonal_onto_x_axis() {
+        let v = Vec2::new(1.0, 1.0);
+        let projected = v.project_onto(Vec2::new(1.0, 0.0));
+        assert!((projected.x - 1.0).abs() < 1e-9);
+        assert!(projected.y.abs() < 1e-9);
+    }
+
+    #[test]
+    fn angle_to_points_towards_target() {
+        let a = Vec2::new(0.0, 0.0);
+        let b = Vec2::new(0.0, 5.0);
+        assert!((a.angle_to(b) - std::f64::consts::FRAC_PI_2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn angle_between_perpendicular_vectors_is_quarter_turn() {
+        let a = Vec2::new(1.0, 0.0);
+        let b = Vec2::new(0.0, 1.0);
+        assert!((Vec2::angle_between(a, b) - std::f64::consts::FRAC_PI_2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn approx_eq_tolerates_differences_within_eps_but_not_beyond() {
+        let a = Vec2::new(1.0, 1.0);
+        assert!(a.approx_eq(Vec2::new(1.0 + EPS / 2.0, 1.0), EPS));
+        assert!(!a.approx_eq(Vec2::new(1.0 + EPS * 10.0, 1.0), EPS));
+    }
+}
@@ -0,0 +1,152 @@
+//! A minimal in-process debug controller for the Vitte VM: a [`Debugger`]
+//! resolves breakpoints against a compiled program's VITBC `LINE` table and
+//! implements [`Session`] so the VM can ask, at each bytecode offset,
+//! whether to keep running.
+
+use std::collections::HashSet;
+
+use vitte_vitbc::{Bytecode, LineEntry};
+
+/// What the VM should do after reaching a given bytecode offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebugAction {
+    Continue,
+    Step,
+    Break,
+}
+
+/// Something the VM reports its current bytecode offset to, to find out
+/// whether it should keep running.
+pub trait Session {
+    fn on_line(&mut self, offset: u32) -> DebugAction;
+}
+
+/// Breakpoints keyed by source line, resolved against a compiled program's
+/// debug `LINE` table so they can be checked against bytecode offsets as
+/// the VM runs.
+pub struct Debugger {
+    lines: Vec<LineEntry>,
+    breakpoints: HashSet<u32>,
+    stepping: bool,
+}
+
+impl Debugger {
+    /// Parses `bytecode` as a VITBC container and reads its `LINE` table.
+    /// A container with no debug info (or one that fails to parse at all)
+    /// still produces a usable `Debugger` — just one with nothing to
+    /// resolve breakpoints against, reflected in [`Debugger::is_available`].
+    pub fn new(bytecode: &[u8]) -> Self {
+        let lines = Bytecode::from_bytes(bytecode).map(|bc| bc.lines).unwrap_or_default();
+        Self { lines, breakpoints: HashSet::new(), stepping: false }
+    }
+
+    /// Whether this program carries debug info to resolve breakpoints
+    /// against. A debugger built from a stripped or malformed container
+    /// always reports `false`, since a breakpoint on it could never fire.
+    pub fn is_available(&self) -> bool {
+        !self.lines.is_empty()
+    }
+
+    pub fn add_breakpoint(&mut self, line: u32) {
+        self.breakpoints.insert(line);
+    }
+
+    pub fn remove_breakpoint(&mut self, line: u32) {
+        self.breakpoints.remove(&line);
+    }
+
+    /// Arms a single step: the next [`Session::on_line`] call reports
+    /// [`DebugAction::Step`] instead of [`DebugAction::Continue`], unless a
+    /// breakpoint takes priority.
+    pub fn step(&mut self) {
+        self.stepping = true;
+    }
+
+    fn line_at(&self, offset: u32) -> Option<u32> {
+        self.lines.iter().find(|entry| entry.offset == offset).map(|entry| entry.line)
+    }
+}
+
+impl Session for Debugger {
+    /// Reports `Break` if `offset` maps to a source line with a breakpoint
+    /// set, `Step` if a single step was armed via [`Debugger::step`] and no
+    /// breakpoint took priority, otherwise `Continue`.
+    fn on_line(&mut self, offset: u32) -> DebugAction {
+        if let Some(line) = self.line_at(offset) {
+            if self.breakpoints.contains(&line) {
+                self.stepping = false;
+                return DebugAction::Break;
+            }
+        }
+
+        if self.stepping {
+            self.stepping = false;
+            return DebugAction::Step;
+        }
+
+        DebugAction::Continue
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn program_with_lines(lines: Vec<LineEntry>) -> Vec<u8> {
+        Bytecode { lines, ..Bytecode::default() }.to_bytes()
+    }
+
+    #[test]
+    fn a_breakpoint_on_a_known_line_breaks_at_its_offset() {
+        let bytecode = program_with_lines(vec![
+            LineEntry { offset: 0, line: 1, column: 1 },
+            LineEntry { offset: 3, line: 2, column: 1 },
+        ]);
+        let mut debugger = Debugger::new(&bytecode);
+        debugger.add_breakpoint(2);
+
+        assert_eq!(debugger.on_line(0), DebugAction::Continue);
+        assert_eq!(debugger.on_line(3), DebugAction::Break);
+    }
+
+    #[test]
+    fn removing_a_breakpoint_lets_the_line_run_through() {
+        let bytecode = program_with_lines(vec![LineEntry { offset: 0, line: 1, column: 1 }]);
+        let mut debugger = Debugger::new(&bytecode);
+        debugger.add_breakpoint(1);
+        debugger.remove_breakpoint(1);
+
+        assert_eq!(debugger.on_line(0), DebugAction::Continue);
+    }
+
+    #[test]
+    fn step_arms_exactly_one_step() {
+        let bytecode = program_with_lines(vec![
+            LineEntry { offset: 0, line: 1, column: 1 },
+            LineEntry { offset: 1, line: 2, column: 1 },
+        ]);
+        let mut debugger = Debugger::new(&bytecode);
+        debugger.step();
+
+        assert_eq!(debugger.on_line(0), DebugAction::Step);
+        assert_eq!(debugger.on_line(1), DebugAction::Continue);
+    }
+
+    #[test]
+    fn a_program_without_debug_info_is_unavailable() {
+        let debugger = Debugger::new(&Bytecode::default().to_bytes());
+        assert!(!debugger.is_available());
+    }
+
+    #[test]
+    fn a_program_with_debug_info_is_available() {
+        let bytecode = program_with_lines(vec![LineEntry { offset: 0, line: 1, column: 1 }]);
+        assert!(Debugger::new(&bytecode).is_available());
+    }
+
+    #[test]
+    fn a_malformed_container_is_unavailable_rather_than_panicking() {
+        let debugger = Debugger::new(b"not a vitbc container");
+        assert!(!debugger.is_available());
+    }
+}
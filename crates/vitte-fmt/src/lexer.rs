@@ -0,0 +1,124 @@
+//! A tolerant tokenizer for the formatter: it never fails, and on input it
+//! doesn't recognize it just emits a one-byte [`TokKind::Punct`] token and
+//! keeps going. This is enough structure for [`crate::format_source`] to
+//! lay out whitespace and indentation without needing a real parse.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokKind {
+    Id,
+    Num,
+    Str,
+    Comment,
+    Punct,
+}
+
+/// A single lexeme, carrying the exact source slice it came from so the
+/// printer can reproduce identifiers, numbers, and strings verbatim.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tok {
+    pub kind: TokKind,
+    pub text: String,
+}
+
+fn is_ident_start(c: char) -> bool {
+    c == '_' || c.is_alphabetic()
+}
+
+fn is_ident_continue(c: char) -> bool {
+    c == '_' || c.is_alphanumeric()
+}
+
+/// Tokenize `src`. Unrecognized bytes are never reported as errors — each
+/// one simply becomes its own [`TokKind::Punct`] token.
+pub fn lex(src: &str) -> Vec<Tok> {
+    let mut toks = Vec::new();
+    let chars: Vec<char> = src.chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+        if c == '/' && chars.get(i + 1) == Some(&'/') {
+            let start = i;
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+            toks.push(Tok { kind: TokKind::Comment, text: chars[start..i].iter().collect() });
+            continue;
+        }
+        if c == '/' && chars.get(i + 1) == Some(&'*') {
+            let start = i;
+            i += 2;
+            while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                i += 1;
+            }
+            i = (i + 2).min(chars.len());
+            toks.push(Tok { kind: TokKind::Comment, text: chars[start..i].iter().collect() });
+            continue;
+        }
+        if c == '"' {
+            let start = i;
+            i += 1;
+            while i < chars.len() && chars[i] != '"' {
+                if chars[i] == '\\' && i + 1 < chars.len() {
+                    i += 1;
+                }
+                i += 1;
+            }
+            i = (i + 1).min(chars.len());
+            toks.push(Tok { kind: TokKind::Str, text: chars[start..i].iter().collect() });
+            continue;
+        }
+        if is_ident_start(c) {
+            let start = i;
+            while i < chars.len() && is_ident_continue(chars[i]) {
+                i += 1;
+            }
+            toks.push(Tok { kind: TokKind::Id, text: chars[start..i].iter().collect() });
+            continue;
+        }
+        if c.is_ascii_digit() {
+            let start = i;
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                i += 1;
+            }
+            toks.push(Tok { kind: TokKind::Num, text: chars[start..i].iter().collect() });
+            continue;
+        }
+        toks.push(Tok { kind: TokKind::Punct, text: c.to_string() });
+        i += 1;
+    }
+    toks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identifiers_numbers_and_strings_keep_their_original_text() {
+        let toks = lex(r#"add(a, "b", 12) + 3.5"#);
+        let texts: Vec<&str> = toks.iter().map(|t| t.text.as_str()).collect();
+        assert!(texts.contains(&"add"));
+        assert!(texts.contains(&"a"));
+        assert!(texts.contains(&"\"b\""));
+        assert!(texts.contains(&"12"));
+        assert!(texts.contains(&"3.5"));
+    }
+
+    #[test]
+    fn a_line_comment_runs_to_the_end_of_the_line() {
+        let toks = lex("a // trailing\nb");
+        assert_eq!(toks[1].kind, TokKind::Comment);
+        assert_eq!(toks[1].text, "// trailing");
+    }
+
+    #[test]
+    fn an_unterminated_string_is_tolerated_rather_than_erroring() {
+        let toks = lex(r#""oops"#);
+        assert_eq!(toks.len(), 1);
+        assert_eq!(toks[0].kind, TokKind::Str);
+    }
+}
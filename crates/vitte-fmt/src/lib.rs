@@ -0,0 +1,12 @@
+//! Formats Vitte source code. Built around a tolerant tokenizer — one that
+//! never errors, since a formatter has to do *something* reasonable with
+//! code that doesn't fully parse — and a pretty-printer that lays it back
+//! out with consistent indentation and spacing.
+
+mod lexer;
+mod options;
+mod printer;
+
+pub use lexer::{lex, Tok, TokKind};
+pub use options::FmtOptions;
+pub use printer::{format_source, format_with};
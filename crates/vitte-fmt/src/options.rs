@@ -0,0 +1,13 @@
+/// Knobs controlling how [`crate::format_source`] lays out its output.
+#[derive(Debug, Clone, Copy)]
+pub struct FmtOptions {
+    /// Call/index argument groups longer than this are wrapped one element
+    /// per line instead of staying on a single line.
+    pub max_width: usize,
+}
+
+impl Default for FmtOptions {
+    fn default() -> Self {
+        Self { max_width: 80 }
+    }
+}
@@ -0,0 +1,301 @@
+use std::collections::HashMap;
+
+use crate::lexer::{lex, Tok, TokKind};
+use crate::options::FmtOptions;
+
+const INDENT: &str = "    ";
+
+fn is_open_brace(text: &str) -> bool {
+    text == "{"
+}
+
+fn is_close_brace(text: &str) -> bool {
+    text == "}"
+}
+
+fn is_open_bracket(text: &str) -> bool {
+    text == "(" || text == "["
+}
+
+fn is_close_bracket(text: &str) -> bool {
+    text == ")" || text == "]"
+}
+
+fn matching_close(open: &str) -> &'static str {
+    if open == "(" {
+        ")"
+    } else {
+        "]"
+    }
+}
+
+/// Maps the index of every `(`/`[` to the index of its matching `)`/`]`.
+/// Unmatched or mismatched brackets are simply left out — the formatter
+/// never errors, it just leaves what it doesn't understand alone.
+fn build_bracket_matches(toks: &[Tok]) -> HashMap<usize, usize> {
+    let mut stack: Vec<usize> = Vec::new();
+    let mut matches = HashMap::new();
+    for (idx, tok) in toks.iter().enumerate() {
+        if is_open_bracket(&tok.text) {
+            stack.push(idx);
+        } else if is_close_bracket(&tok.text) {
+            if let Some(open_idx) = stack.pop() {
+                if matching_close(&toks[open_idx].text) == tok.text {
+                    matches.insert(open_idx, idx);
+                }
+            }
+        }
+    }
+    matches
+}
+
+/// Splits `toks[start..=end]` on commas that aren't nested inside a deeper
+/// bracket, returning the inclusive range of each element. Empty ranges
+/// (a trailing comma, or nothing at all) are omitted.
+fn split_top_level_commas(toks: &[Tok], start: usize, end: usize) -> Vec<(usize, usize)> {
+    if start > end {
+        return Vec::new();
+    }
+    let mut elems = Vec::new();
+    let mut depth: i32 = 0;
+    let mut elem_start = start;
+    for (idx, tok) in toks.iter().enumerate().take(end + 1).skip(start) {
+        let text = tok.text.as_str();
+        if is_open_bracket(text) {
+            depth += 1;
+        } else if is_close_bracket(text) {
+            depth -= 1;
+        } else if text == "," && depth == 0 {
+            if elem_start < idx {
+                elems.push((elem_start, idx - 1));
+            }
+            elem_start = idx + 1;
+        }
+    }
+    if elem_start <= end {
+        elems.push((elem_start, end));
+    }
+    elems
+}
+
+/// Appends `text` to `out`, inserting a single space before it unless it's
+/// one of the tokens that hug the thing before it (`)`, `]`, `,`, `;`), or
+/// `out` already ends in whitespace or an opening bracket.
+fn push_spaced(out: &mut String, text: &str) {
+    let no_leading_space = matches!(text, ")" | "]" | "," | ";");
+    if !no_leading_space
+        && !out.is_empty()
+        && !out.ends_with('\n')
+        && !out.ends_with(' ')
+        && !out.ends_with('(')
+        && !out.ends_with('[')
+    {
+        out.push(' ');
+    }
+    out.push_str(text);
+}
+
+/// Length, in characters, of the current (last) line of `out`.
+fn current_column(out: &str) -> usize {
+    out.rsplit('\n').next().unwrap_or(out).chars().count()
+}
+
+/// Renders `toks[start..=end]` (an expression, i.e. no top-level `;`/`{`/`}`
+/// statement structure) onto one line, via [`push_spaced`]. Used both to
+/// measure whether a bracket group fits, and to render the parts of it
+/// that do.
+fn render_inline(toks: &[Tok], start: usize, end: usize) -> String {
+    let mut out = String::new();
+    for tok in &toks[start..=end] {
+        push_spaced(&mut out, &tok.text);
+    }
+    out
+}
+
+/// Renders `toks[start..=end]` starting at output column `col`, breaking
+/// any bracket group that would overflow `max_width` into one element per
+/// line. Recurses into nested groups so a too-long argument can itself
+/// wrap.
+fn render_expr(
+    toks: &[Tok],
+    matches: &HashMap<usize, usize>,
+    start: usize,
+    end: usize,
+    depth: usize,
+    options: &FmtOptions,
+    col: usize,
+) -> String {
+    let mut out = String::new();
+    let mut cur_col = col;
+    let mut i = start;
+    while i <= end {
+        let tok = &toks[i];
+        if is_open_bracket(&tok.text) {
+            if let Some(&close) = matches.get(&i) {
+                let inline = render_inline(toks, i, close);
+                if close == i + 1 || cur_col + inline.chars().count() <= options.max_width {
+                    push_spaced(&mut out, &inline);
+                    cur_col = current_column(&out);
+                } else {
+                    push_spaced(&mut out, &tok.text);
+                    out.push('\n');
+                    for (elem_start, elem_end) in split_top_level_commas(toks, i + 1, close - 1) {
+                        out.push_str(&INDENT.repeat(depth + 1));
+                        let elem_col = INDENT.len() * (depth + 1);
+                        out.push_str(&render_expr(
+                            toks, matches, elem_start, elem_end, depth + 1, options, elem_col,
+                        ));
+                        out.push_str(",\n");
+                    }
+                    out.push_str(&INDENT.repeat(depth));
+                    out.push_str(matching_close(&tok.text));
+                    cur_col = depth * INDENT.len() + 1;
+                }
+                i = close + 1;
+                continue;
+            }
+        }
+        push_spaced(&mut out, &tok.text);
+        cur_col = current_column(&out);
+        i += 1;
+    }
+    out
+}
+
+/// Re-lay out `src`: one statement per line, braces on their own
+/// indentation level, a single space around everything else, and
+/// [`FmtOptions::max_width`]-aware wrapping of call/index argument groups.
+/// Comments, identifiers, numbers, and strings are emitted verbatim.
+pub fn format_source(src: &str) -> String {
+    format_with(src, &FmtOptions::default())
+}
+
+/// Like [`format_source`], but with explicit [`FmtOptions`].
+pub fn format_with(src: &str, options: &FmtOptions) -> String {
+    let toks = lex(src);
+    let matches = build_bracket_matches(&toks);
+    let mut out = String::new();
+    let mut depth: usize = 0;
+    let mut at_line_start = true;
+    let mut i = 0;
+
+    while i < toks.len() {
+        let tok = &toks[i];
+        if is_close_brace(&tok.text) {
+            depth = depth.saturating_sub(1);
+        }
+        if at_line_start {
+            out.push_str(&INDENT.repeat(depth));
+            at_line_start = false;
+        }
+
+        if is_open_bracket(&tok.text) {
+            if let Some(&close) = matches.get(&i) {
+                let inline = render_inline(&toks, i, close);
+                let col = current_column(&out);
+                if close == i + 1 || col + inline.chars().count() <= options.max_width {
+                    push_spaced(&mut out, &inline);
+                } else {
+                    let wrapped = render_expr(&toks, &matches, i, close, depth, options, col);
+                    push_spaced(&mut out, &wrapped);
+                }
+                i = close + 1;
+                continue;
+            }
+        }
+
+        push_spaced(&mut out, &tok.text);
+
+        match tok.kind {
+            TokKind::Comment => {
+                out.push('\n');
+                at_line_start = true;
+            }
+            TokKind::Punct if tok.text == ";" => {
+                out.push('\n');
+                at_line_start = true;
+            }
+            TokKind::Punct if is_open_brace(&tok.text) => {
+                depth += 1;
+                out.push('\n');
+                at_line_start = true;
+            }
+            TokKind::Punct if is_close_brace(&tok.text) => {
+                out.push('\n');
+                at_line_start = true;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    trim_trailing_spaces_per_line(&out)
+}
+
+/// Strip trailing whitespace from every line.
+fn trim_trailing_spaces_per_line(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for line in s.lines() {
+        out.push_str(line.trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identifiers_and_operators_survive_formatting() {
+        let formatted = format_source("fn add(a, b){ return a+b; }");
+        assert!(formatted.contains("add"), "{formatted}");
+        assert!(formatted.contains('a'), "{formatted}");
+        assert!(formatted.contains('b'), "{formatted}");
+        assert!(formatted.contains('+'), "{formatted}");
+    }
+
+    #[test]
+    fn a_block_is_indented_one_level_deeper_than_its_brace() {
+        let formatted = format_source("fn f(){ x; }");
+        let body_line = formatted.lines().find(|l| l.contains('x')).unwrap();
+        assert!(body_line.starts_with(INDENT), "{formatted}");
+    }
+
+    #[test]
+    fn formatting_twice_is_the_same_as_formatting_once() {
+        let corpus = [
+            "fn add(a, b){ return a+b; }",
+            "fn f(){ if(a){ b; } else { c; } }",
+            "// a leading comment\nfn f(){}",
+            "fn f(){ x; /* inline */ y; }",
+            "\"a string with { braces } and ; semicolons\";",
+            "fn nested(){ fn inner(){ x; } inner(); }",
+            "a,b,c;",
+            "call(aaaaaaaaaaaaaaaaaaaaaaaaaaa, bbbbbbbbbbbbbbbbbbbbbbbbb, ccccccccccccccccccccccc);",
+            "",
+        ];
+        for src in corpus {
+            let once = format_source(src);
+            let twice = format_source(&once);
+            assert_eq!(once, twice, "not idempotent for {src:?}:\n{once:?}\nvs\n{twice:?}");
+        }
+    }
+
+    #[test]
+    fn a_short_call_stays_on_one_line() {
+        let options = FmtOptions { max_width: 40 };
+        let formatted = format_with("call(a, b);", &options);
+        assert_eq!(formatted, "call (a, b);\n");
+    }
+
+    #[test]
+    fn a_call_over_max_width_wraps_one_argument_per_line() {
+        let options = FmtOptions { max_width: 20 };
+        let formatted = format_with("call(aaaa, bbbb, cccc);", &options);
+        assert_eq!(
+            formatted,
+            "call (\n    aaaa,\n    bbbb,\n    cccc,\n);\n"
+        );
+    }
+}
@@ -0,0 +1,46 @@
+use std::fmt;
+use vitte_core::Span;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LexErrorKind {
+    UnexpectedChar(char),
+    UnterminatedString,
+    InvalidEscape,
+    InvalidNumber,
+    /// A `\x..`/`\u{..}` escape inside a byte string or byte literal decoded
+    /// to a value outside `0..=0xFF`.
+    ByteOutOfRange,
+    /// [`crate::LexerOptions::max_tokens`] was exceeded.
+    TooManyTokens,
+}
+
+impl fmt::Display for LexErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LexErrorKind::UnexpectedChar(c) => write!(f, "unexpected character `{c}`"),
+            LexErrorKind::UnterminatedString => write!(f, "unterminated string"),
+            LexErrorKind::InvalidEscape => write!(f, "invalid escape sequence"),
+            LexErrorKind::InvalidNumber => write!(f, "invalid number literal"),
+            LexErrorKind::ByteOutOfRange => write!(f, "escape value out of byte range"),
+            LexErrorKind::TooManyTokens => write!(f, "too many tokens"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LexError {
+    pub kind: LexErrorKind,
+    pub span: Span,
+}
+
+impl LexError {
+    pub fn new(kind: LexErrorKind, span: Span) -> Self {
+        Self { kind, span }
+    }
+}
+
+impl fmt::Display for LexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.kind)
+    }
+}
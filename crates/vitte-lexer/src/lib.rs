@@ -0,0 +1,1068 @@
+//! Hand-written recursive-descent-friendly lexer for Vitte source.
+
+mod error;
+mod token;
+
+pub use error::{LexError, LexErrorKind};
+pub use token::{keyword_of, Keyword, Token, TokenKind};
+
+use unicode_xid::UnicodeXID;
+use vitte_core::Span;
+
+#[derive(Debug, Clone, Copy)]
+pub struct LexerOptions {
+    /// Enable `r"..."` / `r#"..."#` raw string literals.
+    pub raw_strings: bool,
+    /// Maximum number of `#` delimiters accepted around a raw string, to
+    /// bound pathological inputs like `r#####...#####"..."#####...#####`.
+    pub max_raw_hashes: usize,
+    /// When set, `//` and `/* */` comments are surfaced as
+    /// `TokenKind::LineComment`/`BlockComment` tokens instead of being
+    /// silently skipped. Off by default so existing callers are unaffected.
+    pub emit_comments: bool,
+    /// Accept Unicode identifiers (XID_Start/XID_Continue) rather than
+    /// ASCII-only identifiers. Defaults to `true`.
+    pub unicode_idents: bool,
+    /// When set, lexing fails with [`LexErrorKind::TooManyTokens`] once this
+    /// many tokens have been produced, bounding the work a hostile or
+    /// accidentally huge input can force. Complements `max_raw_hashes`.
+    /// `None` (the default) keeps the current unbounded behavior.
+    pub max_tokens: Option<usize>,
+}
+
+impl Default for LexerOptions {
+    fn default() -> Self {
+        Self {
+            raw_strings: true,
+            max_raw_hashes: 255,
+            emit_comments: false,
+            unicode_idents: true,
+            max_tokens: None,
+        }
+    }
+}
+
+pub struct Lexer<'a> {
+    src: &'a str,
+    bytes: &'a [u8],
+    off: usize,
+    opts: LexerOptions,
+    iter_done: bool,
+    token_count: usize,
+}
+
+
+impl<'a> Lexer<'a> {
+    pub fn new(src: &'a str) -> Self {
+        Self::with_options(src, LexerOptions::default())
+    }
+
+    pub fn with_options(src: &'a str, opts: LexerOptions) -> Self {
+        Self { src, bytes: src.as_bytes(), off: 0, opts, iter_done: false, token_count: 0 }
+    }
+
+    /// Resumes lexing `src` from byte offset `off` instead of the start, for
+    /// speculative re-parses that only need the tokens from some point
+    /// onward. Returns `None` if `off` isn't a char boundary of `src`
+    /// (which also rejects `off > src.len()`).
+    pub fn with_offset(src: &'a str, opts: LexerOptions, off: usize) -> Option<Self> {
+        if !src.is_char_boundary(off) {
+            return None;
+        }
+        Some(Self { src, bytes: src.as_bytes(), off, opts, iter_done: false, token_count: 0 })
+    }
+
+    fn peek_byte(&self) -> Option<u8> {
+        self.bytes.get(self.off).copied()
+    }
+
+    fn peek_byte_at(&self, delta: usize) -> Option<u8> {
+        self.bytes.get(self.off + delta).copied()
+    }
+
+    fn peek_char(&self) -> Option<char> {
+        self.src[self.off..].chars().next()
+    }
+
+    fn bump_char(&mut self) -> Option<char> {
+        let c = self.peek_char()?;
+        self.off += c.len_utf8();
+        Some(c)
+    }
+
+    fn is_ident_start(&self, c: char) -> bool {
+        if self.opts.unicode_idents {
+            c == '_' || UnicodeXID::is_xid_start(c)
+        } else {
+            c == '_' || c.is_ascii_alphabetic()
+        }
+    }
+
+    fn is_ident_continue(&self, c: char) -> bool {
+        if self.opts.unicode_idents {
+            UnicodeXID::is_xid_continue(c)
+        } else {
+            c == '_' || c.is_ascii_alphanumeric()
+        }
+    }
+
+    fn skip_ws(&mut self) {
+        while matches!(self.peek_byte(), Some(b' ') | Some(b'\t') | Some(b'\r') | Some(b'\n')) {
+            self.off += 1;
+        }
+    }
+
+    fn skip_line_comment(&mut self) {
+        while !matches!(self.peek_byte(), None | Some(b'\n')) {
+            self.off += 1;
+        }
+    }
+
+    fn skip_block_comment(&mut self) {
+        self.off += 2; // `/*`
+        while self.peek_byte().is_some()
+            && !(self.peek_byte() == Some(b'*') && self.peek_byte_at(1) == Some(b'/'))
+        {
+            self.off += 1;
+        }
+        if self.peek_byte().is_some() {
+            self.off += 2;
+        }
+    }
+
+    /// Lex the next token. Returns `Ok(None)` once `Eof` has already been
+    /// produced for the caller wishing to stop. Fails with
+    /// [`LexErrorKind::TooManyTokens`] once `opts.max_tokens` is exceeded.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Result<Option<Token<'a>>, LexError> {
+        if let Some(max) = self.opts.max_tokens {
+            if self.token_count >= max {
+                return Err(LexError::new(LexErrorKind::TooManyTokens, Span::new(self.off as u32, self.off as u32)));
+            }
+        }
+        let tok = self.next_one()?;
+        if tok.is_some() {
+            self.token_count += 1;
+        }
+        Ok(tok)
+    }
+
+    fn next_one(&mut self) -> Result<Option<Token<'a>>, LexError> {
+        let start;
+        loop {
+            self.skip_ws();
+            let comment_start = self.off;
+            if self.peek_byte() == Some(b'/') && self.peek_byte_at(1) == Some(b'/') {
+                self.skip_line_comment();
+                if self.opts.emit_comments {
+                    let text = &self.src[comment_start..self.off];
+                    return Ok(Some(Token::new(
+                        TokenKind::LineComment(text),
+                        Span::new(comment_start as u32, self.off as u32),
+                    )));
+                }
+                continue;
+            }
+            if self.peek_byte() == Some(b'/') && self.peek_byte_at(1) == Some(b'*') {
+                self.skip_block_comment();
+                if self.opts.emit_comments {
+                    let text = &self.src[comment_start..self.off];
+                    return Ok(Some(Token::new(
+                        TokenKind::BlockComment(text),
+                        Span::new(comment_start as u32, self.off as u32),
+                    )));
+                }
+                continue;
+            }
+            start = comment_start;
+            break;
+        }
+
+        let Some(c) = self.peek_char() else {
+            return Ok(Some(Token::new(TokenKind::Eof, Span::new(start as u32, start as u32))));
+        };
+
+        let kind = match c {
+            'r' if self.opts.raw_strings
+                && (self.peek_byte_at(1) == Some(b'"') || self.peek_byte_at(1) == Some(b'#')) =>
+            {
+                match self.try_raw_string(start)? {
+                    Some(kind) => kind,
+                    None => {
+                        self.off = start;
+                        self.lex_ident()
+                    }
+                }
+            }
+            'b' if self.looks_like_byte_prefix() => self.lex_byte_prefixed(start)?,
+            c if self.is_ident_start(c) => self.lex_ident(),
+            c if c.is_ascii_digit() => self.lex_number(start)?,
+            '.' if matches!(self.peek_byte_at(1), Some(b) if (b as char).is_ascii_digit()) => {
+                self.lex_leading_dot_float(start)?
+            }
+            '"' => self.lex_string(start)?,
+            '=' => {
+                self.bump_char();
+                match self.peek_char() {
+                    Some('=') => {
+                        self.bump_char();
+                        TokenKind::EqEq
+                    }
+                    Some('>') => {
+                        self.bump_char();
+                        TokenKind::FatArrow
+                    }
+                    _ => TokenKind::Eq,
+                }
+            }
+            '!' => {
+                self.bump_char();
+                if self.peek_char() == Some('=') {
+                    self.bump_char();
+                    TokenKind::BangEq
+                } else {
+                    TokenKind::Bang
+                }
+            }
+            '<' => {
+                self.bump_char();
+                match self.peek_char() {
+                    Some('=') => {
+                        self.bump_char();
+                        TokenKind::Le
+                    }
+                    Some('<') => {
+                        self.bump_char();
+                        TokenKind::Shl
+                    }
+                    _ => TokenKind::Lt,
+                }
+            }
+            '>' => {
+                self.bump_char();
+                match self.peek_char() {
+                    Some('=') => {
+                        self.bump_char();
+                        TokenKind::Ge
+                    }
+                    Some('>') => {
+                        self.bump_char();
+                        TokenKind::Shr
+                    }
+                    _ => TokenKind::Gt,
+                }
+            }
+            '&' => {
+                self.bump_char();
+                if self.peek_char() == Some('&') {
+                    self.bump_char();
+                    TokenKind::AndAnd
+                } else {
+                    TokenKind::Amp
+                }
+            }
+            '|' => {
+                self.bump_char();
+                if self.peek_char() == Some('|') {
+                    self.bump_char();
+                    TokenKind::OrOr
+                } else {
+                    TokenKind::Pipe
+                }
+            }
+            '^' => {
+                self.bump_char();
+                TokenKind::Caret
+            }
+            '~' => {
+                self.bump_char();
+                TokenKind::Tilde
+            }
+            '-' => {
+                self.bump_char();
+                match self.peek_char() {
+                    Some('>') => {
+                        self.bump_char();
+                        TokenKind::Arrow
+                    }
+                    Some('=') => {
+                        self.bump_char();
+                        TokenKind::MinusEq
+                    }
+                    _ => TokenKind::Minus,
+                }
+            }
+            '+' => {
+                self.bump_char();
+                if self.peek_char() == Some('=') {
+                    self.bump_char();
+                    TokenKind::PlusEq
+                } else {
+                    TokenKind::Plus
+                }
+            }
+            '*' => {
+                self.bump_char();
+                if self.peek_char() == Some('=') {
+                    self.bump_char();
+                    TokenKind::StarEq
+                } else {
+                    TokenKind::Star
+                }
+            }
+            '/' => {
+                self.bump_char();
+                if self.peek_char() == Some('=') {
+                    self.bump_char();
+                    TokenKind::SlashEq
+                } else {
+                    TokenKind::Slash
+                }
+            }
+            '%' => {
+                self.bump_char();
+                if self.peek_char() == Some('=') {
+                    self.bump_char();
+                    TokenKind::PercentEq
+                } else {
+                    TokenKind::Percent
+                }
+            }
+            '.' => {
+                self.bump_char();
+                match self.peek_char() {
+                    Some('.') => {
+                        self.bump_char();
+                        match self.peek_char() {
+                            Some('=') => {
+                                self.bump_char();
+                                TokenKind::DotDotEq
+                            }
+                            _ => TokenKind::DotDot,
+                        }
+                    }
+                    _ => TokenKind::Dot,
+                }
+            }
+            ',' => {
+                self.bump_char();
+                TokenKind::Comma
+            }
+            ';' => {
+                self.bump_char();
+                TokenKind::Semi
+            }
+            ':' => {
+                self.bump_char();
+                TokenKind::Colon
+            }
+            '(' => {
+                self.bump_char();
+                TokenKind::LParen
+            }
+            ')' => {
+                self.bump_char();
+                TokenKind::RParen
+            }
+            '{' => {
+                self.bump_char();
+                TokenKind::LBrace
+            }
+            '}' => {
+                self.bump_char();
+                TokenKind::RBrace
+            }
+            '[' => {
+                self.bump_char();
+                TokenKind::LBracket
+            }
+            ']' => {
+                self.bump_char();
+                TokenKind::RBracket
+            }
+            other => {
+                self.bump_char();
+                return Err(LexError::new(
+                    LexErrorKind::UnexpectedChar(other),
+                    Span::new(start as u32, self.off as u32),
+                ));
+            }
+        };
+
+        Ok(Some(Token::new(kind, Span::new(start as u32, self.off as u32))))
+    }
+
+    fn lex_ident(&mut self) -> TokenKind<'a> {
+        let start = self.off;
+        while let Some(c) = self.peek_char() {
+            if self.is_ident_continue(c) {
+                self.off += c.len_utf8();
+            } else {
+                break;
+            }
+        }
+        let text = &self.src[start..self.off];
+        match keyword_of(text) {
+            Some(kw) => TokenKind::Kw(kw),
+            None => TokenKind::Ident(text),
+        }
+    }
+
+    const NUM_SUFFIXES: &'static [&'static str] =
+        &["i8", "i16", "i32", "i64", "u8", "u16", "u32", "u64", "f32", "f64"];
+
+    fn lex_number(&mut self, start: usize) -> Result<TokenKind<'a>, LexError> {
+        while matches!(self.peek_char(), Some(c) if c.is_ascii_digit()) {
+            self.off += 1;
+        }
+        let mut is_float = false;
+        if self.peek_char() == Some('.') {
+            if matches!(self.peek_byte_at(1), Some(b) if (b as char).is_ascii_digit()) {
+                is_float = true;
+                self.off += 1;
+                while matches!(self.peek_char(), Some(c) if c.is_ascii_digit()) {
+                    self.off += 1;
+                }
+            } else if self.trailing_dot_starts_float() {
+                // `1.` with no fractional digits, e.g. `1.` or `1. + 2`.
+                is_float = true;
+                self.off += 1;
+            }
+        }
+        let digits_end = self.off;
+        let suffix = self.lex_num_suffix()?;
+
+        let text = &self.src[start..digits_end];
+        if is_float || matches!(suffix, Some("f32") | Some("f64")) {
+            text.parse::<f64>()
+                .map(|v| TokenKind::Float(v, suffix))
+                .map_err(|_| LexError::new(LexErrorKind::InvalidNumber, Span::new(start as u32, digits_end as u32)))
+        } else {
+            text.parse::<i64>()
+                .map(|v| TokenKind::Int(v, suffix))
+                .map_err(|_| LexError::new(LexErrorKind::InvalidNumber, Span::new(start as u32, digits_end as u32)))
+        }
+    }
+
+    /// Whether the `.` at the cursor (not yet consumed, with no fractional
+    /// digits after it) should be read as the end of a trailing-dot float
+    /// like `1.`, rather than left for the caller to tokenize as `Dot`.
+    /// False for `..`/`..=` (ranges) and for `1.foo` (so method-call-style
+    /// syntax on an int literal still lexes as `Int Dot Ident`).
+    fn trailing_dot_starts_float(&self) -> bool {
+        match self.src[self.off + 1..].chars().next() {
+            None => true,
+            Some(c) => c != '.' && !self.is_ident_start(c),
+        }
+    }
+
+    /// A leading-dot float like `.5`: no integer part, just a `.` directly
+    /// followed by a digit. Unambiguous here since field access (`Dot` in
+    /// the parser) always expects an identifier next, never a digit.
+    fn lex_leading_dot_float(&mut self, start: usize) -> Result<TokenKind<'a>, LexError> {
+        self.bump_char(); // the leading '.'
+        while matches!(self.peek_char(), Some(c) if c.is_ascii_digit()) {
+            self.off += 1;
+        }
+        let digits_end = self.off;
+        let suffix = self.lex_num_suffix()?;
+        let text = &self.src[start..digits_end];
+        text.parse::<f64>()
+            .map(|v| TokenKind::Float(v, suffix))
+            .map_err(|_| LexError::new(LexErrorKind::InvalidNumber, Span::new(start as u32, digits_end as u32)))
+    }
+
+    /// Parses and validates the optional type suffix (`i64`, `f32`, ...)
+    /// directly following a number's digits.
+    fn lex_num_suffix(&mut self) -> Result<Option<&'a str>, LexError> {
+        let suffix_start = self.off;
+        while matches!(self.peek_char(), Some(c) if self.is_ident_continue(c)) {
+            self.off += 1;
+        }
+        if suffix_start == self.off {
+            return Ok(None);
+        }
+        let text = &self.src[suffix_start..self.off];
+        if !Self::NUM_SUFFIXES.contains(&text) {
+            return Err(LexError::new(LexErrorKind::InvalidNumber, Span::new(suffix_start as u32, self.off as u32)));
+        }
+        Ok(Some(text))
+    }
+
+    fn decode_escape(&mut self, start: usize) -> Result<char, LexError> {
+        match self.bump_char() {
+            Some('n') => Ok('\n'),
+            Some('t') => Ok('\t'),
+            Some('r') => Ok('\r'),
+            Some('0') => Ok('\0'),
+            Some('\\') => Ok('\\'),
+            Some('"') => Ok('"'),
+            Some('\'') => Ok('\''),
+            Some('x') => {
+                let hi = self.bump_char().filter(char::is_ascii_hexdigit);
+                let lo = self.bump_char().filter(char::is_ascii_hexdigit);
+                match (hi, lo) {
+                    (Some(hi), Some(lo)) => {
+                        let value = hi.to_digit(16).unwrap() * 16 + lo.to_digit(16).unwrap();
+                        Ok(value as u8 as char)
+                    }
+                    _ => Err(LexError::new(LexErrorKind::InvalidEscape, Span::new(start as u32, self.off as u32))),
+                }
+            }
+            Some('u') => {
+                if self.bump_char() != Some('{') {
+                    return Err(LexError::new(LexErrorKind::InvalidEscape, Span::new(start as u32, self.off as u32)));
+                }
+                let digits_start = self.off;
+                while matches!(self.peek_char(), Some(c) if c.is_ascii_hexdigit()) {
+                    self.off += 1;
+                }
+                let digits = &self.src[digits_start..self.off];
+                if self.bump_char() != Some('}') || digits.is_empty() {
+                    return Err(LexError::new(LexErrorKind::InvalidEscape, Span::new(start as u32, self.off as u32)));
+                }
+                let value = u32::from_str_radix(digits, 16)
+                    .map_err(|_| LexError::new(LexErrorKind::InvalidEscape, Span::new(start as u32, self.off as u32)))?;
+                char::from_u32(value)
+                    .ok_or_else(|| LexError::new(LexErrorKind::InvalidEscape, Span::new(start as u32, self.off as u32)))
+            }
+            _ => Err(LexError::new(LexErrorKind::InvalidEscape, Span::new(start as u32, self.off as u32))),
+        }
+    }
+
+    /// Decodes an escape that must fit in a single byte (used by byte
+    /// strings/literals), rejecting `\u{...}` values above `0xFF`.
+    fn decode_byte_escape(&mut self, start: usize) -> Result<u8, LexError> {
+        let c = self.decode_escape(start)?;
+        let cp = c as u32;
+        if cp > 0xFF {
+            return Err(LexError::new(LexErrorKind::ByteOutOfRange, Span::new(start as u32, self.off as u32)));
+        }
+        Ok(cp as u8)
+    }
+
+    fn lex_string(&mut self, start: usize) -> Result<TokenKind<'a>, LexError> {
+        self.bump_char(); // opening quote
+        let mut out = String::new();
+        loop {
+            match self.peek_char() {
+                None => {
+                    return Err(LexError::new(
+                        LexErrorKind::UnterminatedString,
+                        Span::new(start as u32, self.off as u32),
+                    ))
+                }
+                Some('"') => {
+                    self.bump_char();
+                    break;
+                }
+                Some('\\') => {
+                    let esc_start = self.off;
+                    self.bump_char();
+                    out.push(self.decode_escape(esc_start)?);
+                }
+                Some(c) => {
+                    out.push(c);
+                    self.bump_char();
+                }
+            }
+        }
+        Ok(TokenKind::Str(out))
+    }
+
+    /// Attempts to lex a raw string starting at `start` (which points at the
+    /// `r`). Returns `Ok(None)` when the `r#`/`r"` sequence does not actually
+    /// form a valid raw string, so the caller can rewind and lex a plain
+    /// identifier instead.
+    fn try_raw_string(&mut self, start: usize) -> Result<Option<TokenKind<'a>>, LexError> {
+        self.bump_char(); // 'r'
+        Ok(self.try_raw_string_body(start)?.map(|body| TokenKind::Str(body.to_string())))
+    }
+
+    /// Shared raw-string body scanner used by both `r"..."` and `br"..."`.
+    /// Assumes the leading `r` (and, for byte strings, `b`) has already been
+    /// consumed.
+    fn try_raw_string_body(&mut self, start: usize) -> Result<Option<&'a str>, LexError> {
+        let mut hashes = 0usize;
+        while self.peek_char() == Some('#') {
+            hashes += 1;
+            self.off += 1;
+            if hashes > self.opts.max_raw_hashes {
+                return Err(LexError::new(
+                    LexErrorKind::InvalidNumber,
+                    Span::new(start as u32, self.off as u32),
+                ));
+            }
+        }
+        if self.peek_char() != Some('"') {
+            return Ok(None);
+        }
+        self.bump_char(); // opening quote
+        let body_start = self.off;
+        loop {
+            match self.peek_char() {
+                None => {
+                    return Err(LexError::new(
+                        LexErrorKind::UnterminatedString,
+                        Span::new(start as u32, self.off as u32),
+                    ))
+                }
+                Some('"') => {
+                    let body_end = self.off;
+                    let close_start = self.off;
+                    self.off += 1;
+                    let mut matched_hashes = 0usize;
+                    while matched_hashes < hashes && self.peek_char() == Some('#') {
+                        matched_hashes += 1;
+                        self.off += 1;
+                    }
+                    if matched_hashes == hashes {
+                        return Ok(Some(&self.src[body_start..body_end]));
+                    }
+                    self.off = close_start + 1;
+                }
+                Some(_) => {
+                    self.bump_char();
+                }
+            }
+        }
+    }
+
+    fn looks_like_byte_prefix(&self) -> bool {
+        match self.peek_byte_at(1) {
+            Some(b'"') | Some(b'\'') => true,
+            Some(b'r') if self.opts.raw_strings => {
+                matches!(self.peek_byte_at(2), Some(b'"') | Some(b'#'))
+            }
+            _ => false,
+        }
+    }
+
+    /// Lexes `b"..."`, `b'x'`, and (when raw strings are enabled) `br"..."`
+    /// / `br#"..."#`. The leading `b` has not yet been consumed.
+    fn lex_byte_prefixed(&mut self, start: usize) -> Result<TokenKind<'a>, LexError> {
+        self.bump_char(); // 'b'
+        if self.peek_char() == Some('r') {
+            self.bump_char(); // 'r'
+            return match self.try_raw_string_body(start)? {
+                Some(body) => Ok(TokenKind::Bytes(body.as_bytes().to_vec())),
+                None => Err(LexError::new(LexErrorKind::InvalidNumber, Span::new(start as u32, self.off as u32))),
+            };
+        }
+        if self.peek_char() == Some('\'') {
+            self.bump_char();
+            let byte = match self.peek_char() {
+                Some('\\') => {
+                    let esc_start = self.off;
+                    self.bump_char();
+                    self.decode_byte_escape(esc_start)?
+                }
+                Some(c) if c.is_ascii() => {
+                    self.off += 1;
+                    c as u8
+                }
+                _ => {
+                    return Err(LexError::new(
+                        LexErrorKind::InvalidEscape,
+                        Span::new(start as u32, self.off as u32),
+                    ))
+                }
+            };
+            if self.peek_char() != Some('\'') {
+                return Err(LexError::new(LexErrorKind::UnterminatedString, Span::new(start as u32, self.off as u32)));
+            }
+            self.bump_char();
+            return Ok(TokenKind::Byte(byte));
+        }
+        // b"..."
+        self.bump_char(); // opening quote
+        let mut out = Vec::new();
+        loop {
+            match self.peek_char() {
+                None => {
+                    return Err(LexError::new(
+                        LexErrorKind::UnterminatedString,
+                        Span::new(start as u32, self.off as u32),
+                    ))
+                }
+                Some('"') => {
+                    self.bump_char();
+                    break;
+                }
+                Some('\\') => {
+                    let esc_start = self.off;
+                    self.bump_char();
+                    out.push(self.decode_byte_escape(esc_start)?);
+                }
+                Some(c) => {
+                    out.push(c as u8);
+                    self.bump_char();
+                }
+            }
+        }
+        Ok(TokenKind::Bytes(out))
+    }
+
+    /// Lex the whole source into a vector of tokens, including the trailing
+    /// `Eof`, stopping at the first error.
+    pub fn tokenize(mut self) -> Result<Vec<Token<'a>>, LexError> {
+        let mut out = Vec::new();
+        loop {
+            let tok = self.next()?.expect("next() always yields a token or Eof");
+            let is_eof = matches!(tok.kind, TokenKind::Eof);
+            out.push(tok);
+            if is_eof {
+                break;
+            }
+        }
+        Ok(out)
+    }
+
+    /// Lexes the whole source like [`Lexer::tokenize`], but instead of
+    /// aborting at the first [`LexError`] it records it, advances at least
+    /// one byte past the offending position, and keeps going so that
+    /// editor-style diagnostics can report every problem in one pass.
+    pub fn tokenize_recover(mut self) -> (Vec<Token<'a>>, Vec<LexError>) {
+        let mut toks = Vec::new();
+        let mut errs = Vec::new();
+        loop {
+            match self.next() {
+                Ok(Some(tok)) => {
+                    let is_eof = matches!(tok.kind, TokenKind::Eof);
+                    toks.push(tok);
+                    if is_eof {
+                        break;
+                    }
+                }
+                Ok(None) => break,
+                Err(e) => {
+                    let advanced = (e.span.end as usize).max(e.span.start as usize + 1);
+                    self.off = advanced.min(self.src.len());
+                    errs.push(e);
+                }
+            }
+        }
+        (toks, errs)
+    }
+}
+
+/// Ergonomic streaming alternative to calling [`Lexer::next`] in a manual
+/// loop. Yields tokens up to and including a single `Eof`, then stops; an
+/// error also ends the stream.
+impl<'a> Iterator for Lexer<'a> {
+    type Item = Result<Token<'a>, LexError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.iter_done {
+            return None;
+        }
+        match Lexer::next(self) {
+            Ok(Some(tok)) => {
+                if matches!(tok.kind, TokenKind::Eof) {
+                    self.iter_done = true;
+                }
+                Some(Ok(tok))
+            }
+            Ok(None) => {
+                self.iter_done = true;
+                None
+            }
+            Err(e) => {
+                self.iter_done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(src: &str) -> Vec<TokenKind<'_>> {
+        Lexer::new(src).tokenize().unwrap().into_iter().map(|t| t.kind).collect()
+    }
+
+    #[test]
+    fn in_is_a_keyword() {
+        assert_eq!(kinds("in"), vec![TokenKind::Kw(Keyword::In), TokenKind::Eof]);
+    }
+
+    #[test]
+    fn range_dots() {
+        assert_eq!(
+            kinds("0..10..=n."),
+            vec![
+                TokenKind::Int(0, None),
+                TokenKind::DotDot,
+                TokenKind::Int(10, None),
+                TokenKind::DotDotEq,
+                TokenKind::Ident("n"),
+                TokenKind::Dot,
+                TokenKind::Eof,
+            ]
+        );
+    }
+
+    #[test]
+    fn ops_punct() {
+        assert_eq!(
+            kinds("a==b->c=>d"),
+            vec![
+                TokenKind::Ident("a"),
+                TokenKind::EqEq,
+                TokenKind::Ident("b"),
+                TokenKind::Arrow,
+                TokenKind::Ident("c"),
+                TokenKind::FatArrow,
+                TokenKind::Ident("d"),
+                TokenKind::Eof,
+            ]
+        );
+    }
+
+    #[test]
+    fn plain_ident_starting_with_r_is_not_a_raw_string() {
+        assert_eq!(kinds("regex"), vec![TokenKind::Ident("regex"), TokenKind::Eof]);
+    }
+
+    #[test]
+    fn ident_r_hash_without_quote_falls_back_to_ident() {
+        // `r#foo` isn't a raw string (no opening quote after the hashes),
+        // so it must be re-lexed as the identifier `r`, not misread as
+        // `InvalidNumber` or silently swallowed into a raw string.
+        let mut lx = Lexer::new("r#foo");
+        let tok = lx.next().unwrap().unwrap();
+        assert_eq!(tok.kind, TokenKind::Ident("r"));
+    }
+
+    #[test]
+    fn unicode_identifiers() {
+        assert_eq!(kinds("café"), vec![TokenKind::Ident("café"), TokenKind::Eof]);
+        assert_eq!(kinds("変数"), vec![TokenKind::Ident("変数"), TokenKind::Eof]);
+    }
+
+    #[test]
+    fn unicode_identifier_span_is_byte_accurate() {
+        let src = "café + 1";
+        let toks = Lexer::new(src).tokenize().unwrap();
+        let ident = &toks[0];
+        assert_eq!(ident.span, Span::new(0, "café".len() as u32));
+        assert_eq!(&src[ident.span.start as usize..ident.span.end as usize], "café");
+    }
+
+    #[test]
+    fn ascii_only_mode_rejects_unicode_identifiers() {
+        let opts = LexerOptions { unicode_idents: false, ..LexerOptions::default() };
+        let err = Lexer::with_options("café", opts).tokenize().unwrap_err();
+        assert_eq!(err.kind, LexErrorKind::UnexpectedChar('é'));
+    }
+
+    #[test]
+    fn numeric_suffixes() {
+        assert_eq!(kinds("10i32"), vec![TokenKind::Int(10, Some("i32")), TokenKind::Eof]);
+        assert_eq!(kinds("3.0f32"), vec![TokenKind::Float(3.0, Some("f32")), TokenKind::Eof]);
+        let err = Lexer::new("5q").tokenize().unwrap_err();
+        assert_eq!(err.kind, LexErrorKind::InvalidNumber);
+    }
+
+    #[test]
+    fn trailing_dot_is_a_float() {
+        assert_eq!(kinds("1."), vec![TokenKind::Float(1.0, None), TokenKind::Eof]);
+        assert_eq!(kinds("1. + 2"), vec![TokenKind::Float(1.0, None), TokenKind::Plus, TokenKind::Int(2, None), TokenKind::Eof]);
+    }
+
+    #[test]
+    fn leading_dot_is_a_float() {
+        assert_eq!(kinds(".5"), vec![TokenKind::Float(0.5, None), TokenKind::Eof]);
+    }
+
+    #[test]
+    fn a_trailing_dot_followed_by_an_identifier_is_still_dot_then_ident() {
+        assert_eq!(kinds("1.foo"), vec![TokenKind::Int(1, None), TokenKind::Dot, TokenKind::Ident("foo"), TokenKind::Eof]);
+    }
+
+    #[test]
+    fn a_trailing_dot_does_not_clash_with_ranges() {
+        assert_eq!(kinds("1..5"), vec![TokenKind::Int(1, None), TokenKind::DotDot, TokenKind::Int(5, None), TokenKind::Eof]);
+        assert_eq!(kinds("1..=5"), vec![TokenKind::Int(1, None), TokenKind::DotDotEq, TokenKind::Int(5, None), TokenKind::Eof]);
+    }
+
+    #[test]
+    fn tokenize_recover_collects_multiple_errors() {
+        let src = "let x = 1; @ let y = 2; $ ";
+        let (toks, errs) = Lexer::new(src).tokenize_recover();
+        assert_eq!(errs.len(), 2);
+        assert_eq!(errs[0].kind, LexErrorKind::UnexpectedChar('@'));
+        assert_eq!(errs[1].kind, LexErrorKind::UnexpectedChar('$'));
+        // lexing continued on both sides of the bad characters
+        assert!(toks.iter().any(|t| t.kind == TokenKind::Ident("y")));
+        assert_eq!(toks.last().unwrap().kind, TokenKind::Eof);
+    }
+
+    #[test]
+    fn max_tokens_stops_lexing_once_the_limit_is_reached() {
+        let opts = LexerOptions { max_tokens: Some(3), ..LexerOptions::default() };
+        let src = "let x = 1; let y = 2;";
+        let err = Lexer::with_options(src, opts).tokenize().unwrap_err();
+        assert_eq!(err.kind, LexErrorKind::TooManyTokens);
+
+        // Exactly the first 3 tokens were produced before the limit tripped.
+        let mut lexer = Lexer::with_options(src, opts);
+        let first_three: Vec<_> = (0..3).map(|_| lexer.next().unwrap().unwrap()).collect();
+        assert_eq!(first_three[0].kind, TokenKind::Kw(Keyword::Let));
+        assert_eq!(first_three[1].kind, TokenKind::Ident("x"));
+        assert_eq!(first_three[2].kind, TokenKind::Eq);
+        assert!(lexer.next().is_err());
+    }
+
+    #[test]
+    fn iterator_matches_tokenize() {
+        let src = "let x = 1 + 2;";
+        let from_iter: Vec<Token<'_>> = Lexer::new(src).collect::<Result<Vec<_>, _>>().unwrap();
+        let from_tokenize = Lexer::new(src).tokenize().unwrap();
+        assert_eq!(from_iter, from_tokenize);
+    }
+
+    #[test]
+    fn comments_are_skipped_by_default() {
+        assert_eq!(kinds("a // hi\nb /* mid */ c"), vec![
+            TokenKind::Ident("a"),
+            TokenKind::Ident("b"),
+            TokenKind::Ident("c"),
+            TokenKind::Eof,
+        ]);
+    }
+
+    #[test]
+    fn comments_are_emitted_with_exact_spans_when_requested() {
+        let opts = LexerOptions { emit_comments: true, ..LexerOptions::default() };
+        let src = "a // hi\nb /* mid */ c";
+        let toks = Lexer::with_options(src, opts).tokenize().unwrap();
+        let comment_spans: Vec<&str> =
+            toks.iter().map(|t| &src[t.span.start as usize..t.span.end as usize]).collect();
+        assert_eq!(
+            toks.iter().map(|t| &t.kind).collect::<Vec<_>>(),
+            vec![
+                &TokenKind::Ident("a"),
+                &TokenKind::LineComment("// hi"),
+                &TokenKind::Ident("b"),
+                &TokenKind::BlockComment("/* mid */"),
+                &TokenKind::Ident("c"),
+                &TokenKind::Eof,
+            ]
+        );
+        assert_eq!(comment_spans[1], "// hi");
+        assert_eq!(comment_spans[3], "/* mid */");
+    }
+
+    #[test]
+    fn compound_assign_tokens() {
+        assert_eq!(
+            kinds("a+=1"),
+            vec![TokenKind::Ident("a"), TokenKind::PlusEq, TokenKind::Int(1, None), TokenKind::Eof]
+        );
+        assert_eq!(
+            kinds("a-=b*=c/=d%=e"),
+            vec![
+                TokenKind::Ident("a"),
+                TokenKind::MinusEq,
+                TokenKind::Ident("b"),
+                TokenKind::StarEq,
+                TokenKind::Ident("c"),
+                TokenKind::SlashEq,
+                TokenKind::Ident("d"),
+                TokenKind::PercentEq,
+                TokenKind::Ident("e"),
+                TokenKind::Eof,
+            ]
+        );
+        // longer matches still win their races
+        assert_eq!(kinds("a==b"), vec![TokenKind::Ident("a"), TokenKind::EqEq, TokenKind::Ident("b"), TokenKind::Eof]);
+        assert_eq!(kinds("a=>b"), vec![TokenKind::Ident("a"), TokenKind::FatArrow, TokenKind::Ident("b"), TokenKind::Eof]);
+        assert_eq!(kinds("a->b"), vec![TokenKind::Ident("a"), TokenKind::Arrow, TokenKind::Ident("b"), TokenKind::Eof]);
+    }
+
+    #[test]
+    fn ops_bitwise() {
+        assert_eq!(
+            kinds("a&b|c^~d<<e>>f"),
+            vec![
+                TokenKind::Ident("a"),
+                TokenKind::Amp,
+                TokenKind::Ident("b"),
+                TokenKind::Pipe,
+                TokenKind::Ident("c"),
+                TokenKind::Caret,
+                TokenKind::Tilde,
+                TokenKind::Ident("d"),
+                TokenKind::Shl,
+                TokenKind::Ident("e"),
+                TokenKind::Shr,
+                TokenKind::Ident("f"),
+                TokenKind::Eof,
+            ]
+        );
+        assert_eq!(
+            kinds("a&&b||c<=d>=e"),
+            vec![
+                TokenKind::Ident("a"),
+                TokenKind::AndAnd,
+                TokenKind::Ident("b"),
+                TokenKind::OrOr,
+                TokenKind::Ident("c"),
+                TokenKind::Le,
+                TokenKind::Ident("d"),
+                TokenKind::Ge,
+                TokenKind::Ident("e"),
+                TokenKind::Eof,
+            ]
+        );
+    }
+
+    #[test]
+    fn byte_string_with_escapes() {
+        assert_eq!(
+            kinds(r#"b"\x00\xff""#),
+            vec![TokenKind::Bytes(vec![0x00, 0xff]), TokenKind::Eof]
+        );
+    }
+
+    #[test]
+    fn byte_literal() {
+        assert_eq!(kinds("b'A'"), vec![TokenKind::Byte(b'A'), TokenKind::Eof]);
+    }
+
+    #[test]
+    fn byte_escape_out_of_range() {
+        let err = Lexer::new(r#"b"\u{100}""#).tokenize().unwrap_err();
+        assert_eq!(err.kind, LexErrorKind::ByteOutOfRange);
+    }
+
+    #[test]
+    fn raw_string_with_hash_delimiters() {
+        assert_eq!(
+            kinds(r####"r#"x"#"####),
+            vec![TokenKind::Str("x".to_string()), TokenKind::Eof]
+        );
+    }
+
+    #[test]
+    fn lexing_from_an_offset_matches_the_tail_of_lexing_the_whole_source() {
+        let src = "let x = 1; let y = 2;";
+        let off = src.find("let y").unwrap();
+
+        let full = Lexer::new(src).tokenize().unwrap();
+        let from_start_index = full.iter().position(|t| t.span.start as usize == off).unwrap();
+
+        let resumed = Lexer::with_offset(src, LexerOptions::default(), off).unwrap().tokenize().unwrap();
+        assert_eq!(resumed, full[from_start_index..]);
+    }
+
+    #[test]
+    fn with_offset_rejects_a_non_char_boundary() {
+        let src = "let café = 1;";
+        let mid_of_e_acute = src.find('é').unwrap() + 1;
+        assert!(Lexer::with_offset(src, LexerOptions::default(), mid_of_e_acute).is_none());
+    }
+}
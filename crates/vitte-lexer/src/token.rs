@@ -0,0 +1,107 @@
+use vitte_core::Span;
+
+/// Reserved words recognised by [`crate::keyword_of`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Keyword {
+    Fn,
+    Let,
+    Const,
+    Struct,
+    Enum,
+    If,
+    Else,
+    While,
+    For,
+    In,
+    Return,
+    True,
+    False,
+}
+
+pub fn keyword_of(ident: &str) -> Option<Keyword> {
+    Some(match ident {
+        "fn" => Keyword::Fn,
+        "let" => Keyword::Let,
+        "const" => Keyword::Const,
+        "struct" => Keyword::Struct,
+        "enum" => Keyword::Enum,
+        "if" => Keyword::If,
+        "else" => Keyword::Else,
+        "while" => Keyword::While,
+        "for" => Keyword::For,
+        "in" => Keyword::In,
+        "return" => Keyword::Return,
+        "true" => Keyword::True,
+        "false" => Keyword::False,
+        _ => return None,
+    })
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenKind<'a> {
+    Ident(&'a str),
+    Kw(Keyword),
+    Int(i64, Option<&'a str>),
+    Float(f64, Option<&'a str>),
+    Str(String),
+    Bytes(Vec<u8>),
+    Byte(u8),
+    LineComment(&'a str),
+    BlockComment(&'a str),
+
+    // punctuation
+    Eq,
+    EqEq,
+    Bang,
+    BangEq,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    AndAnd,
+    OrOr,
+    Amp,
+    Pipe,
+    Caret,
+    Tilde,
+    Shl,
+    Shr,
+    Arrow,   // ->
+    FatArrow, // =>
+    Plus,
+    PlusEq,
+    Minus,
+    MinusEq,
+    Star,
+    StarEq,
+    Slash,
+    SlashEq,
+    Percent,
+    PercentEq,
+    Dot,
+    DotDot,
+    DotDotEq,
+    Comma,
+    Semi,
+    Colon,
+    LParen,
+    RParen,
+    LBrace,
+    RBrace,
+    LBracket,
+    RBracket,
+
+    Eof,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token<'a> {
+    pub kind: TokenKind<'a>,
+    pub span: Span,
+}
+
+impl<'a> Token<'a> {
+    pub fn new(kind: TokenKind<'a>, span: Span) -> Self {
+        Self { kind, span }
+    }
+}
@@ -0,0 +1,145 @@
+use vitte_ast::{Block, FnDecl, Item, Program, Stmt};
+use vitte_core::Pos;
+use vitte_lexer::Lexer;
+use vitte_parser::Parser;
+
+/// What a [`CompletionItem`] completes to, mirroring the categories LSP
+/// clients group completions by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompletionItemKind {
+    Keyword,
+    Snippet,
+    Function,
+    Variable,
+    Const,
+    Struct,
+    Enum,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompletionItem {
+    pub label: String,
+    pub kind: CompletionItemKind,
+}
+
+const KEYWORDS: &[&str] =
+    &["fn", "let", "const", "struct", "enum", "if", "else", "while", "for", "in", "return", "true", "false"];
+
+const SNIPPET_LABELS: &[&str] = &["fn", "let", "if", "while"];
+
+/// Completions available at byte offset `offset` in `src`: every reserved
+/// keyword and snippet, every top-level `fn`/`const`/`struct`/`enum`, and
+/// the locals (parameters and `let` bindings) visible at that point in the
+/// enclosing function.
+pub fn completions(src: &str, offset: Pos) -> Vec<CompletionItem> {
+    let mut items: Vec<CompletionItem> = KEYWORDS
+        .iter()
+        .map(|k| CompletionItem { label: k.to_string(), kind: CompletionItemKind::Keyword })
+        .chain(SNIPPET_LABELS.iter().map(|s| CompletionItem { label: s.to_string(), kind: CompletionItemKind::Snippet }))
+        .collect();
+
+    let (tokens, _lex_errors) = Lexer::new(src).tokenize_recover();
+    let (program, _parse_errors) = Parser::from_tokens(tokens).parse_program_recover();
+
+    for item in &program.items {
+        let (label, kind) = match item {
+            Item::Fn(f) => (f.name.clone(), CompletionItemKind::Function),
+            Item::Const(c) => (c.name.clone(), CompletionItemKind::Const),
+            Item::Struct(s) => (s.name.clone(), CompletionItemKind::Struct),
+            Item::Enum(e) => (e.name.clone(), CompletionItemKind::Enum),
+        };
+        items.push(CompletionItem { label, kind });
+    }
+
+    if let Some(f) = enclosing_fn(&program, offset) {
+        items.extend(
+            locals_visible_at(f, offset).into_iter().map(|name| CompletionItem { label: name, kind: CompletionItemKind::Variable }),
+        );
+    }
+
+    items
+}
+
+fn enclosing_fn(program: &Program, offset: Pos) -> Option<&FnDecl> {
+    program.items.iter().find_map(|item| match item {
+        Item::Fn(f) if f.span.contains(offset) => Some(f),
+        _ => None,
+    })
+}
+
+/// Parameters (visible throughout the function) plus every `let` whose
+/// binding appears before `offset` in the source. Like the rest of this
+/// toolchain, this doesn't model block scoping — a `let` is filtered by
+/// textual position only, not by which block it's nested in.
+fn locals_visible_at(f: &FnDecl, offset: Pos) -> Vec<String> {
+    let mut locals: Vec<String> = f.params.iter().map(|p| p.name.clone()).collect();
+    collect_lets_before(&f.body, offset, &mut locals);
+    locals
+}
+
+fn collect_lets_before(block: &Block, offset: Pos, locals: &mut Vec<String>) {
+    for stmt in &block.stmts {
+        collect_lets_before_stmt(stmt, offset, locals);
+    }
+}
+
+fn collect_lets_before_stmt(stmt: &Stmt, offset: Pos, locals: &mut Vec<String>) {
+    match stmt {
+        Stmt::Let { name, span, .. } => {
+            if span.start < offset {
+                locals.push(name.clone());
+            }
+        }
+        Stmt::If { then_branch, else_branch, .. } => {
+            collect_lets_before(then_branch, offset, locals);
+            if let Some(else_stmt) = else_branch {
+                collect_lets_before_stmt(else_stmt, offset, locals);
+            }
+        }
+        Stmt::While { body, .. } | Stmt::For { body, .. } => collect_lets_before(body, offset, locals),
+        Stmt::Block(block, _) => collect_lets_before(block, offset, locals),
+        Stmt::Expr(..) | Stmt::Return(..) | Stmt::Assign { .. } => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_local_declared_above_the_cursor_completes_as_a_variable() {
+        let src = "fn main() { let foo = 1; foo }\n";
+        let offset = src.rfind("foo }").unwrap() as Pos;
+        let items = completions(src, offset);
+        assert!(
+            items.iter().any(|item| item.label == "foo" && item.kind == CompletionItemKind::Variable),
+            "{items:?}"
+        );
+    }
+
+    #[test]
+    fn a_local_declared_below_the_cursor_is_not_offered_yet() {
+        let src = "fn main() { ; let foo = 1; }\n";
+        let offset = src.find(';').unwrap() as Pos;
+        let items = completions(src, offset);
+        assert!(!items.iter().any(|item| item.label == "foo"), "{items:?}");
+    }
+
+    #[test]
+    fn top_level_functions_complete_with_function_kind() {
+        let src = "fn add(a: i64, b: i64) -> i64 { a }\nfn main() { add(1, 2); }\n";
+        let offset = src.rfind("add(1").unwrap() as Pos;
+        let items = completions(src, offset);
+        assert!(
+            items.iter().any(|item| item.label == "add" && item.kind == CompletionItemKind::Function),
+            "{items:?}"
+        );
+    }
+
+    #[test]
+    fn keywords_and_snippets_are_always_offered() {
+        let items = completions("", 0);
+        assert!(items.iter().any(|item| item.label == "fn" && item.kind == CompletionItemKind::Keyword));
+        assert!(items.iter().any(|item| item.label == "fn" && item.kind == CompletionItemKind::Snippet));
+    }
+}
@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+use vitte_ast::{Block, Expr, FnDecl, Item, Stmt};
+use vitte_core::{Pos, Span};
+use vitte_lexer::Lexer;
+use vitte_parser::Parser;
+
+/// A resolvable declaration: the name and the span of just the identifier
+/// that introduces it, so jumping to it lands on the name rather than the
+/// whole declaration.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Definition {
+    pub name: String,
+    pub name_span: Span,
+}
+
+/// Resolves the identifier at byte offset `offset` in `src` to its
+/// declaration: a function-local `let` (shadowing same-named functions) or
+/// a top-level `fn`. Returns `None` for unknown names, builtins, or an
+/// offset that isn't on an identifier.
+pub fn goto_definition(src: &str, offset: Pos) -> Option<Definition> {
+    let (tokens, _lex_errors) = Lexer::new(src).tokenize_recover();
+    let (program, _parse_errors) = Parser::from_tokens(tokens).parse_program_recover();
+
+    let functions: HashMap<String, Definition> = program
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Fn(f) => Some((f.name.clone(), Definition { name: f.name.clone(), name_span: f.name_span })),
+            // const/struct/enum declarations aren't parsed yet.
+            Item::Const(_) | Item::Struct(_) | Item::Enum(_) => None,
+        })
+        .collect();
+
+    program.items.iter().find_map(|item| {
+        let Item::Fn(f) = item else { return None };
+        resolve_in_fn(f, offset, &functions)
+    })
+}
+
+fn resolve_in_fn(f: &FnDecl, offset: Pos, functions: &HashMap<String, Definition>) -> Option<Definition> {
+    if f.name_span.contains(offset) {
+        return Some(Definition { name: f.name.clone(), name_span: f.name_span });
+    }
+    let mut locals: HashMap<String, Definition> = HashMap::new();
+    for param in &f.params {
+        locals.insert(param.name.clone(), Definition { name: param.name.clone(), name_span: param.name_span });
+    }
+    let mut target = None;
+    walk_block(&f.body, offset, &mut locals, &mut target);
+    let name = target?;
+    locals.get(&name).or_else(|| functions.get(&name)).cloned()
+}
+
+/// Doesn't model block scoping, like the rest of the toolchain's passes
+/// (see `vitte-typer::TypeChecker`): every `let` in the function and every
+/// parameter is visible everywhere in it.
+fn walk_block(block: &Block, offset: Pos, locals: &mut HashMap<String, Definition>, target: &mut Option<String>) {
+    for stmt in &block.stmts {
+        walk_stmt(stmt, offset, locals, target);
+    }
+}
+
+fn walk_stmt(stmt: &Stmt, offset: Pos, locals: &mut HashMap<String, Definition>, target: &mut Option<String>) {
+    match stmt {
+        Stmt::Expr(expr, _) | Stmt::Return(Some(expr), _) => walk_expr(expr, offset, target),
+        Stmt::Return(None, _) => {}
+        Stmt::Let { name, name_span, value, .. } => {
+            locals.insert(name.clone(), Definition { name: name.clone(), name_span: *name_span });
+            walk_expr(value, offset, target);
+        }
+        Stmt::If { cond, then_branch, else_branch, .. } => {
+            walk_expr(cond, offset, target);
+            walk_block(then_branch, offset, locals, target);
+            if let Some(else_stmt) = else_branch {
+                walk_stmt(else_stmt, offset, locals, target);
+            }
+        }
+        Stmt::While { cond, body, .. } => {
+            walk_expr(cond, offset, target);
+            walk_block(body, offset, locals, target);
+        }
+        Stmt::For { iter, body, .. } => {
+            walk_expr(iter, offset, target);
+            walk_block(body, offset, locals, target);
+        }
+        Stmt::Block(block, _) => walk_block(block, offset, locals, target),
+        Stmt::Assign { target: lhs, value, .. } => {
+            walk_expr(lhs, offset, target);
+            walk_expr(value, offset, target);
+        }
+    }
+}
+
+fn walk_expr(expr: &Expr, offset: Pos, target: &mut Option<String>) {
+    if let Expr::Ident(name, span) = expr {
+        if span.contains(offset) {
+            *target = Some(name.clone());
+        }
+        return;
+    }
+    match expr {
+        Expr::Ident(..) | Expr::Int(..) | Expr::Float(..) | Expr::Str(..) | Expr::Bool(..) => {}
+        Expr::Unary { expr, .. } | Expr::Group(expr, _) => walk_expr(expr, offset, target),
+        Expr::Binary { lhs, rhs, .. } => {
+            walk_expr(lhs, offset, target);
+            walk_expr(rhs, offset, target);
+        }
+        Expr::Call { callee, args, .. } => {
+            walk_expr(callee, offset, target);
+            for arg in args {
+                walk_expr(arg, offset, target);
+            }
+        }
+        Expr::Field { expr, .. } => walk_expr(expr, offset, target),
+        Expr::Index { expr, index, .. } => {
+            walk_expr(expr, offset, target);
+            walk_expr(index, offset, target);
+        }
+        Expr::StructLit { fields, .. } => {
+            for (_, value) in fields {
+                walk_expr(value, offset, target);
+            }
+        }
+        Expr::Variant { value, .. } => walk_expr(value, offset, target),
+        Expr::Range { start, end, .. } => {
+            if let Some(start) = start {
+                walk_expr(start, offset, target);
+            }
+            if let Some(end) = end {
+                walk_expr(end, offset, target);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_call_site_resolves_to_the_functions_name_span() {
+        let src = "fn add(a: i64, b: i64) -> i64 { a }\nfn main() { add(1, 2); }\n";
+        let call_offset = src.find("add(1").unwrap() as Pos;
+        let def = goto_definition(src, call_offset).expect("add is declared");
+        assert_eq!(def.name, "add");
+        let name_start = src.find("add").unwrap() as Pos;
+        assert_eq!(def.name_span, Span::new(name_start, name_start + "add".len() as Pos));
+    }
+
+    #[test]
+    fn a_local_reference_resolves_to_its_let_binding_rather_than_a_same_named_function() {
+        let src = "fn add(a: i64, b: i64) -> i64 { a }\nfn main() { let add = 1; add; }\n";
+        let use_offset = src.rfind("add;").unwrap() as Pos;
+        let def = goto_definition(src, use_offset).expect("add is a local here");
+        let let_name_start = src.find("let add").unwrap() as Pos + "let ".len() as Pos;
+        assert_eq!(def.name_span, Span::new(let_name_start, let_name_start + "add".len() as Pos));
+    }
+
+    #[test]
+    fn a_parameter_reference_resolves_to_its_param_in_the_signature() {
+        let src = "fn add(a: i64, b: i64) -> i64 { a }\n";
+        let use_offset = src.rfind('a').unwrap() as Pos;
+        let def = goto_definition(src, use_offset).expect("a is a parameter here");
+        let param_start = src.find("a: i64").unwrap() as Pos;
+        assert_eq!(def.name_span, Span::new(param_start, param_start + "a".len() as Pos));
+    }
+
+    #[test]
+    fn an_unknown_name_resolves_to_nothing() {
+        let src = "fn main() { missing; }\n";
+        let offset = src.find("missing").unwrap() as Pos;
+        assert_eq!(goto_definition(src, offset), None);
+    }
+}
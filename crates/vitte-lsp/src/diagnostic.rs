@@ -0,0 +1,48 @@
+use vitte_core::{LineMap, Span};
+use vitte_lexer::LexError;
+use vitte_parser::ParseError;
+
+/// A single editor-facing diagnostic: a human-readable message plus the
+/// UTF-16 line/column range editors speaking the Language Server Protocol
+/// expect.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub message: String,
+    pub span: Span,
+    pub start: (u32, u32),
+    pub end: (u32, u32),
+}
+
+impl Diagnostic {
+    fn new(message: String, span: Span, src: &str, line_map: &LineMap) -> Self {
+        let start = line_map.line_col_utf16(src, span.start);
+        let end = line_map.line_col_utf16(src, span.end);
+        Self { message, span, start, end }
+    }
+
+    pub(crate) fn from_lex_error(error: &LexError, src: &str, line_map: &LineMap) -> Self {
+        Self::new(error.to_string(), error.span, src, line_map)
+    }
+
+    pub(crate) fn from_parse_error(error: &ParseError, src: &str, line_map: &LineMap) -> Self {
+        Self::new(error.to_string(), error.span, src, line_map)
+    }
+}
+
+/// Whether spans `a` and `b` cover any of the same bytes.
+fn spans_overlap(a: Span, b: Span) -> bool {
+    a.start < b.end && b.start < a.end
+}
+
+/// Drops every diagnostic whose span overlaps one already kept, preserving
+/// order — a parse error raised on top of a span the lexer already flagged
+/// is almost always just an echo of the same problem.
+pub(crate) fn dedupe_overlapping(diagnostics: Vec<Diagnostic>) -> Vec<Diagnostic> {
+    let mut kept: Vec<Diagnostic> = Vec::new();
+    for diagnostic in diagnostics {
+        if !kept.iter().any(|k| spans_overlap(k.span, diagnostic.span)) {
+            kept.push(diagnostic);
+        }
+    }
+    kept
+}
@@ -0,0 +1,122 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use vitte_ast::Program;
+use vitte_lexer::Lexer;
+use vitte_parser::Parser;
+use vitte_typer::SymbolTable;
+
+#[cfg(test)]
+use std::cell::Cell;
+
+/// The parsed artifacts every LSP feature (hover, completion, go-to-def,
+/// ...) needs: the AST and the top-level function signatures collected
+/// from it.
+///
+/// The raw token vector isn't kept here: every consumer in this crate
+/// already turns tokens into a `Program` and discards them within the same
+/// function call, so there's nothing downstream that would actually reuse
+/// a cached `Vec<Token>` — only the owned, lifetime-free results are worth
+/// keeping around.
+#[derive(Debug)]
+pub struct Analysis {
+    pub program: Program,
+    pub symbols: SymbolTable,
+}
+
+struct Cached {
+    version: i32,
+    analysis: Rc<Analysis>,
+}
+
+/// An open editor buffer. Tracks the text and the client's `version`
+/// counter, and lazily recomputes [`Analysis`] on first access after each
+/// edit rather than on every keystroke.
+pub struct Document {
+    text: String,
+    version: i32,
+    cache: RefCell<Option<Cached>>,
+    #[cfg(test)]
+    recompute_count: Cell<u32>,
+}
+
+impl Document {
+    pub fn new(text: String, version: i32) -> Self {
+        Self {
+            text,
+            version,
+            cache: RefCell::new(None),
+            #[cfg(test)]
+            recompute_count: Cell::new(0),
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn version(&self) -> i32 {
+        self.version
+    }
+
+    /// Replaces the whole document and invalidates the cache. This crate
+    /// only deals in full-document syncs, so there's no incremental-range
+    /// patching to do here — just recording the new text and version.
+    pub fn apply_change(&mut self, text: String, version: i32) {
+        self.text = text;
+        self.version = version;
+        *self.cache.borrow_mut() = None;
+    }
+
+    /// Returns the cached [`Analysis`] for the current `version`, recomputing
+    /// it only the first time it's asked for since the last [`Self::apply_change`].
+    pub fn analysis(&self) -> Rc<Analysis> {
+        if let Some(cached) = self.cache.borrow().as_ref() {
+            if cached.version == self.version {
+                return cached.analysis.clone();
+            }
+        }
+
+        #[cfg(test)]
+        self.recompute_count.set(self.recompute_count.get() + 1);
+
+        let (tokens, _lex_errors) = Lexer::new(&self.text).tokenize_recover();
+        let (program, _parse_errors) = Parser::from_tokens(tokens).parse_program_recover();
+        let symbols = SymbolTable::collect(&program);
+        let analysis = Rc::new(Analysis { program, symbols });
+        *self.cache.borrow_mut() = Some(Cached { version: self.version, analysis: analysis.clone() });
+        analysis
+    }
+
+    #[cfg(test)]
+    pub fn recompute_count(&self) -> u32 {
+        self.recompute_count.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn two_hovers_at_the_same_version_reuse_the_cached_analysis() {
+        let doc = Document::new("fn main() { let x = 1; }\n".to_string(), 1);
+        doc.analysis();
+        doc.analysis();
+        assert_eq!(doc.recompute_count(), 1);
+    }
+
+    #[test]
+    fn apply_change_invalidates_the_cache() {
+        let mut doc = Document::new("fn main() { let x = 1; }\n".to_string(), 1);
+        doc.analysis();
+        doc.apply_change("fn main() { let x = 2; }\n".to_string(), 2);
+        doc.analysis();
+        assert_eq!(doc.recompute_count(), 2);
+    }
+
+    #[test]
+    fn analysis_reflects_the_current_text() {
+        let doc = Document::new("fn add(a: i64, b: i64) -> i64 { a }\n".to_string(), 1);
+        assert!(doc.analysis().symbols.resolve("add").is_some());
+    }
+}
@@ -0,0 +1,98 @@
+use vitte_ast::{Block, Item, Stmt};
+use vitte_core::Span;
+use vitte_lexer::Lexer;
+use vitte_parser::Parser;
+
+/// A symbol an editor's outline view should show, with its children nested
+/// underneath rather than flattened alongside it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentSymbol {
+    pub name: String,
+    /// The symbol's full extent, e.g. the whole `fn ... { ... }`.
+    pub range: Span,
+    /// The span of just the name, for the outline entry editors highlight
+    /// when the cursor is on the declaration.
+    pub selection_range: Span,
+    pub children: Vec<DocumentSymbol>,
+}
+
+/// Parses `src` and builds the hierarchical outline: one entry per top-level
+/// `fn`, with its function-local `let` bindings nested underneath in
+/// declaration order.
+///
+/// Unlike a lexer-only pass that pairs a keyword with whatever identifier
+/// comes next, this walks the real AST, so it can't mistake `let x =
+/// struct_like` for a struct declaration — `struct_like` is just the value
+/// expression of an ordinary `let`.
+pub fn document_symbols(src: &str) -> Vec<DocumentSymbol> {
+    let (tokens, _lex_errors) = Lexer::new(src).tokenize_recover();
+    let (program, _parse_errors) = Parser::from_tokens(tokens).parse_program_recover();
+
+    program
+        .items
+        .iter()
+        .map(|item| match item {
+            Item::Fn(f) => {
+                let mut children = Vec::new();
+                collect_block(&f.body, &mut children);
+                DocumentSymbol { name: f.name.clone(), range: f.span, selection_range: f.name_span, children }
+            }
+            // const/struct/enum declarations aren't parsed yet.
+            Item::Const(_) | Item::Struct(_) | Item::Enum(_) => unreachable!("parser only produces Item::Fn"),
+        })
+        .collect()
+}
+
+fn collect_block(block: &Block, out: &mut Vec<DocumentSymbol>) {
+    for stmt in &block.stmts {
+        collect_stmt(stmt, out);
+    }
+}
+
+fn collect_stmt(stmt: &Stmt, out: &mut Vec<DocumentSymbol>) {
+    match stmt {
+        Stmt::Let { name, name_span, span, .. } => {
+            out.push(DocumentSymbol { name: name.clone(), range: *span, selection_range: *name_span, children: Vec::new() });
+        }
+        Stmt::If { then_branch, else_branch, .. } => {
+            collect_block(then_branch, out);
+            if let Some(else_stmt) = else_branch {
+                collect_stmt(else_stmt, out);
+            }
+        }
+        Stmt::While { body, .. } | Stmt::For { body, .. } => collect_block(body, out),
+        Stmt::Block(block, _) => collect_block(block, out),
+        Stmt::Expr(..) | Stmt::Return(..) | Stmt::Assign { .. } => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_function_contains_its_let_bindings_as_children() {
+        let src = "fn main() { let a = 1; let b = 2; a + b; }\n";
+        let symbols = document_symbols(src);
+        assert_eq!(symbols.len(), 1, "{symbols:?}");
+        assert_eq!(symbols[0].name, "main");
+        let children: Vec<_> = symbols[0].children.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(children, vec!["a", "b"], "{symbols:?}");
+    }
+
+    #[test]
+    fn a_let_binding_to_an_identifier_named_struct_like_is_not_misclassified() {
+        let src = "fn main() { let x = struct_like; }\n";
+        let symbols = document_symbols(src);
+        assert_eq!(symbols[0].children.len(), 1, "{symbols:?}");
+        assert_eq!(symbols[0].children[0].name, "x");
+    }
+
+    #[test]
+    fn a_let_nested_inside_an_if_is_still_a_child_of_the_enclosing_function() {
+        let src = "fn main() { if true { let a = 1; } }\n";
+        let symbols = document_symbols(src);
+        assert_eq!(symbols[0].children.len(), 1, "{symbols:?}");
+        assert_eq!(symbols[0].children[0].name, "a");
+    }
+}
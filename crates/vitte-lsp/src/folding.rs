@@ -0,0 +1,86 @@
+use vitte_core::{LineMap, Span};
+use vitte_lexer::{Lexer, LexerOptions, TokenKind};
+
+/// What a [`FoldingRange`] collapses: a `{}` block, or a multi-line comment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FoldingRangeKind {
+    Region,
+    Comment,
+}
+
+/// A collapsible range of source lines, 1-indexed to match the rest of
+/// this crate's line numbers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FoldingRange {
+    pub start_line: u32,
+    pub end_line: u32,
+    pub kind: FoldingRangeKind,
+}
+
+/// Scans `src`'s tokens for matching brace pairs and block comments that
+/// span more than one line, in the order their closing token appears.
+pub fn folding_ranges(src: &str) -> Vec<FoldingRange> {
+    let line_map = LineMap::new(src);
+    let opts = LexerOptions { emit_comments: true, ..LexerOptions::default() };
+    let (tokens, _lex_errors) = Lexer::with_options(src, opts).tokenize_recover();
+
+    let mut ranges = Vec::new();
+    let mut open_braces: Vec<Span> = Vec::new();
+    for token in &tokens {
+        match token.kind {
+            TokenKind::LBrace => open_braces.push(token.span),
+            TokenKind::RBrace => {
+                if let Some(open) = open_braces.pop() {
+                    push_if_multiline(&mut ranges, &line_map, open.start, token.span.start, FoldingRangeKind::Region);
+                }
+            }
+            TokenKind::BlockComment(_) => {
+                push_if_multiline(&mut ranges, &line_map, token.span.start, token.span.end, FoldingRangeKind::Comment);
+            }
+            _ => {}
+        }
+    }
+    ranges
+}
+
+fn push_if_multiline(ranges: &mut Vec<FoldingRange>, line_map: &LineMap, start: u32, end: u32, kind: FoldingRangeKind) {
+    let (start_line, _) = line_map.line_col(start);
+    let (end_line, _) = line_map.line_col(end);
+    if end_line > start_line {
+        ranges.push(FoldingRange { start_line, end_line, kind });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_two_line_function_body_yields_one_fold() {
+        let src = "fn main() {\n    let x = 1;\n}\n";
+        let ranges = folding_ranges(src);
+        assert_eq!(ranges, vec![FoldingRange { start_line: 1, end_line: 3, kind: FoldingRangeKind::Region }]);
+    }
+
+    #[test]
+    fn a_multiline_block_comment_yields_a_comment_fold() {
+        let src = "/*\n * hello\n */\nfn main() {}\n";
+        let ranges = folding_ranges(src);
+        assert_eq!(ranges, vec![FoldingRange { start_line: 1, end_line: 3, kind: FoldingRangeKind::Comment }]);
+    }
+
+    #[test]
+    fn a_single_line_block_never_folds() {
+        let src = "fn main() { let x = 1; }\n";
+        assert!(folding_ranges(src).is_empty());
+    }
+
+    #[test]
+    fn nested_blocks_each_get_their_own_fold() {
+        let src = "fn main() {\n    if true {\n        1;\n    }\n}\n";
+        let ranges = folding_ranges(src);
+        assert_eq!(ranges.len(), 2);
+        assert!(ranges.contains(&FoldingRange { start_line: 1, end_line: 5, kind: FoldingRangeKind::Region }));
+        assert!(ranges.contains(&FoldingRange { start_line: 2, end_line: 4, kind: FoldingRangeKind::Region }));
+    }
+}
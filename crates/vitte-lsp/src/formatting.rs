@@ -0,0 +1,85 @@
+use vitte_core::Span;
+use vitte_fmt::format_source;
+
+/// The subset of LSP's `FormattingOptions` plus `formatOnType`-adjacent
+/// client preferences that `format_document` can act on.
+///
+/// `vitte-fmt` always indents with four spaces (see `vitte_fmt::printer`),
+/// so `tab_size`/`insert_spaces` have nothing to plug into yet and are
+/// accepted but unused; they're kept here so callers don't have to special
+/// case this crate once the formatter grows configurable indentation.
+#[derive(Debug, Clone, Copy)]
+pub struct FormattingOptions {
+    pub tab_size: u32,
+    pub insert_spaces: bool,
+    /// Collapse multiple blank lines at the end of the file down to none.
+    pub trim_final_newlines: bool,
+    /// Ensure the file ends with exactly one newline.
+    pub insert_final_newline: bool,
+}
+
+/// A replacement for the whole document, in the byte range it covers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextEdit {
+    pub range: Span,
+    pub new_text: String,
+}
+
+/// Formats `src` with `vitte-fmt` and applies the client's final-newline
+/// preferences, returning a single full-document edit only when the result
+/// actually differs from `src`.
+pub fn format_document(src: &str, options: &FormattingOptions) -> Option<TextEdit> {
+    let mut formatted = format_source(src);
+
+    if options.trim_final_newlines {
+        while formatted.ends_with('\n') {
+            formatted.pop();
+        }
+    }
+    if options.insert_final_newline && !formatted.ends_with('\n') {
+        formatted.push('\n');
+    }
+
+    if formatted == src {
+        return None;
+    }
+    Some(TextEdit { range: Span::new(0, src.len() as u32), new_text: formatted })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options() -> FormattingOptions {
+        FormattingOptions { tab_size: 4, insert_spaces: true, trim_final_newlines: false, insert_final_newline: true }
+    }
+
+    #[test]
+    fn a_poorly_indented_function_matches_vitte_fmts_own_output() {
+        let src = "fn main() {\nlet x = 1;\n        let y = 2;\n}\n";
+        let edit = format_document(src, &options()).expect("badly indented source needs an edit");
+        assert_eq!(edit.new_text, format_source(src));
+        assert_eq!(edit.range, Span::new(0, src.len() as u32));
+    }
+
+    #[test]
+    fn already_formatted_source_produces_no_edit() {
+        let src = format_source("fn main() {\nlet x = 1;\n}\n");
+        assert_eq!(format_document(&src, &options()), None);
+    }
+
+    #[test]
+    fn trim_final_newlines_strips_trailing_blank_lines() {
+        let src = "fn main() {\n    let x = 1;\n}\n\n\n";
+        let opts = FormattingOptions { trim_final_newlines: true, insert_final_newline: false, ..options() };
+        let edit = format_document(src, &opts).expect("trailing blank lines need trimming");
+        assert!(!edit.new_text.ends_with('\n'), "{:?}", edit.new_text);
+    }
+
+    #[test]
+    fn insert_final_newline_adds_a_trailing_newline_when_missing() {
+        let src = "fn main() {\n    let x = 1;\n}";
+        let edit = format_document(src, &options()).expect("missing trailing newline needs an edit");
+        assert!(edit.new_text.ends_with('\n'), "{:?}", edit.new_text);
+    }
+}
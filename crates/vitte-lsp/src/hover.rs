@@ -0,0 +1,76 @@
+use crate::definition::goto_definition;
+use vitte_core::Pos;
+use vitte_lexer::{Lexer, Token, TokenKind};
+use vitte_parser::Parser;
+use vitte_typer::SymbolTable;
+
+/// Markdown hover text for the identifier or token at byte offset `offset`
+/// in `src`. For an identifier that resolves to a declared function, shows
+/// its signature (e.g. `` `fn add(int, int) -> int` ``); otherwise falls
+/// back to the lexical kind of whatever token sits at `offset`.
+pub fn hover_contents(src: &str, offset: Pos) -> Option<String> {
+    hover_function_signature(src, offset).or_else(|| hover_lexical(src, offset))
+}
+
+fn hover_function_signature(src: &str, offset: Pos) -> Option<String> {
+    let def = goto_definition(src, offset)?;
+    let (tokens, _lex_errors) = Lexer::new(src).tokenize_recover();
+    let (program, _parse_errors) = Parser::from_tokens(tokens).parse_program_recover();
+    let symbols = SymbolTable::collect(&program);
+    let sig = symbols.resolve(&def.name)?;
+    Some(format!("```\nfn {}{sig}\n```", def.name))
+}
+
+/// Falls back to naming the lexical category of the token under `offset`,
+/// for punctuation, literals, and anything else type resolution can't
+/// explain (keywords, unresolved names, syntax errors).
+fn hover_lexical(src: &str, offset: Pos) -> Option<String> {
+    let (tokens, _lex_errors) = Lexer::new(src).tokenize_recover();
+    let token = tokens.iter().find(|t| t.span.contains(offset))?;
+    Some(format!("`{}`", lexical_kind_label(token)))
+}
+
+fn lexical_kind_label(token: &Token<'_>) -> &'static str {
+    match token.kind {
+        TokenKind::Ident(_) => "identifier",
+        TokenKind::Kw(_) => "keyword",
+        TokenKind::Int(..) => "integer literal",
+        TokenKind::Float(..) => "float literal",
+        TokenKind::Str(_) => "string literal",
+        TokenKind::Bytes(_) => "byte string literal",
+        TokenKind::Byte(_) => "byte literal",
+        TokenKind::LineComment(_) | TokenKind::BlockComment(_) => "comment",
+        TokenKind::Eof => "end of file",
+        _ => "punctuation",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hovering_a_function_name_shows_its_signature() {
+        let src = "fn add(a: i64, b: i64) -> i64 { a }\nfn main() { add(1, 2); }\n";
+        let offset = src.rfind("add(1").unwrap() as Pos;
+        let hover = hover_contents(src, offset).expect("add is declared");
+        assert!(hover.contains("fn add"), "{hover}");
+        assert!(hover.contains("-> int"), "{hover}");
+    }
+
+    #[test]
+    fn hovering_a_keyword_falls_back_to_its_lexical_kind() {
+        let src = "fn main() {}\n";
+        let offset = src.find("fn").unwrap() as Pos;
+        let hover = hover_contents(src, offset).expect("fn is a token");
+        assert_eq!(hover, "`keyword`");
+    }
+
+    #[test]
+    fn hovering_an_unresolved_name_falls_back_to_its_lexical_kind() {
+        let src = "fn main() { missing; }\n";
+        let offset = src.find("missing").unwrap() as Pos;
+        let hover = hover_contents(src, offset).expect("missing is at least an identifier token");
+        assert_eq!(hover, "`identifier`");
+    }
+}
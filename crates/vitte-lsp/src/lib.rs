@@ -0,0 +1,68 @@
+//! Turns a Vitte source file into the diagnostics an editor should show,
+//! without stopping at the first problem: both the lexer and the parser
+//! are run in recovery mode so a file with several unrelated mistakes gets
+//! a squiggle under each of them in one pass.
+
+mod completion;
+mod definition;
+mod diagnostic;
+mod document;
+mod document_symbols;
+mod folding;
+mod formatting;
+mod hover;
+mod rename;
+mod semantic;
+mod signature_help;
+
+pub use completion::{completions, CompletionItem, CompletionItemKind};
+pub use definition::{goto_definition, Definition};
+pub use diagnostic::Diagnostic;
+pub use document::{Analysis, Document};
+pub use document_symbols::{document_symbols, DocumentSymbol};
+pub use folding::{folding_ranges, FoldingRange, FoldingRangeKind};
+pub use formatting::{format_document, FormattingOptions, TextEdit};
+pub use hover::hover_contents;
+pub use rename::{rename, RenameError};
+pub use semantic::{encode, semantic_tokens, EncodedToken, SemanticToken, SemanticTokenType, LEGEND};
+pub use signature_help::{signature_help, SignatureInformation};
+
+use diagnostic::dedupe_overlapping;
+use vitte_core::LineMap;
+use vitte_lexer::Lexer;
+use vitte_parser::Parser;
+
+/// Lexes and parses `src` with recovery, collecting every diagnostic
+/// instead of stopping at the first one, and deduping ranges the lexer and
+/// parser both flagged.
+pub fn compute_diagnostics(src: &str) -> Vec<Diagnostic> {
+    let line_map = LineMap::new(src);
+    let (tokens, lex_errors) = Lexer::new(src).tokenize_recover();
+    let mut diagnostics: Vec<Diagnostic> =
+        lex_errors.iter().map(|e| Diagnostic::from_lex_error(e, src, &line_map)).collect();
+
+    let (_program, parse_errors) = Parser::from_tokens(tokens).parse_program_recover();
+    diagnostics.extend(parse_errors.iter().map(|e| Diagnostic::from_parse_error(e, src, &line_map)));
+
+    dedupe_overlapping(diagnostics)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn two_unrelated_bad_tokens_produce_two_diagnostics() {
+        let src = "fn f() { let x = 1; @ let y = 2; $ }";
+        let diagnostics = compute_diagnostics(src);
+        assert_eq!(diagnostics.len(), 2, "{diagnostics:?}");
+        assert!(diagnostics[0].message.contains('@'), "{diagnostics:?}");
+        assert!(diagnostics[1].message.contains('$'), "{diagnostics:?}");
+    }
+
+    #[test]
+    fn a_clean_file_has_no_diagnostics() {
+        let diagnostics = compute_diagnostics("fn f() { let a = 1; }");
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+    }
+}
@@ -0,0 +1,321 @@
+use crate::definition::{goto_definition, Definition};
+use crate::formatting::TextEdit;
+use std::collections::HashMap;
+use std::fmt;
+use vitte_ast::{Block, Expr, Item, Program, Stmt};
+use vitte_core::{Pos, Span};
+use vitte_lexer::Lexer;
+use vitte_parser::Parser;
+
+/// Why [`rename`] couldn't produce an edit.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RenameError {
+    /// The cursor wasn't on an identifier that resolves to a declaration —
+    /// a keyword, a literal, whitespace, or an unresolved name.
+    NotRenamable,
+}
+
+impl fmt::Display for RenameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RenameError::NotRenamable => write!(f, "the symbol under the cursor can't be renamed"),
+        }
+    }
+}
+
+impl std::error::Error for RenameError {}
+
+/// Renames the identifier at byte offset `offset` in `src` to `new_name`,
+/// returning an edit for its declaration plus every reference to it.
+///
+/// A renamed top-level `fn` updates its declaration and every call site
+/// across the document that resolves to it (via [`goto_definition`], which
+/// already prefers a same-named local over a same-named function). A
+/// renamed local is resolved with its own lexical scope walk so an inner
+/// `let x` shadowing an outer one is a distinct declaration — renaming one
+/// never edits the other.
+pub fn rename(src: &str, offset: Pos, new_name: &str) -> Result<Vec<TextEdit>, RenameError> {
+    let target = goto_definition(src, offset).ok_or(RenameError::NotRenamable)?;
+
+    let (tokens, _lex_errors) = Lexer::new(src).tokenize_recover();
+    let (program, _parse_errors) = Parser::from_tokens(tokens).parse_program_recover();
+
+    let is_function = program.items.iter().any(|item| matches!(item, Item::Fn(f) if f.name_span == target.name_span));
+
+    let mut edits =
+        if is_function { rename_function(src, &program, &target, new_name) } else { rename_local(&program, &target, new_name) };
+
+    edits.sort_by_key(|edit| edit.range.start);
+    Ok(edits)
+}
+
+fn rename_function(src: &str, program: &Program, target: &Definition, new_name: &str) -> Vec<TextEdit> {
+    let mut edits = vec![TextEdit { range: target.name_span, new_text: new_name.to_string() }];
+    for item in &program.items {
+        let Item::Fn(f) = item else { continue };
+        let mut refs = Vec::new();
+        collect_idents_in_block(&f.body, &mut refs);
+        for span in refs {
+            if goto_definition(src, span.start).is_some_and(|def| def.name_span == target.name_span) {
+                edits.push(TextEdit { range: span, new_text: new_name.to_string() });
+            }
+        }
+    }
+    edits
+}
+
+/// Scope-aware rename of a local: seeds the outermost scope with the
+/// declaring function's parameters, then walks its body in order, tracking
+/// which `let` bindings are visible at each point via a stack of scopes
+/// pushed/popped around each [`Block`], so resolution reflects real
+/// lexical shadowing rather than the simplified flat-locals model the rest
+/// of this toolchain uses (see `vitte_typer::TypeChecker`).
+fn rename_local(program: &Program, target: &Definition, new_name: &str) -> Vec<TextEdit> {
+    let Some(f) = program.items.iter().find_map(|item| match item {
+        Item::Fn(f) if f.span.contains(target.name_span.start) => Some(f),
+        _ => None,
+    }) else {
+        return Vec::new();
+    };
+
+    let mut edits = Vec::new();
+    let mut scopes: Vec<HashMap<String, Span>> = vec![HashMap::new()];
+    for param in &f.params {
+        if param.name_span == target.name_span {
+            edits.push(TextEdit { range: param.name_span, new_text: new_name.to_string() });
+        }
+        scopes[0].insert(param.name.clone(), param.name_span);
+    }
+    walk_block_scoped(&f.body, target, new_name, &mut scopes, &mut edits);
+    edits
+}
+
+fn resolve_in_scopes(scopes: &[HashMap<String, Span>], name: &str) -> Option<Span> {
+    scopes.iter().rev().find_map(|scope| scope.get(name).copied())
+}
+
+fn walk_block_scoped(block: &Block, target: &Definition, new_name: &str, scopes: &mut Vec<HashMap<String, Span>>, edits: &mut Vec<TextEdit>) {
+    scopes.push(HashMap::new());
+    for stmt in &block.stmts {
+        walk_stmt_scoped(stmt, target, new_name, scopes, edits);
+    }
+    scopes.pop();
+}
+
+fn walk_stmt_scoped(stmt: &Stmt, target: &Definition, new_name: &str, scopes: &mut Vec<HashMap<String, Span>>, edits: &mut Vec<TextEdit>) {
+    match stmt {
+        Stmt::Expr(expr, _) | Stmt::Return(Some(expr), _) => walk_expr_scoped(expr, target, new_name, scopes, edits),
+        Stmt::Return(None, _) => {}
+        Stmt::Let { name, name_span, value, .. } => {
+            walk_expr_scoped(value, target, new_name, scopes, edits);
+            if *name_span == target.name_span {
+                edits.push(TextEdit { range: *name_span, new_text: new_name.to_string() });
+            }
+            scopes.last_mut().expect("at least one scope").insert(name.clone(), *name_span);
+        }
+        Stmt::If { cond, then_branch, else_branch, .. } => {
+            walk_expr_scoped(cond, target, new_name, scopes, edits);
+            walk_block_scoped(then_branch, target, new_name, scopes, edits);
+            if let Some(else_stmt) = else_branch {
+                walk_stmt_scoped(else_stmt, target, new_name, scopes, edits);
+            }
+        }
+        Stmt::While { cond, body, .. } => {
+            walk_expr_scoped(cond, target, new_name, scopes, edits);
+            walk_block_scoped(body, target, new_name, scopes, edits);
+        }
+        Stmt::For { iter, body, .. } => {
+            walk_expr_scoped(iter, target, new_name, scopes, edits);
+            walk_block_scoped(body, target, new_name, scopes, edits);
+        }
+        Stmt::Block(block, _) => walk_block_scoped(block, target, new_name, scopes, edits),
+        Stmt::Assign { target: lhs, value, .. } => {
+            walk_expr_scoped(lhs, target, new_name, scopes, edits);
+            walk_expr_scoped(value, target, new_name, scopes, edits);
+        }
+    }
+}
+
+fn walk_expr_scoped(expr: &Expr, target: &Definition, new_name: &str, scopes: &mut Vec<HashMap<String, Span>>, edits: &mut Vec<TextEdit>) {
+    if let Expr::Ident(name, span) = expr {
+        if resolve_in_scopes(scopes, name) == Some(target.name_span) {
+            edits.push(TextEdit { range: *span, new_text: new_name.to_string() });
+        }
+        return;
+    }
+    match expr {
+        Expr::Ident(..) | Expr::Int(..) | Expr::Float(..) | Expr::Str(..) | Expr::Bool(..) => {}
+        Expr::Unary { expr, .. } | Expr::Group(expr, _) => walk_expr_scoped(expr, target, new_name, scopes, edits),
+        Expr::Binary { lhs, rhs, .. } => {
+            walk_expr_scoped(lhs, target, new_name, scopes, edits);
+            walk_expr_scoped(rhs, target, new_name, scopes, edits);
+        }
+        Expr::Call { callee, args, .. } => {
+            walk_expr_scoped(callee, target, new_name, scopes, edits);
+            for arg in args {
+                walk_expr_scoped(arg, target, new_name, scopes, edits);
+            }
+        }
+        Expr::Field { expr, .. } => walk_expr_scoped(expr, target, new_name, scopes, edits),
+        Expr::Index { expr, index, .. } => {
+            walk_expr_scoped(expr, target, new_name, scopes, edits);
+            walk_expr_scoped(index, target, new_name, scopes, edits);
+        }
+        Expr::StructLit { fields, .. } => {
+            for (_, value) in fields {
+                walk_expr_scoped(value, target, new_name, scopes, edits);
+            }
+        }
+        Expr::Variant { value, .. } => walk_expr_scoped(value, target, new_name, scopes, edits),
+        Expr::Range { start, end, .. } => {
+            if let Some(start) = start {
+                walk_expr_scoped(start, target, new_name, scopes, edits);
+            }
+            if let Some(end) = end {
+                walk_expr_scoped(end, target, new_name, scopes, edits);
+            }
+        }
+    }
+}
+
+/// Every `Expr::Ident` span reachable from `block`, used by
+/// [`rename_function`] to find call sites — it doesn't need scope
+/// tracking since [`goto_definition`] already applies the right
+/// local-shadows-function precedence per occurrence.
+fn collect_idents_in_block(block: &Block, out: &mut Vec<Span>) {
+    for stmt in &block.stmts {
+        collect_idents_in_stmt(stmt, out);
+    }
+}
+
+fn collect_idents_in_stmt(stmt: &Stmt, out: &mut Vec<Span>) {
+    match stmt {
+        Stmt::Expr(expr, _) | Stmt::Return(Some(expr), _) => collect_idents_in_expr(expr, out),
+        Stmt::Return(None, _) => {}
+        Stmt::Let { value, .. } => collect_idents_in_expr(value, out),
+        Stmt::If { cond, then_branch, else_branch, .. } => {
+            collect_idents_in_expr(cond, out);
+            collect_idents_in_block(then_branch, out);
+            if let Some(else_stmt) = else_branch {
+                collect_idents_in_stmt(else_stmt, out);
+            }
+        }
+        Stmt::While { cond, body, .. } => {
+            collect_idents_in_expr(cond, out);
+            collect_idents_in_block(body, out);
+        }
+        Stmt::For { iter, body, .. } => {
+            collect_idents_in_expr(iter, out);
+            collect_idents_in_block(body, out);
+        }
+        Stmt::Block(block, _) => collect_idents_in_block(block, out),
+        Stmt::Assign { target, value, .. } => {
+            collect_idents_in_expr(target, out);
+            collect_idents_in_expr(value, out);
+        }
+    }
+}
+
+fn collect_idents_in_expr(expr: &Expr, out: &mut Vec<Span>) {
+    if let Expr::Ident(_, span) = expr {
+        out.push(*span);
+        return;
+    }
+    match expr {
+        Expr::Ident(..) | Expr::Int(..) | Expr::Float(..) | Expr::Str(..) | Expr::Bool(..) => {}
+        Expr::Unary { expr, .. } | Expr::Group(expr, _) => collect_idents_in_expr(expr, out),
+        Expr::Binary { lhs, rhs, .. } => {
+            collect_idents_in_expr(lhs, out);
+            collect_idents_in_expr(rhs, out);
+        }
+        Expr::Call { callee, args, .. } => {
+            collect_idents_in_expr(callee, out);
+            for arg in args {
+                collect_idents_in_expr(arg, out);
+            }
+        }
+        Expr::Field { expr, .. } => collect_idents_in_expr(expr, out),
+        Expr::Index { expr, index, .. } => {
+            collect_idents_in_expr(expr, out);
+            collect_idents_in_expr(index, out);
+        }
+        Expr::StructLit { fields, .. } => {
+            for (_, value) in fields {
+                collect_idents_in_expr(value, out);
+            }
+        }
+        Expr::Variant { value, .. } => collect_idents_in_expr(value, out),
+        Expr::Range { start, end, .. } => {
+            if let Some(start) = start {
+                collect_idents_in_expr(start, out);
+            }
+            if let Some(end) = end {
+                collect_idents_in_expr(end, out);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renaming_a_local_edits_the_declaration_and_both_uses() {
+        let src = "fn main() { let x = 1; x; x; }\n";
+        let use_offset = src.rfind("x; }").unwrap() as Pos;
+        let edits = rename(src, use_offset, "y").expect("x is a renamable local");
+        assert_eq!(edits.len(), 3, "{edits:?}");
+        assert!(edits.iter().all(|e| e.new_text == "y"));
+
+        let decl_start = src.find("let x").unwrap() as Pos + "let ".len() as Pos;
+        assert_eq!(edits[0].range, Span::new(decl_start, decl_start + 1));
+    }
+
+    #[test]
+    fn an_inner_shadowing_local_does_not_rename_the_outer_one() {
+        let src = "fn main() { let x = 1; { let x = 2; x; } x; }\n";
+        let inner_use = src[src.find('{').unwrap() + 1..].find("x;").unwrap() + src.find('{').unwrap() + 1;
+        let edits = rename(src, inner_use as Pos, "y").expect("inner x is renamable");
+        // Only the inner declaration and the reference inside its own
+        // block should move; the final `x;` outside the block resolves to
+        // the outer declaration and must stay untouched.
+        assert_eq!(edits.len(), 2, "{edits:?}");
+        let outer_use_start = src.rfind("x; }").unwrap() as Pos;
+        assert!(!edits.iter().any(|e| e.range.start == outer_use_start));
+    }
+
+    #[test]
+    fn renaming_a_parameter_edits_the_signature_and_its_uses() {
+        let src = "fn add(a: i64, b: i64) -> i64 { a + a }\n";
+        let use_offset = src.rfind('a').unwrap() as Pos;
+        let edits = rename(src, use_offset, "x").expect("a is a renamable parameter");
+        assert_eq!(edits.len(), 3, "{edits:?}");
+        assert!(edits.iter().all(|e| e.new_text == "x"));
+
+        let decl_start = src.find("a: i64").unwrap() as Pos;
+        assert_eq!(edits[0].range, Span::new(decl_start, decl_start + 1));
+    }
+
+    #[test]
+    fn renaming_a_function_updates_its_declaration_and_call_sites() {
+        let src = "fn add(a: i64, b: i64) -> i64 { a }\nfn main() { add(1, 2); add(3, 4); }\n";
+        let offset = src.find("fn add").unwrap() as Pos + "fn ".len() as Pos;
+        let edits = rename(src, offset, "sum").expect("add is a renamable function");
+        assert_eq!(edits.len(), 3, "{edits:?}");
+    }
+
+    #[test]
+    fn renaming_a_keyword_is_rejected() {
+        let src = "fn main() {}\n";
+        let offset = src.find("fn").unwrap() as Pos;
+        assert_eq!(rename(src, offset, "whatever"), Err(RenameError::NotRenamable));
+    }
+
+    #[test]
+    fn renaming_a_literal_is_rejected() {
+        let src = "fn main() { 1; }\n";
+        let offset = src.find('1').unwrap() as Pos;
+        assert_eq!(rename(src, offset, "whatever"), Err(RenameError::NotRenamable));
+    }
+}
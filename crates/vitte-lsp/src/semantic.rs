@@ -0,0 +1,225 @@
+use vitte_core::LineMap;
+use vitte_lexer::{Keyword, Lexer, LexerOptions, Token, TokenKind};
+
+/// The semantic token categories this crate classifies, in the order
+/// clients should index into when building their `SemanticTokensLegend`.
+pub const LEGEND: &[SemanticTokenType] = &[
+    SemanticTokenType::Keyword,
+    SemanticTokenType::Function,
+    SemanticTokenType::Variable,
+    SemanticTokenType::Parameter,
+    SemanticTokenType::Number,
+    SemanticTokenType::String,
+    SemanticTokenType::Comment,
+    SemanticTokenType::Operator,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SemanticTokenType {
+    Keyword,
+    Function,
+    Variable,
+    Parameter,
+    Number,
+    String,
+    Comment,
+    Operator,
+}
+
+impl SemanticTokenType {
+    /// Index into [`LEGEND`], which is what clients encode in each tuple.
+    fn legend_index(self) -> u32 {
+        LEGEND.iter().position(|t| *t == self).expect("every variant appears in LEGEND") as u32
+    }
+}
+
+/// One classified token, in the 1-indexed `(line, column)` coordinates
+/// [`LineMap::line_col_utf16`] produces.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SemanticToken {
+    pub line: u32,
+    pub start_char: u32,
+    pub length: u32,
+    pub token_type: SemanticTokenType,
+}
+
+/// A `(delta_line, delta_start_char, length, token_type)` tuple as LSP's
+/// `semanticTokens/full` response encodes them: `delta_line` is relative to
+/// the previous token's line, and `delta_start_char` is relative to the
+/// previous token's start column when the two share a line, or absolute
+/// otherwise.
+pub type EncodedToken = (u32, u32, u32, u32);
+
+/// Lexes `src` and classifies every token an editor should highlight,
+/// splitting multiline block comments into one token per line so each line
+/// gets its own range.
+pub fn semantic_tokens(src: &str) -> Vec<SemanticToken> {
+    let line_map = LineMap::new(src);
+    let opts = LexerOptions { emit_comments: true, ..LexerOptions::default() };
+    let (tokens, _errors) = Lexer::with_options(src, opts).tokenize_recover();
+
+    let mut out = Vec::new();
+    for (i, token) in tokens.iter().enumerate() {
+        let prev_kind = i.checked_sub(1).map(|j| &tokens[j].kind);
+        let next_kind = tokens.get(i + 1).map(|t| &t.kind);
+        if let Some(token_type) = classify(&token.kind, prev_kind, next_kind) {
+            push_token(&mut out, src, &line_map, token, token_type);
+        }
+    }
+    out
+}
+
+fn push_token(
+    out: &mut Vec<SemanticToken>,
+    src: &str,
+    line_map: &LineMap,
+    token: &Token<'_>,
+    token_type: SemanticTokenType,
+) {
+    let text = &src[token.span.start as usize..token.span.end as usize];
+    if !text.contains('\n') {
+        let (line, start_char) = line_map.line_col_utf16(src, token.span.start);
+        let length = text.chars().map(char::len_utf16).sum::<usize>() as u32;
+        out.push(SemanticToken { line, start_char, length, token_type });
+        return;
+    }
+
+    // A multiline block comment: emit one token per physical line so each
+    // line gets its own highlighted range instead of one range that editors
+    // would have to split themselves.
+    let (first_line, first_col) = line_map.line_col_utf16(src, token.span.start);
+    for (offset, line_text) in text.split('\n').enumerate() {
+        let line = first_line + offset as u32;
+        let start_char = if offset == 0 { first_col } else { 1 };
+        let length = line_text.chars().map(char::len_utf16).sum::<usize>() as u32;
+        out.push(SemanticToken { line, start_char, length, token_type });
+    }
+}
+
+fn classify(
+    kind: &TokenKind<'_>,
+    prev: Option<&TokenKind<'_>>,
+    next: Option<&TokenKind<'_>>,
+) -> Option<SemanticTokenType> {
+    use SemanticTokenType::*;
+    match kind {
+        TokenKind::Kw(_) => Some(Keyword),
+        TokenKind::Int(..) | TokenKind::Float(..) => Some(Number),
+        TokenKind::Str(_) | TokenKind::Bytes(_) | TokenKind::Byte(_) => Some(String),
+        TokenKind::LineComment(_) | TokenKind::BlockComment(_) => Some(Comment),
+        TokenKind::Ident(_) => classify_ident(prev, next),
+        TokenKind::Eq
+        | TokenKind::EqEq
+        | TokenKind::Bang
+        | TokenKind::BangEq
+        | TokenKind::Lt
+        | TokenKind::Le
+        | TokenKind::Gt
+        | TokenKind::Ge
+        | TokenKind::AndAnd
+        | TokenKind::OrOr
+        | TokenKind::Amp
+        | TokenKind::Pipe
+        | TokenKind::Caret
+        | TokenKind::Tilde
+        | TokenKind::Shl
+        | TokenKind::Shr
+        | TokenKind::Plus
+        | TokenKind::PlusEq
+        | TokenKind::Minus
+        | TokenKind::MinusEq
+        | TokenKind::Star
+        | TokenKind::StarEq
+        | TokenKind::Slash
+        | TokenKind::SlashEq
+        | TokenKind::Percent
+        | TokenKind::PercentEq => Some(Operator),
+        // Structural punctuation (braces, parens, commas, `.`/`:`/`->`/`=>`)
+        // isn't highlighted as a semantic token; TextMate grammars already
+        // handle it fine.
+        _ => None,
+    }
+}
+
+/// An identifier is a `function` when it's declared (`fn name`) or called
+/// (`name(`), a `parameter` when it's a `name:` inside a parameter list
+/// (directly after `(` or `,`), unclassified (the legend has no `type`
+/// category) right after a `:` since that's a type name rather than a
+/// binding, and a `variable` otherwise.
+fn classify_ident(prev: Option<&TokenKind<'_>>, next: Option<&TokenKind<'_>>) -> Option<SemanticTokenType> {
+    if matches!(prev, Some(TokenKind::Kw(Keyword::Fn))) || matches!(next, Some(TokenKind::LParen)) {
+        return Some(SemanticTokenType::Function);
+    }
+    if matches!(prev, Some(TokenKind::LParen) | Some(TokenKind::Comma)) && matches!(next, Some(TokenKind::Colon)) {
+        return Some(SemanticTokenType::Parameter);
+    }
+    if matches!(prev, Some(TokenKind::Colon)) {
+        return None;
+    }
+    Some(SemanticTokenType::Variable)
+}
+
+/// Delta-encodes `tokens` as LSP's `semanticTokens/full` response expects.
+pub fn encode(tokens: &[SemanticToken]) -> Vec<EncodedToken> {
+    let mut out = Vec::with_capacity(tokens.len());
+    let mut prev_line = 0;
+    let mut prev_start_char = 0;
+    for token in tokens {
+        let delta_line = token.line - prev_line;
+        let delta_start_char = if delta_line == 0 { token.start_char - prev_start_char } else { token.start_char };
+        out.push((delta_line, delta_start_char, token.length, token.token_type.legend_index()));
+        prev_line = token.line;
+        prev_start_char = token.start_char;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn let_and_its_number_literal_are_classified() {
+        let tokens = semantic_tokens("let x = 1;");
+        assert_eq!(tokens[0].token_type, SemanticTokenType::Keyword, "{tokens:?}");
+        assert_eq!(tokens.last().unwrap().token_type, SemanticTokenType::Number, "{tokens:?}");
+
+        let encoded = encode(&tokens);
+        let keyword_index = LEGEND.iter().position(|t| *t == SemanticTokenType::Keyword).unwrap() as u32;
+        let number_index = LEGEND.iter().position(|t| *t == SemanticTokenType::Number).unwrap() as u32;
+        assert_eq!(encoded[0], (1, 1, 3, keyword_index), "{encoded:?}");
+        assert_eq!(encoded.last().copied().unwrap().3, number_index, "{encoded:?}");
+    }
+
+    #[test]
+    fn a_function_declaration_and_its_call_are_both_classified_as_functions() {
+        let tokens = semantic_tokens("fn add(a: i64) { add(1); }");
+        let functions: Vec<_> = tokens.iter().filter(|t| t.token_type == SemanticTokenType::Function).collect();
+        assert_eq!(functions.len(), 2, "{tokens:?}");
+    }
+
+    #[test]
+    fn a_parameter_name_is_classified_separately_from_a_variable() {
+        let tokens = semantic_tokens("fn add(a: i64) { let b = a; }");
+        assert_eq!(
+            tokens.iter().filter(|t| t.token_type == SemanticTokenType::Parameter).count(),
+            1,
+            "{tokens:?}"
+        );
+        assert_eq!(
+            tokens.iter().filter(|t| t.token_type == SemanticTokenType::Variable).count(),
+            2,
+            "{tokens:?}"
+        );
+    }
+
+    #[test]
+    fn a_multiline_block_comment_is_split_into_one_token_per_line() {
+        let tokens = semantic_tokens("/* line one\nline two */");
+        let comments: Vec<_> = tokens.iter().filter(|t| t.token_type == SemanticTokenType::Comment).collect();
+        assert_eq!(comments.len(), 2, "{tokens:?}");
+        assert_eq!(comments[0].line, 1);
+        assert_eq!(comments[1].line, 2);
+        assert_eq!(comments[1].start_char, 1);
+    }
+}
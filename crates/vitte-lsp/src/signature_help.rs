@@ -0,0 +1,170 @@
+use vitte_ast::{Expr, FnDecl, Item};
+use vitte_core::Pos;
+use vitte_lexer::Lexer;
+use vitte_parser::Parser;
+use vitte_typer::TypeId;
+
+/// A function signature plus which of its parameters the cursor is
+/// currently inside, for editors to render while the user types a call.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignatureInformation {
+    /// `name(param: Type, param: Type) -> Type`.
+    pub label: String,
+    /// `"param: Type"` for each parameter, in declaration order.
+    pub parameters: Vec<String>,
+    pub active_parameter: u32,
+}
+
+/// Parses `src`, finds the call whose argument list `offset` falls inside,
+/// resolves its callee to a declared function, and returns that function's
+/// signature with `active_parameter` set to the number of commas already
+/// typed before `offset`. Returns `None` if `offset` isn't inside a call to
+/// a known function.
+pub fn signature_help(src: &str, offset: Pos) -> Option<SignatureInformation> {
+    let (tokens, _lex_errors) = Lexer::new(src).tokenize_recover();
+    let (program, _parse_errors) = Parser::from_tokens(tokens).parse_program_recover();
+
+    let functions: Vec<&FnDecl> = program.items.iter().filter_map(|item| match item {
+        Item::Fn(f) => Some(f),
+        Item::Const(_) | Item::Struct(_) | Item::Enum(_) => None,
+    }).collect();
+
+    let mut found = None;
+    for f in &functions {
+        find_enclosing_call(&f.body.stmts, offset, &mut found);
+    }
+    let (callee_name, args) = found?;
+
+    let callee = functions.iter().find(|f| f.name == callee_name)?;
+    let active_parameter = args.iter().filter(|arg| vitte_ast::WithSpan::span(*arg).end <= offset).count();
+    let active_parameter = active_parameter.min(callee.params.len().saturating_sub(1)) as u32;
+
+    let parameters: Vec<String> = callee
+        .params
+        .iter()
+        .map(|p| format!("{}: {}", p.name, TypeId::from_ast(&p.ty)))
+        .collect();
+    let ret = callee.ret.as_ref().map(TypeId::from_ast).unwrap_or(TypeId::Unit);
+    let label = format!("{}({}) -> {ret}", callee.name, parameters.join(", "));
+
+    Some(SignatureInformation { label, parameters, active_parameter })
+}
+
+/// Finds the innermost `Expr::Call` whose argument list (the span strictly
+/// after the callee, up to and including the closing paren) contains
+/// `offset`, recording its callee name and args into `found`. Walks
+/// statements and expressions the same way `definition`/`document_symbols`
+/// do, since none of these passes model real scoping beyond "everything in
+/// the function is visible."
+fn find_enclosing_call<'a>(
+    stmts: &'a [vitte_ast::Stmt],
+    offset: Pos,
+    found: &mut Option<(String, &'a [Expr])>,
+) {
+    for stmt in stmts {
+        walk_stmt(stmt, offset, found);
+    }
+}
+
+fn walk_stmt<'a>(stmt: &'a vitte_ast::Stmt, offset: Pos, found: &mut Option<(String, &'a [Expr])>) {
+    use vitte_ast::Stmt;
+    match stmt {
+        Stmt::Expr(expr, _) | Stmt::Return(Some(expr), _) => walk_expr(expr, offset, found),
+        Stmt::Return(None, _) => {}
+        Stmt::Let { value, .. } => walk_expr(value, offset, found),
+        Stmt::If { cond, then_branch, else_branch, .. } => {
+            walk_expr(cond, offset, found);
+            find_enclosing_call(&then_branch.stmts, offset, found);
+            if let Some(else_stmt) = else_branch {
+                walk_stmt(else_stmt, offset, found);
+            }
+        }
+        Stmt::While { cond, body, .. } => {
+            walk_expr(cond, offset, found);
+            find_enclosing_call(&body.stmts, offset, found);
+        }
+        Stmt::For { iter, body, .. } => {
+            walk_expr(iter, offset, found);
+            find_enclosing_call(&body.stmts, offset, found);
+        }
+        Stmt::Block(block, _) => find_enclosing_call(&block.stmts, offset, found),
+        Stmt::Assign { target, value, .. } => {
+            walk_expr(target, offset, found);
+            walk_expr(value, offset, found);
+        }
+    }
+}
+
+fn walk_expr<'a>(expr: &'a Expr, offset: Pos, found: &mut Option<(String, &'a [Expr])>) {
+    use vitte_ast::WithSpan;
+    if let Expr::Call { callee, args, span } = expr {
+        let args_start = callee.span().end;
+        if args_start <= offset && offset <= span.end {
+            if let Expr::Ident(name, _) = callee.as_ref() {
+                *found = Some((name.clone(), args));
+            }
+        }
+        walk_expr(callee, offset, found);
+        for arg in args {
+            walk_expr(arg, offset, found);
+        }
+        return;
+    }
+    match expr {
+        Expr::Ident(..) | Expr::Int(..) | Expr::Float(..) | Expr::Str(..) | Expr::Bool(..) => {}
+        Expr::Unary { expr, .. } | Expr::Group(expr, _) => walk_expr(expr, offset, found),
+        Expr::Binary { lhs, rhs, .. } => {
+            walk_expr(lhs, offset, found);
+            walk_expr(rhs, offset, found);
+        }
+        Expr::Call { .. } => unreachable!("handled above"),
+        Expr::Field { expr, .. } => walk_expr(expr, offset, found),
+        Expr::Index { expr, index, .. } => {
+            walk_expr(expr, offset, found);
+            walk_expr(index, offset, found);
+        }
+        Expr::StructLit { fields, .. } => {
+            for (_, value) in fields {
+                walk_expr(value, offset, found);
+            }
+        }
+        Expr::Variant { value, .. } => walk_expr(value, offset, found),
+        Expr::Range { start, end, .. } => {
+            if let Some(start) = start {
+                walk_expr(start, offset, found);
+            }
+            if let Some(end) = end {
+                walk_expr(end, offset, found);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_after_the_first_comma_of_a_three_arg_call_selects_the_second_parameter() {
+        let src = "fn add3(a: i64, b: i64, c: i64) -> i64 { a }\nfn main() { add3(1, 2, 3); }\n";
+        let offset = src.find("1,").unwrap() as Pos + "1,".len() as Pos;
+        let help = signature_help(src, offset).expect("inside a known call");
+        assert_eq!(help.active_parameter, 1, "{help:?}");
+        assert_eq!(help.label, "add3(a: int, b: int, c: int) -> int");
+    }
+
+    #[test]
+    fn cursor_before_any_comma_selects_the_first_parameter() {
+        let src = "fn add(a: i64, b: i64) -> i64 { a }\nfn main() { add(1, 2); }\n";
+        let offset = src.find("1, 2").unwrap() as Pos;
+        let help = signature_help(src, offset).expect("inside a known call");
+        assert_eq!(help.active_parameter, 0, "{help:?}");
+    }
+
+    #[test]
+    fn a_call_to_an_unknown_function_has_no_signature_help() {
+        let src = "fn main() { missing(1, 2); }\n";
+        let offset = src.find("1, 2").unwrap() as Pos;
+        assert_eq!(signature_help(src, offset), None);
+    }
+}
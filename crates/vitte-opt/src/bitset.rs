@@ -0,0 +1,218 @@
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign};
+
+/// A growable set of small non-negative integers, stored as 64-bit words.
+/// Used by passes like liveness that need fast union/intersection over
+/// dense value-id ranges without the overhead of a hash set.
+#[derive(Debug, Clone, Default)]
+pub struct BitSet {
+    words: Vec<u64>,
+}
+
+impl PartialEq for BitSet {
+    // Two sets with a different number of (all-zero) trailing words still
+    // represent the same bits, so compare word-by-word up to the longer
+    // operand's length rather than deriving equality on the raw `Vec`.
+    fn eq(&self, other: &Self) -> bool {
+        let n = self.words.len().max(other.words.len());
+        (0..n).all(|i| self.words.get(i).copied().unwrap_or(0) == other.words.get(i).copied().unwrap_or(0))
+    }
+}
+
+impl Eq for BitSet {}
+
+impl BitSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity(bits: usize) -> Self {
+        Self { words: vec![0; bits.div_ceil(64)] }
+    }
+
+    pub fn insert(&mut self, bit: usize) {
+        let (word, offset) = (bit / 64, bit % 64);
+        if self.words.len() <= word {
+            self.words.resize(word + 1, 0);
+        }
+        self.words[word] |= 1 << offset;
+    }
+
+    pub fn remove(&mut self, bit: usize) {
+        let (word, offset) = (bit / 64, bit % 64);
+        if let Some(w) = self.words.get_mut(word) {
+            *w &= !(1 << offset);
+        }
+    }
+
+    pub fn contains(&self, bit: usize) -> bool {
+        let (word, offset) = (bit / 64, bit % 64);
+        self.words.get(word).is_some_and(|w| w & (1 << offset) != 0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Removes every bit also present in `other`, word-wise, without
+    /// allocating a new set.
+    pub fn difference_with(&mut self, other: &BitSet) {
+        for (a, &b) in self.words.iter_mut().zip(other.words.iter()) {
+            *a &= !b;
+        }
+    }
+
+    /// Whether every bit set in `self` is also set in `other`.
+    pub fn is_subset(&self, other: &BitSet) -> bool {
+        self.words.iter().enumerate().all(|(i, &w)| {
+            let ow = other.words.get(i).copied().unwrap_or(0);
+            w & !ow == 0
+        })
+    }
+
+    /// `(self & other).count_ones()`, computed word-wise without
+    /// materializing the intersection.
+    pub fn intersect_count(&self, other: &BitSet) -> usize {
+        self.words.iter().zip(other.words.iter()).map(|(&a, &b)| (a & b).count_ones() as usize).sum()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &w)| (0..64).filter(move |&b| w & (1 << b) != 0).map(move |b| i * 64 + b))
+    }
+}
+
+impl BitOrAssign<&BitSet> for BitSet {
+    /// Unions in place. `other` may have more or fewer words than `self`;
+    /// `self` grows to fit rather than dropping `other`'s extra bits.
+    fn bitor_assign(&mut self, other: &BitSet) {
+        if other.words.len() > self.words.len() {
+            self.words.resize(other.words.len(), 0);
+        }
+        for (a, &b) in self.words.iter_mut().zip(other.words.iter()) {
+            *a |= b;
+        }
+    }
+}
+
+impl BitAndAssign<&BitSet> for BitSet {
+    /// Intersects in place. A word `other` doesn't have is treated as all
+    /// zero bits rather than being left untouched, so bits beyond
+    /// `other`'s length are correctly cleared.
+    fn bitand_assign(&mut self, other: &BitSet) {
+        for (i, a) in self.words.iter_mut().enumerate() {
+            *a &= other.words.get(i).copied().unwrap_or(0);
+        }
+    }
+}
+
+impl BitOr<&BitSet> for BitSet {
+    type Output = BitSet;
+    fn bitor(mut self, other: &BitSet) -> BitSet {
+        self |= other;
+        self
+    }
+}
+
+impl BitAnd<&BitSet> for BitSet {
+    type Output = BitSet;
+    fn bitand(mut self, other: &BitSet) -> BitSet {
+        self &= other;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(bits: &[usize]) -> BitSet {
+        let mut s = BitSet::new();
+        for &b in bits {
+            s.insert(b);
+        }
+        s
+    }
+
+    #[test]
+    fn insert_remove_and_contains() {
+        let mut s = BitSet::new();
+        s.insert(3);
+        s.insert(70);
+        assert!(s.contains(3));
+        assert!(s.contains(70));
+        assert!(!s.contains(4));
+        s.remove(3);
+        assert!(!s.contains(3));
+    }
+
+    #[test]
+    fn count_ones_counts_across_words() {
+        let s = set(&[0, 1, 63, 64, 128]);
+        assert_eq!(s.count_ones(), 5);
+    }
+
+    #[test]
+    fn difference_with_removes_shared_bits() {
+        let mut a = set(&[1, 2, 3, 100]);
+        let b = set(&[2, 3]);
+        a.difference_with(&b);
+        assert_eq!(a, set(&[1, 100]));
+    }
+
+    #[test]
+    fn is_subset_true_and_false() {
+        let small = set(&[1, 2]);
+        let big = set(&[1, 2, 3]);
+        assert!(small.is_subset(&big));
+        assert!(!big.is_subset(&small));
+    }
+
+    #[test]
+    fn intersect_count_without_materializing() {
+        let a = set(&[1, 2, 65]);
+        let b = set(&[2, 65, 66]);
+        assert_eq!(a.intersect_count(&b), 2);
+    }
+
+    #[test]
+    fn bitor_handles_mismatched_lengths_in_both_directions() {
+        let short = set(&[1]);
+        let long = set(&[1, 200]);
+
+        let mut a = short.clone();
+        a |= &long;
+        assert_eq!(a, set(&[1, 200]));
+
+        let mut b = long.clone();
+        b |= &short;
+        assert_eq!(b, set(&[1, 200]));
+    }
+
+    #[test]
+    fn bitand_clears_bits_beyond_the_shorter_operands_length() {
+        // Regression: a naive `zip`-based `&=` stops at the shorter
+        // operand's word count and leaves the longer operand's extra
+        // high-order bits untouched instead of clearing them.
+        let mut a = set(&[1, 200]);
+        let b = set(&[1]);
+        a &= &b;
+        assert_eq!(a, set(&[1]));
+    }
+
+    #[test]
+    fn bitor_and_bitand_operators_match_their_assign_forms() {
+        let a = set(&[1, 2, 3]);
+        let b = set(&[2, 3, 4]);
+        assert_eq!(a.clone() | &b, set(&[1, 2, 3, 4]));
+        assert_eq!(a & &b, set(&[2, 3]));
+    }
+
+    #[test]
+    fn iter_yields_every_set_bit_in_order() {
+        let s = set(&[0, 64, 130]);
+        assert_eq!(s.iter().collect::<Vec<_>>(), vec![0, 64, 130]);
+    }
+}
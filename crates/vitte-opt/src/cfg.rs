@@ -0,0 +1,84 @@
+/// A control-flow graph over `n` basic blocks, identified by index. Edges
+/// are added explicitly; blocks with no incoming edges besides `entry` are
+/// simply unreachable.
+#[derive(Debug, Clone)]
+pub struct Cfg {
+    pub entry: usize,
+    succs: Vec<Vec<usize>>,
+    preds: Vec<Vec<usize>>,
+}
+
+impl Cfg {
+    pub fn new(num_blocks: usize, entry: usize) -> Self {
+        Self { entry, succs: vec![Vec::new(); num_blocks], preds: vec![Vec::new(); num_blocks] }
+    }
+
+    pub fn len(&self) -> usize {
+        self.succs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.succs.is_empty()
+    }
+
+    pub fn add_edge(&mut self, from: usize, to: usize) {
+        self.succs[from].push(to);
+        self.preds[to].push(from);
+    }
+
+    pub fn succs(&self, block: usize) -> &[usize] {
+        &self.succs[block]
+    }
+
+    pub fn preds(&self, block: usize) -> &[usize] {
+        &self.preds[block]
+    }
+
+    /// Reverse-postorder over the blocks reachable from `entry`. Blocks
+    /// unreachable from `entry` are omitted.
+    pub(crate) fn reverse_postorder(&self) -> Vec<usize> {
+        let mut visited = vec![false; self.len()];
+        let mut postorder = Vec::new();
+        let mut frames: Vec<(usize, usize)> = vec![(self.entry, 0)];
+        visited[self.entry] = true;
+        while let Some(&mut (node, ref mut next)) = frames.last_mut() {
+            if *next < self.succs[node].len() {
+                let child = self.succs[node][*next];
+                *next += 1;
+                if !visited[child] {
+                    visited[child] = true;
+                    frames.push((child, 0));
+                }
+            } else {
+                postorder.push(node);
+                frames.pop();
+            }
+        }
+        postorder.reverse();
+        postorder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reverse_postorder_starts_at_the_entry() {
+        let mut cfg = Cfg::new(4, 0);
+        cfg.add_edge(0, 1);
+        cfg.add_edge(0, 2);
+        cfg.add_edge(1, 3);
+        cfg.add_edge(2, 3);
+        let rpo = cfg.reverse_postorder();
+        assert_eq!(rpo[0], 0);
+        assert_eq!(rpo.len(), 4);
+    }
+
+    #[test]
+    fn reverse_postorder_skips_unreachable_blocks() {
+        let cfg = Cfg::new(3, 0);
+        // Block 2 has no edges at all, so it's unreachable from the entry.
+        assert_eq!(cfg.reverse_postorder(), vec![0]);
+    }
+}
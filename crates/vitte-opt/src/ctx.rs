@@ -0,0 +1,90 @@
+use crate::{Cfg, Dominators, Loops, PostDominators};
+
+/// Per-CFG analysis cache, so multiple passes can share expensive analyses
+/// like dominators instead of each recomputing them. A pass that mutates
+/// the CFG's edges must call the matching `invalidate_*` method afterwards.
+pub struct Ctx<'a> {
+    cfg: &'a Cfg,
+    doms: Option<Dominators>,
+    post_doms: Option<PostDominators>,
+    loops: Option<Loops>,
+}
+
+impl<'a> Ctx<'a> {
+    pub fn new(cfg: &'a Cfg) -> Self {
+        Self { cfg, doms: None, post_doms: None, loops: None }
+    }
+
+    pub fn doms(&mut self) -> &Dominators {
+        self.doms.get_or_insert_with(|| Dominators::compute_fast(self.cfg))
+    }
+
+    pub fn invalidate_doms(&mut self) {
+        self.doms = None;
+    }
+
+    pub fn post_doms(&mut self) -> &PostDominators {
+        self.post_doms.get_or_insert_with(|| PostDominators::compute(self.cfg))
+    }
+
+    pub fn invalidate_post_doms(&mut self) {
+        self.post_doms = None;
+    }
+
+    pub fn loops(&mut self) -> &Loops {
+        if self.loops.is_none() {
+            let cfg = self.cfg;
+            let doms = self.doms();
+            self.loops = Some(Loops::compute(cfg, doms));
+        }
+        self.loops.as_ref().unwrap()
+    }
+
+    pub fn invalidate_loops(&mut self) {
+        self.loops = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diamond() -> Cfg {
+        let mut cfg = Cfg::new(4, 0);
+        cfg.add_edge(0, 1);
+        cfg.add_edge(0, 2);
+        cfg.add_edge(1, 3);
+        cfg.add_edge(2, 3);
+        cfg
+    }
+
+    #[test]
+    fn doms_and_post_doms_are_cached_and_stay_correct_after_invalidation() {
+        let cfg = diamond();
+        let mut ctx = Ctx::new(&cfg);
+
+        assert!(ctx.doms().dominates(0, 3));
+        assert!(ctx.doms().dominates(0, 3)); // second call hits the cache
+
+        assert!(ctx.post_doms().post_dominates(3, 1));
+
+        ctx.invalidate_doms();
+        assert!(ctx.doms().dominates(0, 3));
+
+        ctx.invalidate_post_doms();
+        assert!(ctx.post_doms().post_dominates(3, 1));
+    }
+
+    #[test]
+    fn loops_are_cached_and_stay_correct_after_invalidation() {
+        let mut cfg = diamond();
+        cfg.add_edge(3, 0);
+        let mut ctx = Ctx::new(&cfg);
+
+        assert_eq!(ctx.loops().len(), 1);
+        assert_eq!(ctx.loops().len(), 1); // second call hits the cache
+
+        ctx.invalidate_loops();
+        assert_eq!(ctx.loops().len(), 1);
+    }
+}
@@ -0,0 +1,239 @@
+use crate::Cfg;
+
+const UNREACHABLE: usize = usize::MAX;
+
+/// The immediate-dominator relation over a [`Cfg`]'s blocks reachable from
+/// its entry.
+pub struct Dominators {
+    idom: Vec<usize>,
+}
+
+impl Dominators {
+    /// Classic iterative dataflow fixpoint: a dominator-set bitset per
+    /// block, refined until nothing changes, followed by an O(n) idom
+    /// extraction per block. Simple and easy to trust, but quadratic in the
+    /// block count — kept around to cross-check [`Dominators::compute_fast`].
+    pub fn compute(cfg: &Cfg) -> Self {
+        let n = cfg.len();
+        let entry = cfg.entry;
+
+        let mut dom = vec![vec![false; n]; n];
+        for (b, set) in dom.iter_mut().enumerate() {
+            if b == entry {
+                set[entry] = true;
+            } else {
+                *set = vec![true; n];
+            }
+        }
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for b in 0..n {
+                if b == entry {
+                    continue;
+                }
+                let preds = cfg.preds(b);
+                let mut new_dom = match preds.split_first() {
+                    None => vec![false; n],
+                    Some((&first, rest)) => {
+                        let mut acc = dom[first].clone();
+                        for &p in rest {
+                            for i in 0..n {
+                                acc[i] = acc[i] && dom[p][i];
+                            }
+                        }
+                        acc
+                    }
+                };
+                new_dom[b] = true;
+                if new_dom != dom[b] {
+                    dom[b] = new_dom;
+                    changed = true;
+                }
+            }
+        }
+
+        let mut idom = vec![UNREACHABLE; n];
+        idom[entry] = entry;
+        for (b, dom_b) in dom.iter().enumerate() {
+            if b == entry || !dom_b[entry] {
+                continue; // entry itself, or unreachable
+            }
+            let best = (0..n)
+                .filter(|&d| d != b && dom_b[d])
+                .max_by_key(|&d| dom[d].iter().filter(|&&x| x).count());
+            if let Some(best) = best {
+                idom[b] = best;
+            }
+        }
+        Self { idom }
+    }
+
+    /// The Cooper-Harvey-Kennedy iterative dominator algorithm over a
+    /// reverse-postorder numbering. Produces the same `idom` map as
+    /// [`Dominators::compute`] but converges in only a few passes on
+    /// typical (reducible) CFGs, without ever materializing a dominator set.
+    pub fn compute_fast(cfg: &Cfg) -> Self {
+        let n = cfg.len();
+        let entry = cfg.entry;
+        let rpo = cfg.reverse_postorder();
+
+        let mut rpo_number = vec![UNREACHABLE; n];
+        for (i, &b) in rpo.iter().enumerate() {
+            rpo_number[b] = i;
+        }
+
+        let mut idom = vec![UNREACHABLE; n];
+        idom[entry] = entry;
+
+        let intersect = |idom: &[usize], mut a: usize, mut b: usize| -> usize {
+            while a != b {
+                while rpo_number[a] > rpo_number[b] {
+                    a = idom[a];
+                }
+                while rpo_number[b] > rpo_number[a] {
+                    b = idom[b];
+                }
+            }
+            a
+        };
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for &b in &rpo {
+                if b == entry {
+                    continue;
+                }
+                let mut new_idom = UNREACHABLE;
+                for &p in cfg.preds(b) {
+                    if idom[p] == UNREACHABLE {
+                        continue;
+                    }
+                    new_idom = if new_idom == UNREACHABLE { p } else { intersect(&idom, new_idom, p) };
+                }
+                if new_idom != UNREACHABLE && idom[b] != new_idom {
+                    idom[b] = new_idom;
+                    changed = true;
+                }
+            }
+        }
+
+        Self { idom }
+    }
+
+    /// The immediate dominator of `block`, or `None` if it's the entry or
+    /// unreachable.
+    pub fn idom(&self, block: usize) -> Option<usize> {
+        match self.idom[block] {
+            UNREACHABLE => None,
+            d if d == block => None,
+            d => Some(d),
+        }
+    }
+
+    /// Whether every path from the entry to `b` passes through `a`. A block
+    /// always dominates itself.
+    pub fn dominates(&self, a: usize, b: usize) -> bool {
+        if a == b {
+            return true;
+        }
+        let mut node = b;
+        loop {
+            if self.idom[node] == UNREACHABLE {
+                return false;
+            }
+            if self.idom[node] == node {
+                return false; // reached the entry without finding `a`
+            }
+            node = self.idom[node];
+            if node == a {
+                return true;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diamond_cfg_idom_is_the_branch_point() {
+        // 0 -> 1, 0 -> 2, 1 -> 3, 2 -> 3
+        let mut cfg = Cfg::new(4, 0);
+        cfg.add_edge(0, 1);
+        cfg.add_edge(0, 2);
+        cfg.add_edge(1, 3);
+        cfg.add_edge(2, 3);
+        let dom = Dominators::compute(&cfg);
+        assert_eq!(dom.idom(3), Some(0));
+        assert!(dom.dominates(0, 3));
+        assert!(!dom.dominates(1, 3));
+    }
+
+    #[test]
+    fn compute_fast_agrees_with_compute_on_a_diamond() {
+        let mut cfg = Cfg::new(4, 0);
+        cfg.add_edge(0, 1);
+        cfg.add_edge(0, 2);
+        cfg.add_edge(1, 3);
+        cfg.add_edge(2, 3);
+        let slow = Dominators::compute(&cfg);
+        let fast = Dominators::compute_fast(&cfg);
+        for a in 0..4 {
+            for b in 0..4 {
+                assert_eq!(slow.dominates(a, b), fast.dominates(a, b));
+            }
+        }
+    }
+
+    struct TestRng(u64);
+
+    impl TestRng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, n: usize) -> usize {
+            (self.next() % n as u64) as usize
+        }
+    }
+
+    /// A forward-edges-only CFG (a DAG rooted at block 0) is trivially
+    /// reducible: it has no back edges at all.
+    fn random_reducible_cfg(rng: &mut TestRng, n: usize) -> Cfg {
+        let mut cfg = Cfg::new(n, 0);
+        for b in 1..n {
+            let edge_count = 1 + rng.below(2);
+            let mut preds = std::collections::HashSet::new();
+            for _ in 0..edge_count {
+                preds.insert(rng.below(b));
+            }
+            for p in preds {
+                cfg.add_edge(p, b);
+            }
+        }
+        cfg
+    }
+
+    #[test]
+    fn compute_and_compute_fast_agree_on_random_reducible_cfgs() {
+        let mut rng = TestRng(0xC0FFEE);
+        for trial in 0..50 {
+            let n = 6 + (trial % 10);
+            let cfg = random_reducible_cfg(&mut rng, n);
+            let slow = Dominators::compute(&cfg);
+            let fast = Dominators::compute_fast(&cfg);
+            for a in 0..n {
+                for b in 0..n {
+                    assert_eq!(slow.dominates(a, b), fast.dominates(a, b), "trial {trial}, n={n}, a={a}, b={b}");
+                }
+            }
+        }
+    }
+}
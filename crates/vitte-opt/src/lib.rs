@@ -0,0 +1,18 @@
+//! Control-flow analyses for the Vitte compiler.
+
+mod bitset;
+mod cfg;
+mod ctx;
+mod dominators;
+mod liveness;
+mod loops;
+pub mod passes;
+mod post_dominators;
+
+pub use bitset::BitSet;
+pub use cfg::Cfg;
+pub use ctx::Ctx;
+pub use dominators::Dominators;
+pub use liveness::{BlockEffects, Liveness};
+pub use loops::{LoopInfo, Loops};
+pub use post_dominators::PostDominators;
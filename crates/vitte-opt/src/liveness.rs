@@ -0,0 +1,196 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::Cfg;
+
+/// A block's use/def effects on named values: `uses` are values read before
+/// any local definition ("upward exposed"), `defs` are values written
+/// unconditionally somewhere in the block.
+#[derive(Debug, Clone, Default)]
+pub struct BlockEffects {
+    pub uses: HashSet<String>,
+    pub defs: HashSet<String>,
+}
+
+/// Live-in/live-out sets per block, computed by the standard backward
+/// dataflow equations:
+///
+/// ```text
+/// live_out[b] = union of live_in[s] for every successor s of b
+/// live_in[b]  = uses[b] ∪ (live_out[b] - defs[b])
+/// ```
+pub struct Liveness {
+    pub live_in: Vec<HashSet<String>>,
+    pub live_out: Vec<HashSet<String>>,
+    pub value_index: HashMap<String, usize>,
+    #[cfg(test)]
+    pub(crate) visits: usize,
+}
+
+impl Liveness {
+    /// Computes liveness with a worklist seeded in reverse postorder: a
+    /// block is only re-enqueued when its `live_in` actually changes, so
+    /// blocks away from any change are never revisited. This avoids the
+    /// full-CFG sweeps a naive fixpoint loop repeats until nothing changes.
+    pub fn compute(cfg: &Cfg, effects: &[BlockEffects]) -> Self {
+        let n = cfg.len();
+
+        let mut names: std::collections::BTreeSet<&str> = Default::default();
+        for e in effects {
+            names.extend(e.uses.iter().map(String::as_str));
+            names.extend(e.defs.iter().map(String::as_str));
+        }
+        let value_index: HashMap<String, usize> =
+            names.into_iter().enumerate().map(|(i, s)| (s.to_string(), i)).collect();
+
+        let mut live_in: Vec<HashSet<String>> = vec![HashSet::new(); n];
+        let mut live_out: Vec<HashSet<String>> = vec![HashSet::new(); n];
+
+        let mut queued = vec![false; n];
+        let mut queue: VecDeque<usize> = VecDeque::new();
+        for b in cfg.reverse_postorder() {
+            queued[b] = true;
+            queue.push_back(b);
+        }
+
+        #[cfg(test)]
+        let mut visits = 0usize;
+
+        while let Some(b) = queue.pop_front() {
+            queued[b] = false;
+            #[cfg(test)]
+            {
+                visits += 1;
+            }
+
+            let mut new_out = HashSet::new();
+            for &s in cfg.succs(b) {
+                new_out.extend(live_in[s].iter().cloned());
+            }
+
+            let mut new_in = effects[b].uses.clone();
+            for v in &new_out {
+                if !effects[b].defs.contains(v) {
+                    new_in.insert(v.clone());
+                }
+            }
+
+            live_out[b] = new_out;
+            if new_in != live_in[b] {
+                live_in[b] = new_in;
+                for &p in cfg.preds(b) {
+                    if !queued[p] {
+                        queued[p] = true;
+                        queue.push_back(p);
+                    }
+                }
+            }
+        }
+
+        Self {
+            live_in,
+            live_out,
+            value_index,
+            #[cfg(test)]
+            visits,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn effects(uses: &[&str], defs: &[&str]) -> BlockEffects {
+        BlockEffects {
+            uses: uses.iter().map(|s| s.to_string()).collect(),
+            defs: defs.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    fn set(vals: &[&str]) -> HashSet<String> {
+        vals.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn diamond_propagates_a_value_used_only_on_one_branch() {
+        // 0 -> 1, 0 -> 2, 1 -> 3, 2 -> 3; only block 1 uses "a", nothing
+        // ever defines it, so "a" is live on entry to every block except 2.
+        let mut cfg = Cfg::new(4, 0);
+        cfg.add_edge(0, 1);
+        cfg.add_edge(0, 2);
+        cfg.add_edge(1, 3);
+        cfg.add_edge(2, 3);
+        let effs = vec![
+            BlockEffects::default(),
+            effects(&["a"], &[]),
+            BlockEffects::default(),
+            BlockEffects::default(),
+        ];
+
+        let live = Liveness::compute(&cfg, &effs);
+
+        assert_eq!(live.live_in[1], set(&["a"]));
+        assert_eq!(live.live_in[0], set(&["a"]));
+        assert_eq!(live.live_in[2], set(&[]));
+        assert_eq!(live.value_index, HashMap::from([("a".to_string(), 0)]));
+    }
+
+    fn naive_liveness(cfg: &Cfg, effects: &[BlockEffects]) -> (Vec<HashSet<String>>, Vec<HashSet<String>>, usize) {
+        let n = cfg.len();
+        let mut live_in: Vec<HashSet<String>> = vec![HashSet::new(); n];
+        let mut live_out: Vec<HashSet<String>> = vec![HashSet::new(); n];
+        let mut visits = 0usize;
+        loop {
+            let mut changed = false;
+            for b in 0..n {
+                visits += 1;
+                let mut new_out = HashSet::new();
+                for &s in cfg.succs(b) {
+                    new_out.extend(live_in[s].iter().cloned());
+                }
+                let mut new_in = effects[b].uses.clone();
+                for v in &new_out {
+                    if !effects[b].defs.contains(v) {
+                        new_in.insert(v.clone());
+                    }
+                }
+                if new_out != live_out[b] {
+                    live_out[b] = new_out;
+                    changed = true;
+                }
+                if new_in != live_in[b] {
+                    live_in[b] = new_in;
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+        (live_in, live_out, visits)
+    }
+
+    #[test]
+    fn worklist_matches_naive_with_far_fewer_visits_on_a_long_chain() {
+        let n = 40;
+        let mut cfg = Cfg::new(n, 0);
+        for i in 0..n - 1 {
+            cfg.add_edge(i, i + 1);
+        }
+        let mut effs: Vec<BlockEffects> = (0..n).map(|_| BlockEffects::default()).collect();
+        effs[0].defs.insert("x".to_string());
+        effs[n - 1].uses.insert("x".to_string());
+
+        let result = Liveness::compute(&cfg, &effs);
+        let (naive_in, naive_out, naive_visits) = naive_liveness(&cfg, &effs);
+
+        assert_eq!(result.live_in, naive_in);
+        assert_eq!(result.live_out, naive_out);
+        assert!(
+            result.visits < naive_visits / 2,
+            "worklist visits {} should be far fewer than naive visits {}",
+            result.visits,
+            naive_visits
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{Cfg, Dominators};
+
+/// A single natural loop: a header block that dominates every block in its
+/// `body`, reached via at least one back edge into the header.
+pub struct LoopInfo {
+    pub header: usize,
+    pub body: BTreeSet<usize>,
+    /// `1` for a top-level loop, incremented for each loop it's nested in.
+    pub depth: usize,
+}
+
+/// The natural loops of a [`Cfg`], found from its back edges (an edge whose
+/// target dominates its source). Edges that merely close a cycle without
+/// satisfying that dominance condition — as happens in irreducible graphs —
+/// are not back edges and contribute no loop; detection simply finds fewer
+/// loops rather than failing.
+pub struct Loops {
+    loops: Vec<LoopInfo>,
+}
+
+impl Loops {
+    pub fn compute(cfg: &Cfg, doms: &Dominators) -> Self {
+        let n = cfg.len();
+        let mut bodies: BTreeMap<usize, BTreeSet<usize>> = BTreeMap::new();
+        for tail in 0..n {
+            for &header in cfg.succs(tail) {
+                if doms.dominates(header, tail) {
+                    bodies.entry(header).or_default().extend(natural_loop_body(cfg, header, tail));
+                }
+            }
+        }
+
+        let mut loops: Vec<LoopInfo> =
+            bodies.into_iter().map(|(header, body)| LoopInfo { header, body, depth: 0 }).collect();
+
+        for i in 0..loops.len() {
+            let depth = 1 + (0..loops.len()).filter(|&j| i != j && loops[j].body.contains(&loops[i].header)).count();
+            loops[i].depth = depth;
+        }
+
+        Self { loops }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &LoopInfo> {
+        self.loops.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.loops.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.loops.is_empty()
+    }
+}
+
+/// The natural loop for a back edge `tail -> header`: `header` plus every
+/// block that can reach `tail` without first passing back through `header`.
+fn natural_loop_body(cfg: &Cfg, header: usize, tail: usize) -> BTreeSet<usize> {
+    let mut body = BTreeSet::new();
+    body.insert(header);
+    if tail == header {
+        return body;
+    }
+    body.insert(tail);
+    let mut stack = vec![tail];
+    while let Some(n) = stack.pop() {
+        for &p in cfg.preds(n) {
+            if body.insert(p) {
+                stack.push(p);
+            }
+        }
+    }
+    body
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn self_loop_has_a_single_block_body() {
+        let mut cfg = Cfg::new(3, 0);
+        cfg.add_edge(0, 1);
+        cfg.add_edge(1, 1);
+        cfg.add_edge(1, 2);
+        let doms = Dominators::compute_fast(&cfg);
+        let loops = Loops::compute(&cfg, &doms);
+
+        assert_eq!(loops.len(), 1);
+        let l = loops.iter().next().unwrap();
+        assert_eq!(l.header, 1);
+        assert_eq!(l.body, BTreeSet::from([1]));
+        assert_eq!(l.depth, 1);
+    }
+
+    #[test]
+    fn nested_loops_report_increasing_depth() {
+        // 0 -> 1 (outer header) -> 2 (inner header) -> 3 -> 2 (inner back edge)
+        //                                           3 -> 4 -> 1 (outer back edge)
+        // 1 -> 5 (loop exit)
+        let mut cfg = Cfg::new(6, 0);
+        cfg.add_edge(0, 1);
+        cfg.add_edge(1, 2);
+        cfg.add_edge(2, 3);
+        cfg.add_edge(3, 2);
+        cfg.add_edge(3, 4);
+        cfg.add_edge(4, 1);
+        cfg.add_edge(1, 5);
+        let doms = Dominators::compute_fast(&cfg);
+        let loops = Loops::compute(&cfg, &doms);
+
+        assert_eq!(loops.len(), 2);
+        let inner = loops.iter().find(|l| l.header == 2).unwrap();
+        let outer = loops.iter().find(|l| l.header == 1).unwrap();
+        assert_eq!(inner.body, BTreeSet::from([2, 3]));
+        assert_eq!(inner.depth, 2);
+        assert_eq!(outer.body, BTreeSet::from([1, 2, 3, 4]));
+        assert_eq!(outer.depth, 1);
+    }
+
+    #[test]
+    fn irreducible_graph_reports_no_natural_loop() {
+        // 0 -> 1, 0 -> 2, 1 -> 2, 2 -> 1: two loop entries, neither
+        // dominates the other, so the 1<->2 cycle has no valid back edge.
+        let mut cfg = Cfg::new(3, 0);
+        cfg.add_edge(0, 1);
+        cfg.add_edge(0, 2);
+        cfg.add_edge(1, 2);
+        cfg.add_edge(2, 1);
+        let doms = Dominators::compute_fast(&cfg);
+        let loops = Loops::compute(&cfg, &doms);
+        assert!(loops.is_empty());
+    }
+}
@@ -0,0 +1,122 @@
+//! Constant folding for literal arithmetic, reusable by any IR's
+//! [`super::Program`] implementation of a constant-propagation pass: an IR
+//! checks whether an instruction's operands are both known constants, then
+//! calls [`fold_binary`]/[`fold_not`] to compute the replacement instead of
+//! reimplementing the arithmetic itself.
+
+/// A folded value: the IR maps this back to whatever literal instruction it
+/// uses to materialize a constant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Const {
+    Int(i64),
+    Bool(bool),
+}
+
+/// The binary operators [`fold_binary`] knows how to fold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FoldOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    And,
+    Or,
+}
+
+/// Folds `lhs op rhs`, or returns `None` when `op` doesn't apply to the
+/// operand types or (for `Div`/`Rem`) `rhs` is zero.
+///
+/// Integer overflow wraps rather than panicking or saturating, matching
+/// plain `i64` arithmetic semantics — folding must never observe behavior
+/// a non-folded evaluation of the same expression wouldn't have.
+pub fn fold_binary(op: FoldOp, lhs: Const, rhs: Const) -> Option<Const> {
+    use Const::{Bool, Int};
+    match (op, lhs, rhs) {
+        (FoldOp::Add, Int(a), Int(b)) => Some(Int(a.wrapping_add(b))),
+        (FoldOp::Sub, Int(a), Int(b)) => Some(Int(a.wrapping_sub(b))),
+        (FoldOp::Mul, Int(a), Int(b)) => Some(Int(a.wrapping_mul(b))),
+        (FoldOp::Div, Int(a), Int(b)) => (b != 0).then(|| Int(a.wrapping_div(b))),
+        (FoldOp::Rem, Int(a), Int(b)) => (b != 0).then(|| Int(a.wrapping_rem(b))),
+        (FoldOp::Eq, Int(a), Int(b)) => Some(Bool(a == b)),
+        (FoldOp::Ne, Int(a), Int(b)) => Some(Bool(a != b)),
+        (FoldOp::Lt, Int(a), Int(b)) => Some(Bool(a < b)),
+        (FoldOp::Le, Int(a), Int(b)) => Some(Bool(a <= b)),
+        (FoldOp::Gt, Int(a), Int(b)) => Some(Bool(a > b)),
+        (FoldOp::Ge, Int(a), Int(b)) => Some(Bool(a >= b)),
+        (FoldOp::Eq, Bool(a), Bool(b)) => Some(Bool(a == b)),
+        (FoldOp::Ne, Bool(a), Bool(b)) => Some(Bool(a != b)),
+        (FoldOp::And, Bool(a), Bool(b)) => Some(Bool(a && b)),
+        (FoldOp::Or, Bool(a), Bool(b)) => Some(Bool(a || b)),
+        _ => None,
+    }
+}
+
+/// Folds boolean negation, or returns `None` if `operand` isn't a `Bool`.
+pub fn fold_not(operand: Const) -> Option<Const> {
+    match operand {
+        Const::Bool(b) => Some(Const::Bool(!b)),
+        Const::Int(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_arithmetic_folds() {
+        assert_eq!(fold_binary(FoldOp::Add, Const::Int(2), Const::Int(3)), Some(Const::Int(5)));
+        assert_eq!(fold_binary(FoldOp::Sub, Const::Int(2), Const::Int(3)), Some(Const::Int(-1)));
+        assert_eq!(fold_binary(FoldOp::Mul, Const::Int(2), Const::Int(3)), Some(Const::Int(6)));
+        assert_eq!(fold_binary(FoldOp::Div, Const::Int(7), Const::Int(2)), Some(Const::Int(3)));
+        assert_eq!(fold_binary(FoldOp::Rem, Const::Int(7), Const::Int(2)), Some(Const::Int(1)));
+    }
+
+    #[test]
+    fn division_and_remainder_by_zero_do_not_fold() {
+        assert_eq!(fold_binary(FoldOp::Div, Const::Int(1), Const::Int(0)), None);
+        assert_eq!(fold_binary(FoldOp::Rem, Const::Int(1), Const::Int(0)), None);
+    }
+
+    #[test]
+    fn integer_overflow_wraps_instead_of_panicking() {
+        assert_eq!(fold_binary(FoldOp::Add, Const::Int(i64::MAX), Const::Int(1)), Some(Const::Int(i64::MIN)));
+        assert_eq!(fold_binary(FoldOp::Sub, Const::Int(i64::MIN), Const::Int(1)), Some(Const::Int(i64::MAX)));
+        assert_eq!(fold_binary(FoldOp::Mul, Const::Int(i64::MAX), Const::Int(2)), Some(Const::Int(-2)));
+    }
+
+    #[test]
+    fn integer_comparisons_fold() {
+        assert_eq!(fold_binary(FoldOp::Eq, Const::Int(3), Const::Int(3)), Some(Const::Bool(true)));
+        assert_eq!(fold_binary(FoldOp::Ne, Const::Int(3), Const::Int(3)), Some(Const::Bool(false)));
+        assert_eq!(fold_binary(FoldOp::Lt, Const::Int(2), Const::Int(3)), Some(Const::Bool(true)));
+        assert_eq!(fold_binary(FoldOp::Le, Const::Int(3), Const::Int(3)), Some(Const::Bool(true)));
+        assert_eq!(fold_binary(FoldOp::Gt, Const::Int(2), Const::Int(3)), Some(Const::Bool(false)));
+        assert_eq!(fold_binary(FoldOp::Ge, Const::Int(3), Const::Int(3)), Some(Const::Bool(true)));
+    }
+
+    #[test]
+    fn boolean_and_or_fold() {
+        assert_eq!(fold_binary(FoldOp::And, Const::Bool(true), Const::Bool(false)), Some(Const::Bool(false)));
+        assert_eq!(fold_binary(FoldOp::Or, Const::Bool(true), Const::Bool(false)), Some(Const::Bool(true)));
+    }
+
+    #[test]
+    fn boolean_not_folds() {
+        assert_eq!(fold_not(Const::Bool(true)), Some(Const::Bool(false)));
+        assert_eq!(fold_not(Const::Int(1)), None);
+    }
+
+    #[test]
+    fn mismatched_operand_types_do_not_fold() {
+        assert_eq!(fold_binary(FoldOp::Add, Const::Int(1), Const::Bool(true)), None);
+        assert_eq!(fold_binary(FoldOp::And, Const::Int(1), Const::Int(0)), None);
+    }
+}
@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+
+use crate::Dominators;
+
+use super::{AnalysisCache, AnalysisId, FuncId, Pass, PassResult, Program, ValueId};
+
+/// Global value numbering: walks each function's dominator tree, keeping a
+/// table of instructions seen so far that's scoped to the current block and
+/// its dominator-tree ancestors. An instruction whose
+/// [`Program::inst_value_number_key`] matches one already in scope is
+/// redundant, so its result is replaced with the earlier one; instructions
+/// returning `None` (side effects, or keys the IR can't characterize) are
+/// left alone.
+pub struct Gvn;
+
+impl Gvn {
+    pub fn run<P: Program>(prog: &mut P, f: FuncId) -> PassResult {
+        let cfg = prog.cfg(f).clone();
+        let doms = Dominators::compute_fast(&cfg);
+
+        let mut children = vec![Vec::new(); cfg.len()];
+        for b in 0..cfg.len() {
+            if let Some(idom) = doms.idom(b) {
+                children[idom].push(b);
+            }
+        }
+
+        let mut table: HashMap<u64, ValueId> = HashMap::new();
+        let mut result = PassResult::default();
+
+        let inserted = process_block(prog, f, cfg.entry, &mut table, &mut result);
+        let mut frames: Vec<(usize, usize, Vec<u64>)> = vec![(cfg.entry, 0, inserted)];
+
+        while let Some(&mut (block, ref mut next, _)) = frames.last_mut() {
+            if *next < children[block].len() {
+                let child = children[block][*next];
+                *next += 1;
+                let inserted = process_block(prog, f, child, &mut table, &mut result);
+                frames.push((child, 0, inserted));
+            } else {
+                let (_, _, inserted) = frames.pop().unwrap();
+                for key in inserted {
+                    table.remove(&key);
+                }
+            }
+        }
+
+        result
+    }
+}
+
+impl<P: Program> Pass<P> for Gvn {
+    fn name(&self) -> &str {
+        "gvn"
+    }
+
+    // Recomputes its own dominator tree internally and never changes the
+    // CFG's edges, so both stay valid after it runs.
+    fn preserves(&self) -> &[AnalysisId] {
+        &[AnalysisId::Dominators, AnalysisId::Loops]
+    }
+
+    fn run(&self, prog: &mut P, f: FuncId, _analyses: &mut AnalysisCache) -> PassResult {
+        Gvn::run(prog, f)
+    }
+}
+
+/// Numbers every instruction in `block`, returning the keys it newly added
+/// to `table` so the caller can remove them again once the block's
+/// dominator subtree has been fully visited.
+fn process_block<P: Program>(
+    prog: &mut P,
+    f: FuncId,
+    block: usize,
+    table: &mut HashMap<u64, ValueId>,
+    result: &mut PassResult,
+) -> Vec<u64> {
+    let mut inserted = Vec::new();
+    for inst in prog.block_insts(f, block) {
+        let Some(key) = prog.inst_value_number_key(f, inst) else { continue };
+        let Some(value) = prog.inst_result(f, inst) else { continue };
+
+        match table.get(&key) {
+            Some(&existing) if existing != value => {
+                prog.replace_uses(f, value, existing);
+                result.changes += 1;
+                result.invalidate_liveness = true;
+            }
+            Some(_) => {}
+            None => {
+                table.insert(key, value);
+                inserted.push(key);
+            }
+        }
+    }
+    inserted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Cfg;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    #[derive(Debug, Clone)]
+    struct MockInst {
+        op: &'static str,
+        operands: Vec<ValueId>,
+        result: Option<ValueId>,
+    }
+
+    struct MockProgram {
+        cfg: Cfg,
+        insts: Vec<MockInst>,
+    }
+
+    impl Program for MockProgram {
+        fn cfg(&self, _f: FuncId) -> &Cfg {
+            &self.cfg
+        }
+
+        fn block_insts(&self, _f: FuncId, block: usize) -> Vec<usize> {
+            if block == 0 { (0..self.insts.len()).collect() } else { Vec::new() }
+        }
+
+        fn inst_result(&self, _f: FuncId, i: usize) -> Option<ValueId> {
+            self.insts[i].result
+        }
+
+        fn inst_value_number_key(&self, _f: FuncId, i: usize) -> Option<u64> {
+            let inst = &self.insts[i];
+            if inst.op == "param" {
+                return None;
+            }
+            let mut operands = inst.operands.clone();
+            if inst.op == "add" {
+                operands.sort_unstable();
+            }
+            let mut hasher = DefaultHasher::new();
+            inst.op.hash(&mut hasher);
+            operands.hash(&mut hasher);
+            Some(hasher.finish())
+        }
+
+        fn replace_uses(&mut self, _f: FuncId, old: ValueId, new: ValueId) {
+            for inst in &mut self.insts {
+                for operand in &mut inst.operands {
+                    if *operand == old {
+                        *operand = new;
+                    }
+                }
+            }
+        }
+
+        fn funcs(&self) -> Vec<FuncId> {
+            vec![0]
+        }
+    }
+
+    #[test]
+    fn identical_add_instructions_collapse_to_one() {
+        let mut prog = MockProgram {
+            cfg: Cfg::new(1, 0),
+            insts: vec![
+                MockInst { op: "param", operands: vec![], result: Some(0) },
+                MockInst { op: "add", operands: vec![0, 0], result: Some(1) },
+                MockInst { op: "add", operands: vec![0, 0], result: Some(2) },
+                MockInst { op: "mul", operands: vec![2, 0], result: Some(3) },
+            ],
+        };
+
+        let result = Gvn::run(&mut prog, 0);
+
+        assert_eq!(result.changes, 1);
+        assert!(result.invalidate_liveness);
+        // The redundant add's result (2) was replaced by the first one (1).
+        assert_eq!(prog.insts[3].operands, vec![1, 0]);
+    }
+
+    #[test]
+    fn commuted_operands_are_still_recognized_as_equal() {
+        let mut prog = MockProgram {
+            cfg: Cfg::new(1, 0),
+            insts: vec![
+                MockInst { op: "param", operands: vec![], result: Some(0) },
+                MockInst { op: "param", operands: vec![], result: Some(1) },
+                MockInst { op: "add", operands: vec![0, 1], result: Some(2) },
+                MockInst { op: "add", operands: vec![1, 0], result: Some(3) },
+            ],
+        };
+
+        let result = Gvn::run(&mut prog, 0);
+
+        assert_eq!(result.changes, 1);
+        assert!(prog.insts.iter().all(|i| !i.operands.contains(&3)));
+    }
+
+    #[test]
+    fn instructions_with_no_value_number_key_are_left_alone() {
+        let mut prog = MockProgram {
+            cfg: Cfg::new(1, 0),
+            insts: vec![
+                MockInst { op: "param", operands: vec![], result: Some(0) },
+                MockInst { op: "param", operands: vec![], result: Some(1) },
+            ],
+        };
+
+        let result = Gvn::run(&mut prog, 0);
+        assert_eq!(result, PassResult::default());
+    }
+}
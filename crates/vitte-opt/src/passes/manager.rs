@@ -0,0 +1,247 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{Cfg, Dominators, Loops};
+
+use super::{FuncId, PassResult, Program, Report};
+
+/// An analysis a pass can declare as a dependency or a guarantee.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnalysisId {
+    Dominators,
+    Liveness,
+    Loops,
+}
+
+/// A cached set of analyses for one function. [`PassManager::run_module`]
+/// keeps one of these per function across passes (and across repeated
+/// `run_module` calls), recomputing an analysis only when a prior pass
+/// invalidated it.
+///
+/// Liveness has no IR-independent representation the way [`Dominators`] and
+/// [`Loops`] do (it needs per-instruction use/def data `Program` doesn't
+/// expose), so this cache only tracks its validity and how many times it
+/// would have been recomputed, not a cached value.
+#[derive(Default)]
+pub struct AnalysisCache {
+    valid: HashSet<AnalysisId>,
+    dominators: Option<Dominators>,
+    loops: Option<Loops>,
+    pub dominator_computations: usize,
+    pub loop_computations: usize,
+    pub liveness_computations: usize,
+}
+
+impl AnalysisCache {
+    /// Recomputes `id` if it isn't currently valid, returning how many
+    /// individual analyses were recomputed (more than one for `Loops`,
+    /// which also ensures `Dominators`).
+    fn ensure(&mut self, id: AnalysisId, cfg: &Cfg) -> usize {
+        if self.valid.contains(&id) {
+            return 0;
+        }
+        let mut recomputes = 0;
+        match id {
+            AnalysisId::Dominators => {
+                self.dominators = Some(Dominators::compute_fast(cfg));
+                self.dominator_computations += 1;
+                recomputes += 1;
+            }
+            AnalysisId::Loops => {
+                recomputes += self.ensure(AnalysisId::Dominators, cfg);
+                let doms = self.dominators.as_ref().expect("just ensured above");
+                self.loops = Some(Loops::compute(cfg, doms));
+                self.loop_computations += 1;
+                recomputes += 1;
+            }
+            AnalysisId::Liveness => {
+                self.liveness_computations += 1;
+                recomputes += 1;
+            }
+        }
+        self.valid.insert(id);
+        recomputes
+    }
+
+    fn invalidate_except(&mut self, preserved: &[AnalysisId]) {
+        let preserved: HashSet<AnalysisId> = preserved.iter().copied().collect();
+        self.valid.retain(|id| preserved.contains(id));
+        if !self.valid.contains(&AnalysisId::Dominators) {
+            self.dominators = None;
+        }
+        if !self.valid.contains(&AnalysisId::Loops) {
+            self.loops = None;
+        }
+    }
+
+    pub fn dominators(&self) -> Option<&Dominators> {
+        self.dominators.as_ref()
+    }
+
+    pub fn loops(&self) -> Option<&Loops> {
+        self.loops.as_ref()
+    }
+}
+
+/// A single optimization or analysis pass over a [`Program`].
+///
+/// `requires`/`preserves` are declarative hints [`PassManager`] uses to
+/// order recomputation; they don't affect a single `run` call's behavior.
+pub trait Pass<P: Program> {
+    fn name(&self) -> &str;
+
+    /// Analyses that must be valid in the cache before `run` is called.
+    fn requires(&self) -> &[AnalysisId] {
+        &[]
+    }
+
+    /// Analyses `run` guarantees are still valid afterwards, so the manager
+    /// doesn't discard and recompute them for the next pass.
+    fn preserves(&self) -> &[AnalysisId] {
+        &[]
+    }
+
+    fn run(&self, prog: &mut P, f: FuncId, analyses: &mut AnalysisCache) -> PassResult;
+}
+
+/// Runs a fixed pipeline of passes over every function in a [`Program`],
+/// recomputing each pass's required analyses only when a prior pass (in
+/// this run or an earlier one) invalidated them.
+pub struct PassManager<P: Program> {
+    passes: Vec<Box<dyn Pass<P>>>,
+    caches: HashMap<FuncId, AnalysisCache>,
+}
+
+impl<P: Program> PassManager<P> {
+    pub fn new() -> Self {
+        Self { passes: Vec::new(), caches: HashMap::new() }
+    }
+
+    pub fn add(&mut self, pass: Box<dyn Pass<P>>) -> &mut Self {
+        self.passes.push(pass);
+        self
+    }
+
+    pub fn run_module(&mut self, prog: &mut P) -> Report {
+        let mut report = Report::new();
+        for f in prog.funcs() {
+            let cache = self.caches.entry(f).or_default();
+            for pass in &self.passes {
+                let cfg = prog.cfg(f).clone();
+                for &id in pass.requires() {
+                    report.analysis_recomputes += cache.ensure(id, &cfg);
+                }
+                let result = pass.run(prog, f, cache);
+                report.record(pass.name(), result);
+                cache.invalidate_except(pass.preserves());
+            }
+        }
+        report
+    }
+
+    pub fn analysis_cache(&self, f: FuncId) -> Option<&AnalysisCache> {
+        self.caches.get(&f)
+    }
+}
+
+impl<P: Program> Default for PassManager<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::ValueId;
+    use crate::Cfg;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    struct MockProgram {
+        cfg: Cfg,
+    }
+
+    impl Program for MockProgram {
+        fn cfg(&self, _f: FuncId) -> &Cfg {
+            &self.cfg
+        }
+
+        fn block_insts(&self, _f: FuncId, _block: usize) -> Vec<usize> {
+            Vec::new()
+        }
+
+        fn inst_result(&self, _f: FuncId, _i: usize) -> Option<ValueId> {
+            None
+        }
+
+        fn inst_value_number_key(&self, _f: FuncId, _i: usize) -> Option<u64> {
+            let mut hasher = DefaultHasher::new();
+            0.hash(&mut hasher);
+            Some(hasher.finish())
+        }
+
+        fn replace_uses(&mut self, _f: FuncId, _old: ValueId, _new: ValueId) {}
+
+        fn funcs(&self) -> Vec<FuncId> {
+            vec![0]
+        }
+    }
+
+    struct RequireDomsPass;
+
+    impl Pass<MockProgram> for RequireDomsPass {
+        fn name(&self) -> &str {
+            "require-doms"
+        }
+
+        fn requires(&self) -> &[AnalysisId] {
+            &[AnalysisId::Dominators]
+        }
+
+        fn preserves(&self) -> &[AnalysisId] {
+            &[AnalysisId::Dominators]
+        }
+
+        fn run(&self, _prog: &mut MockProgram, _f: FuncId, analyses: &mut AnalysisCache) -> PassResult {
+            assert!(analyses.dominators().is_some());
+            PassResult::default()
+        }
+    }
+
+    #[test]
+    fn a_preserved_analysis_is_computed_only_once_across_runs() {
+        let mut prog = MockProgram { cfg: Cfg::new(2, 0) };
+        let mut pm: PassManager<MockProgram> = PassManager::new();
+        pm.add(Box::new(RequireDomsPass));
+
+        pm.run_module(&mut prog);
+        pm.run_module(&mut prog);
+
+        assert_eq!(pm.analysis_cache(0).unwrap().dominator_computations, 1);
+    }
+
+    struct InvalidateDomsPass;
+
+    impl Pass<MockProgram> for InvalidateDomsPass {
+        fn name(&self) -> &str {
+            "invalidate-doms"
+        }
+
+        fn run(&self, _prog: &mut MockProgram, _f: FuncId, _analyses: &mut AnalysisCache) -> PassResult {
+            PassResult::default()
+        }
+    }
+
+    #[test]
+    fn an_unpreserved_analysis_is_recomputed_after_an_intervening_pass() {
+        let mut prog = MockProgram { cfg: Cfg::new(2, 0) };
+        let mut pm: PassManager<MockProgram> = PassManager::new();
+        pm.add(Box::new(RequireDomsPass));
+        pm.add(Box::new(InvalidateDomsPass));
+        pm.add(Box::new(RequireDomsPass));
+
+        pm.run_module(&mut prog);
+
+        assert_eq!(pm.analysis_cache(0).unwrap().dominator_computations, 2);
+    }
+}
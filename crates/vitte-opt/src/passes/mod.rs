@@ -0,0 +1,55 @@
+//! Optimization passes built on top of the analyses in this crate. Each
+//! pass takes a `&mut impl Program` (the compiler's IR, seen through the
+//! small interface this module defines) and reports what it changed via a
+//! [`PassResult`].
+
+pub mod fold;
+mod gvn;
+mod manager;
+mod report;
+
+pub use fold::{fold_binary, fold_not, Const, FoldOp};
+pub use gvn::Gvn;
+pub use manager::{AnalysisCache, AnalysisId, Pass, PassManager};
+pub use report::Report;
+
+/// Identifiers into a caller-owned IR: a function, an instruction within
+/// it, and a value it may define. Kept as plain indices so passes here
+/// don't depend on the IR's internal representation.
+pub type FuncId = usize;
+pub type InstId = usize;
+pub type ValueId = usize;
+
+/// The minimal IR surface a pass needs. Implemented by the compiler's real
+/// IR; a pass only ever sees it through this trait.
+pub trait Program {
+    /// The CFG for function `f`.
+    fn cfg(&self, f: FuncId) -> &crate::Cfg;
+
+    /// All instruction ids in `block` of function `f`, in program order.
+    fn block_insts(&self, f: FuncId, block: usize) -> Vec<InstId>;
+
+    /// The value instruction `i` defines, or `None` if it defines nothing
+    /// (e.g. a store or a branch).
+    fn inst_result(&self, f: FuncId, i: InstId) -> Option<ValueId>;
+
+    /// A commutativity-aware hash of instruction `i`'s opcode and operands,
+    /// or `None` to opt it out of value numbering entirely (it has side
+    /// effects, or the IR can't characterize it cheaply).
+    fn inst_value_number_key(&self, f: FuncId, i: InstId) -> Option<u64>;
+
+    /// Rewrites every use of `old` in function `f` to use `new` instead.
+    fn replace_uses(&mut self, f: FuncId, old: ValueId, new: ValueId);
+
+    /// Every function in the program, in the order `run_module` should
+    /// process them.
+    fn funcs(&self) -> Vec<FuncId>;
+}
+
+/// What a pass changed, so a pass manager can decide which cached analyses
+/// are still valid and whether to run another round.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PassResult {
+    pub changes: usize,
+    pub invalidate_liveness: bool,
+}
@@ -0,0 +1,126 @@
+use super::PassResult;
+
+/// Aggregated results of running a [`super::PassManager`] pipeline: what
+/// each pass reported, and running totals across the whole run (or, after
+/// [`Report::merge`], across several runs).
+#[derive(Debug, Default, Clone)]
+pub struct Report {
+    per_pass: Vec<(String, PassResult)>,
+    pub total_changes: usize,
+    pub analysis_recomputes: usize,
+}
+
+impl Report {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, pass_name: &str, result: PassResult) {
+        self.total_changes += result.changes;
+        self.per_pass.push((pass_name.to_string(), result));
+    }
+
+    pub fn per_pass(&self) -> &[(String, PassResult)] {
+        &self.per_pass
+    }
+
+    /// Whether any pass in this report made a change.
+    pub fn changed(&self) -> bool {
+        self.total_changes > 0
+    }
+
+    /// Folds `other`'s totals and per-pass history into `self`, as if both
+    /// runs had been recorded into the same report.
+    pub fn merge(&mut self, other: Report) {
+        self.total_changes += other.total_changes;
+        self.analysis_recomputes += other.analysis_recomputes;
+        self.per_pass.extend(other.per_pass);
+    }
+
+    /// A short human-readable summary, one line per pass plus a totals
+    /// line.
+    pub fn human(&self) -> String {
+        let mut lines: Vec<String> =
+            self.per_pass.iter().map(|(name, result)| format!("{name}: {} changes", result.changes)).collect();
+        lines.push(format!(
+            "total changes: {} across {} passes, {} analysis recomputes",
+            self.total_changes,
+            self.per_pass.len(),
+            self.analysis_recomputes
+        ));
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{AnalysisCache, FuncId, Pass, PassManager, Program, ValueId};
+    use super::*;
+    use crate::Cfg;
+
+    struct MockProgram {
+        cfg: Cfg,
+    }
+
+    impl Program for MockProgram {
+        fn cfg(&self, _f: FuncId) -> &Cfg {
+            &self.cfg
+        }
+
+        fn block_insts(&self, _f: FuncId, _block: usize) -> Vec<usize> {
+            Vec::new()
+        }
+
+        fn inst_result(&self, _f: FuncId, _i: usize) -> Option<ValueId> {
+            None
+        }
+
+        fn inst_value_number_key(&self, _f: FuncId, _i: usize) -> Option<u64> {
+            None
+        }
+
+        fn replace_uses(&mut self, _f: FuncId, _old: ValueId, _new: ValueId) {}
+
+        fn funcs(&self) -> Vec<FuncId> {
+            vec![0]
+        }
+    }
+
+    struct FixedChangesPass {
+        name: &'static str,
+        changes: usize,
+    }
+
+    impl Pass<MockProgram> for FixedChangesPass {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn run(&self, _prog: &mut MockProgram, _f: FuncId, _analyses: &mut AnalysisCache) -> PassResult {
+            PassResult { changes: self.changes, invalidate_liveness: false }
+        }
+    }
+
+    #[test]
+    fn total_changes_equals_the_sum_of_per_pass_changes() {
+        let mut prog = MockProgram { cfg: Cfg::new(1, 0) };
+        let mut pm: PassManager<MockProgram> = PassManager::new();
+        pm.add(Box::new(FixedChangesPass { name: "fold", changes: 2 }));
+        pm.add(Box::new(FixedChangesPass { name: "dce", changes: 0 }));
+        pm.add(Box::new(FixedChangesPass { name: "cse", changes: 5 }));
+
+        let report = pm.run_module(&mut prog);
+
+        let sum: usize = report.per_pass().iter().map(|(_, r)| r.changes).sum();
+        assert_eq!(report.total_changes, sum);
+        assert_eq!(report.total_changes, 7);
+        assert!(report.changed());
+        assert!(report.human().contains("total changes: 7 across 3 passes"));
+    }
+
+    #[test]
+    fn a_report_with_no_changes_reports_unchanged() {
+        let report = Report::new();
+        assert!(!report.changed());
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{Cfg, Dominators};
+
+/// The post-dominator relation over a [`Cfg`]: `a` post-dominates `b` if
+/// every path from `b` to the CFG's exit passes through `a`.
+///
+/// Computed by running [`Dominators`] on the reversed graph, rooted at a
+/// virtual exit block that every block with no successors points into (a
+/// CFG can have more than one real exit).
+pub struct PostDominators {
+    dom: Dominators,
+}
+
+impl PostDominators {
+    pub fn compute(cfg: &Cfg) -> Self {
+        let n = cfg.len();
+        let virtual_exit = n;
+        let mut reversed = Cfg::new(n + 1, virtual_exit);
+        for b in 0..n {
+            for &s in cfg.succs(b) {
+                reversed.add_edge(s, b);
+            }
+            if cfg.succs(b).is_empty() {
+                reversed.add_edge(virtual_exit, b);
+            }
+        }
+        Self { dom: Dominators::compute_fast(&reversed) }
+    }
+
+    /// The immediate post-dominator of `block`, or `None` if it's an exit
+    /// block or unreachable from every exit.
+    pub fn post_idom(&self, block: usize) -> Option<usize> {
+        self.dom.idom(block)
+    }
+
+    /// Whether every path from `b` to the CFG's exit passes through `a`. A
+    /// block always post-dominates itself.
+    pub fn post_dominates(&self, a: usize, b: usize) -> bool {
+        self.dom.dominates(a, b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diamond() -> Cfg {
+        // 0 -> 1, 0 -> 2, 1 -> 3, 2 -> 3
+        let mut cfg = Cfg::new(4, 0);
+        cfg.add_edge(0, 1);
+        cfg.add_edge(0, 2);
+        cfg.add_edge(1, 3);
+        cfg.add_edge(2, 3);
+        cfg
+    }
+
+    #[test]
+    fn join_block_postdominates_both_branches_but_not_vice_versa() {
+        let post = PostDominators::compute(&diamond());
+        assert!(post.post_dominates(3, 1));
+        assert!(post.post_dominates(3, 2));
+        assert!(!post.post_dominates(1, 3));
+        assert!(!post.post_dominates(2, 3));
+    }
+
+    #[test]
+    fn join_block_also_postdominates_the_entry() {
+        let post = PostDominators::compute(&diamond());
+        assert!(post.post_dominates(3, 0));
+        assert_eq!(post.post_idom(0), Some(3));
+    }
+}
@@ -0,0 +1,45 @@
+use std::fmt;
+use vitte_core::Span;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseErrorKind {
+    UnexpectedToken { expected: &'static str, found: String },
+    UnexpectedEof { expected: &'static str },
+    InvalidAssignTarget,
+    /// A statement was missing its trailing `;`, but the next token
+    /// unambiguously starts a new statement, so the parser synthesized the
+    /// `;` and kept going instead of aborting. Only raised by
+    /// [`crate::Parser::parse_program_recover`]'s statement-level recovery.
+    MissingSemicolon,
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::UnexpectedToken { expected, found } => {
+                write!(f, "expected {expected}, found `{found}`")
+            }
+            ParseErrorKind::UnexpectedEof { expected } => write!(f, "expected {expected}, found end of file"),
+            ParseErrorKind::InvalidAssignTarget => write!(f, "invalid assignment target"),
+            ParseErrorKind::MissingSemicolon => write!(f, "missing `;`"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub kind: ParseErrorKind,
+    pub span: Span,
+}
+
+impl ParseError {
+    pub fn new(kind: ParseErrorKind, span: Span) -> Self {
+        Self { kind, span }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.kind)
+    }
+}
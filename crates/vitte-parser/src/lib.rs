@@ -0,0 +1,1008 @@
+//! Recursive-descent parser turning a token stream from `vitte-lexer` into
+//! the `vitte-ast` tree.
+
+mod error;
+
+pub use error::{ParseError, ParseErrorKind};
+
+use vitte_ast::{BinOp, Block, Expr, Param, Program, Stmt, Type, UnOp, WithSpan};
+use vitte_core::Span;
+use vitte_lexer::{Keyword, LexError, Lexer, Token, TokenKind};
+
+/// Whether `expr` can appear on the left side of an assignment.
+fn is_lvalue(expr: &Expr) -> bool {
+    matches!(expr, Expr::Ident(..) | Expr::Field { .. } | Expr::Index { .. })
+}
+
+pub struct Parser<'a> {
+    tokens: Vec<Token<'a>>,
+    pos: usize,
+    /// Set while parsing an `if`/`while` condition, where a bare `{` must
+    /// start the body block rather than a struct literal — mirrors Rust's
+    /// restriction on struct literals in condition position.
+    no_struct_lit: bool,
+}
+
+/// A saved parser position. Cheap to take and restore since the whole token
+/// stream is already buffered in [`Parser::tokens`]: a checkpoint is just
+/// the cursor plus whatever other mutable state affects dispatch, and
+/// restoring is a plain field reset rather than re-lexing anything. Lets a
+/// caller try a grammar production speculatively and back out if it turns
+/// out not to match, e.g. disambiguating a struct literal from a plain
+/// block.
+#[derive(Debug, Clone, Copy)]
+pub struct Checkpoint {
+    pos: usize,
+    no_struct_lit: bool,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(src: &'a str) -> Result<Self, LexError> {
+        let tokens = Lexer::new(src).tokenize()?;
+        Ok(Self::from_tokens(tokens))
+    }
+
+    /// Builds a parser directly from an already-lexed token stream, e.g.
+    /// one produced by [`Lexer::tokenize_recover`] so lexical errors don't
+    /// prevent parsing the tokens that did come out clean.
+    pub fn from_tokens(tokens: Vec<Token<'a>>) -> Self {
+        Self { tokens, pos: 0, no_struct_lit: false }
+    }
+
+    /// Runs `f` with struct-literal parsing temporarily disabled, restoring
+    /// the previous setting afterwards (parenthesized/bracketed
+    /// sub-expressions re-enable it, since the ambiguity only applies to a
+    /// bare `{` directly after the condition).
+    fn without_struct_lit<T>(&mut self, f: impl FnOnce(&mut Self) -> Result<T, ParseError>) -> Result<T, ParseError> {
+        let prev = self.no_struct_lit;
+        self.no_struct_lit = true;
+        let result = f(self);
+        self.no_struct_lit = prev;
+        result
+    }
+
+    fn with_struct_lit<T>(&mut self, f: impl FnOnce(&mut Self) -> Result<T, ParseError>) -> Result<T, ParseError> {
+        let prev = self.no_struct_lit;
+        self.no_struct_lit = false;
+        let result = f(self);
+        self.no_struct_lit = prev;
+        result
+    }
+
+    fn peek(&self) -> &TokenKind<'a> {
+        &self.tokens[self.pos].kind
+    }
+
+    fn peek_span(&self) -> Span {
+        self.tokens[self.pos].span
+    }
+
+    fn advance(&mut self) -> Token<'a> {
+        let tok = self.tokens[self.pos].clone();
+        if self.pos + 1 < self.tokens.len() {
+            self.pos += 1;
+        }
+        tok
+    }
+
+    /// Captures the current parse position so it can be rewound later if a
+    /// speculative parse attempt doesn't pan out.
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint { pos: self.pos, no_struct_lit: self.no_struct_lit }
+    }
+
+    /// Rewinds to a position captured by [`Self::checkpoint`], discarding
+    /// any progress made since.
+    pub fn restore(&mut self, checkpoint: Checkpoint) {
+        self.pos = checkpoint.pos;
+        self.no_struct_lit = checkpoint.no_struct_lit;
+    }
+
+    fn unexpected(&self, expected: &'static str) -> ParseError {
+        if matches!(self.peek(), TokenKind::Eof) {
+            return ParseError::new(ParseErrorKind::UnexpectedEof { expected }, self.peek_span());
+        }
+        let found = format!("{:?}", self.peek());
+        ParseError::new(ParseErrorKind::UnexpectedToken { expected, found }, self.peek_span())
+    }
+
+    fn eat_kw(&mut self, kw: Keyword) -> bool {
+        if matches!(self.peek(), TokenKind::Kw(k) if *k == kw) {
+            self.advance();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect_kw(&mut self, kw: Keyword, expected: &'static str) -> Result<Span, ParseError> {
+        match self.peek() {
+            TokenKind::Kw(k) if *k == kw => Ok(self.advance().span),
+            _ => Err(self.unexpected(expected)),
+        }
+    }
+
+    fn expect_ident(&mut self) -> Result<(String, Span), ParseError> {
+        match self.peek() {
+            TokenKind::Ident(s) => {
+                let s = s.to_string();
+                let span = self.advance().span;
+                Ok((s, span))
+            }
+            _ => Err(self.unexpected("identifier")),
+        }
+    }
+
+    fn expect(&mut self, expected: &'static str, kind: TokenKind<'static>) -> Result<Span, ParseError> {
+        if std::mem::discriminant(self.peek()) == std::mem::discriminant(&kind) {
+            Ok(self.advance().span)
+        } else {
+            Err(self.unexpected(expected))
+        }
+    }
+
+    pub fn parse_program(&mut self) -> Result<Program, ParseError> {
+        let mut items = Vec::new();
+        while !matches!(self.peek(), TokenKind::Eof) {
+            items.push(vitte_ast::Item::Fn(self.parse_fn()?));
+        }
+        Ok(Program { items })
+    }
+
+    /// Like [`Self::parse_program`], but never stops at the first error.
+    /// Item-level failures (a broken `fn` signature, say) synchronize to the
+    /// next `fn`/`const`/`struct`/`enum` keyword or EOF; failures inside a
+    /// function body synchronize to the next `;` or `}` instead, so one
+    /// broken statement doesn't take out the rest of the file. Useful for
+    /// the LSP, which wants every diagnostic in one pass rather than just
+    /// the first.
+    pub fn parse_program_recover(&mut self) -> (Program, Vec<ParseError>) {
+        let mut items = Vec::new();
+        let mut errors = Vec::new();
+        while !matches!(self.peek(), TokenKind::Eof) {
+            match self.parse_fn_recover(&mut errors) {
+                Ok(f) => items.push(vitte_ast::Item::Fn(f)),
+                Err(e) => {
+                    errors.push(e);
+                    self.synchronize_item();
+                }
+            }
+        }
+        (Program { items }, errors)
+    }
+
+    fn synchronize_item(&mut self) {
+        while !matches!(
+            self.peek(),
+            TokenKind::Eof
+                | TokenKind::Kw(Keyword::Fn)
+                | TokenKind::Kw(Keyword::Const)
+                | TokenKind::Kw(Keyword::Struct)
+                | TokenKind::Kw(Keyword::Enum)
+        ) {
+            self.advance();
+        }
+    }
+
+    fn synchronize_stmt(&mut self) {
+        loop {
+            match self.peek() {
+                TokenKind::Semi => {
+                    self.advance();
+                    break;
+                }
+                TokenKind::RBrace | TokenKind::Eof => break,
+                _ => {
+                    self.advance();
+                }
+            }
+        }
+    }
+
+    fn parse_fn(&mut self) -> Result<vitte_ast::FnDecl, ParseError> {
+        let (start, name, name_span, params, ret) = self.parse_fn_header()?;
+        let body = self.parse_block()?;
+        let span = Span::merge(start, body.span);
+        Ok(vitte_ast::FnDecl { name, name_span, params, ret, body, span })
+    }
+
+    fn parse_fn_recover(&mut self, errors: &mut Vec<ParseError>) -> Result<vitte_ast::FnDecl, ParseError> {
+        let (start, name, name_span, params, ret) = self.parse_fn_header()?;
+        let body = self.parse_block_recover(errors)?;
+        let span = Span::merge(start, body.span);
+        Ok(vitte_ast::FnDecl { name, name_span, params, ret, body, span })
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn parse_fn_header(&mut self) -> Result<(Span, String, Span, Vec<Param>, Option<Type>), ParseError> {
+        let start = self.expect("fn", TokenKind::Kw(Keyword::Fn))?;
+        let (name, name_span) = self.expect_ident()?;
+        self.expect("(", TokenKind::LParen)?;
+        let mut params = Vec::new();
+        while !matches!(self.peek(), TokenKind::RParen) {
+            let (pname, pname_span) = self.expect_ident()?;
+            self.expect(":", TokenKind::Colon)?;
+            let ty = self.parse_type()?;
+            params.push(Param { name: pname, name_span: pname_span, ty });
+            if matches!(self.peek(), TokenKind::Comma) {
+                self.advance();
+            } else {
+                break;
+            }
+        }
+        self.expect(")", TokenKind::RParen)?;
+        let ret = if matches!(self.peek(), TokenKind::Arrow) {
+            self.advance();
+            Some(self.parse_type()?)
+        } else {
+            None
+        };
+        Ok((start, name, name_span, params, ret))
+    }
+
+    /// Recursive-descent type parser: `(T, T)` tuples, `[T]` arrays, and
+    /// `fn(T, T) -> T` function types, on top of the plain primitive/ident
+    /// names `parse_type` already understood.
+    fn parse_type(&mut self) -> Result<Type, ParseError> {
+        match self.peek() {
+            TokenKind::LParen => {
+                self.advance();
+                let mut elems = Vec::new();
+                while !matches!(self.peek(), TokenKind::RParen) {
+                    elems.push(self.parse_type()?);
+                    if matches!(self.peek(), TokenKind::Comma) {
+                        self.advance();
+                    } else {
+                        break;
+                    }
+                }
+                self.expect(")", TokenKind::RParen)?;
+                Ok(Type::Tuple(elems))
+            }
+            TokenKind::LBracket => {
+                self.advance();
+                let elem = self.parse_type()?;
+                self.expect("]", TokenKind::RBracket)?;
+                Ok(Type::Array(Box::new(elem)))
+            }
+            TokenKind::Kw(Keyword::Fn) => {
+                self.advance();
+                self.expect("(", TokenKind::LParen)?;
+                let mut params = Vec::new();
+                while !matches!(self.peek(), TokenKind::RParen) {
+                    params.push(self.parse_type()?);
+                    if matches!(self.peek(), TokenKind::Comma) {
+                        self.advance();
+                    } else {
+                        break;
+                    }
+                }
+                self.expect(")", TokenKind::RParen)?;
+                self.expect("->", TokenKind::Arrow)?;
+                let ret = self.parse_type()?;
+                Ok(Type::Fn(params, Box::new(ret)))
+            }
+            _ => {
+                let (name, _) = self.expect_ident()?;
+                Ok(match name.as_str() {
+                    "i64" => Type::Prim(vitte_ast::PrimType::I64),
+                    "f64" => Type::Prim(vitte_ast::PrimType::F64),
+                    "bool" => Type::Prim(vitte_ast::PrimType::Bool),
+                    "str" => Type::Prim(vitte_ast::PrimType::Str),
+                    _ => Type::Ident(name),
+                })
+            }
+        }
+    }
+
+    fn parse_block(&mut self) -> Result<Block, ParseError> {
+        let start = self.expect("{", TokenKind::LBrace)?;
+        let mut stmts = Vec::new();
+        while !matches!(self.peek(), TokenKind::RBrace) {
+            stmts.push(self.parse_stmt()?);
+        }
+        let end = self.expect("}", TokenKind::RBrace)?;
+        Ok(Block { stmts, span: Span::merge(start, end) })
+    }
+
+    /// Like [`Self::parse_block`], but a failing statement is recorded into
+    /// `errors` and skipped up to the next `;` or `}` instead of aborting
+    /// the whole block.
+    fn parse_block_recover(&mut self, errors: &mut Vec<ParseError>) -> Result<Block, ParseError> {
+        let start = self.expect("{", TokenKind::LBrace)?;
+        let mut stmts = Vec::new();
+        while !matches!(self.peek(), TokenKind::RBrace | TokenKind::Eof) {
+            match self.parse_stmt_recover(errors) {
+                Ok(stmt) => stmts.push(stmt),
+                Err(e) => {
+                    errors.push(e);
+                    self.synchronize_stmt();
+                }
+            }
+        }
+        let end = self.expect("}", TokenKind::RBrace)?;
+        Ok(Block { stmts, span: Span::merge(start, end) })
+    }
+
+    fn parse_stmt(&mut self) -> Result<Stmt, ParseError> {
+        match self.peek() {
+            TokenKind::Kw(Keyword::Let) => self.parse_let(),
+            TokenKind::Kw(Keyword::Return) => self.parse_return(),
+            TokenKind::Kw(Keyword::If) => self.parse_if(),
+            TokenKind::Kw(Keyword::While) => self.parse_while(),
+            TokenKind::Kw(Keyword::For) => self.parse_for(),
+            TokenKind::LBrace => {
+                let block = self.parse_block()?;
+                let span = block.span;
+                Ok(Stmt::Block(block, span))
+            }
+            _ => {
+                let expr = self.parse_expr()?;
+                let start = expr.span();
+                if matches!(self.peek(), TokenKind::Eq) {
+                    if !is_lvalue(&expr) {
+                        return Err(ParseError::new(ParseErrorKind::InvalidAssignTarget, start));
+                    }
+                    self.advance();
+                    let value = self.parse_expr()?;
+                    let end = self.expect(";", TokenKind::Semi)?;
+                    Ok(Stmt::Assign { target: expr, value, span: Span::merge(start, end) })
+                } else {
+                    let end = self.expect(";", TokenKind::Semi)?;
+                    Ok(Stmt::Expr(expr, Span::merge(start, end)))
+                }
+            }
+        }
+    }
+
+    /// Like [`Self::parse_stmt`], but a missing trailing `;` on a
+    /// `let`/`return`/assignment/expression statement is recorded into
+    /// `errors` and recovered from instead of aborting the statement,
+    /// provided the next token unambiguously starts a new one (see
+    /// [`Self::can_start_stmt`]). Only used by [`Self::parse_block_recover`].
+    fn parse_stmt_recover(&mut self, errors: &mut Vec<ParseError>) -> Result<Stmt, ParseError> {
+        match self.peek() {
+            TokenKind::Kw(Keyword::Let) => self.parse_let_recover(errors),
+            TokenKind::Kw(Keyword::Return) => self.parse_return_recover(errors),
+            TokenKind::Kw(Keyword::If) => self.parse_if(),
+            TokenKind::Kw(Keyword::While) => self.parse_while(),
+            TokenKind::Kw(Keyword::For) => self.parse_for(),
+            TokenKind::LBrace => {
+                let block = self.parse_block()?;
+                let span = block.span;
+                Ok(Stmt::Block(block, span))
+            }
+            _ => {
+                let expr = self.parse_expr()?;
+                let start = expr.span();
+                if matches!(self.peek(), TokenKind::Eq) {
+                    if !is_lvalue(&expr) {
+                        return Err(ParseError::new(ParseErrorKind::InvalidAssignTarget, start));
+                    }
+                    self.advance();
+                    let value = self.parse_expr()?;
+                    let end = self.expect_semi_recover(start, errors)?;
+                    Ok(Stmt::Assign { target: expr, value, span: Span::merge(start, end) })
+                } else {
+                    let end = self.expect_semi_recover(start, errors)?;
+                    Ok(Stmt::Expr(expr, Span::merge(start, end)))
+                }
+            }
+        }
+    }
+
+    fn parse_let_recover(&mut self, errors: &mut Vec<ParseError>) -> Result<Stmt, ParseError> {
+        let start = self.expect("let", TokenKind::Kw(Keyword::Let))?;
+        let (name, name_span) = self.expect_ident()?;
+        let ty = if matches!(self.peek(), TokenKind::Colon) {
+            self.advance();
+            Some(self.parse_type()?)
+        } else {
+            None
+        };
+        self.expect("=", TokenKind::Eq)?;
+        let value = self.parse_expr()?;
+        let end = self.expect_semi_recover(start, errors)?;
+        Ok(Stmt::Let { name, name_span, ty, value, span: Span::merge(start, end) })
+    }
+
+    fn parse_return_recover(&mut self, errors: &mut Vec<ParseError>) -> Result<Stmt, ParseError> {
+        let start = self.expect("return", TokenKind::Kw(Keyword::Return))?;
+        let value = if matches!(self.peek(), TokenKind::Semi) { None } else { Some(self.parse_expr()?) };
+        let end = self.expect_semi_recover(start, errors)?;
+        Ok(Stmt::Return(value, Span::merge(start, end)))
+    }
+
+    /// Expects a `;`, but if one isn't there and the next token already
+    /// looks like the start of a new statement, synthesizes a zero-width
+    /// `;` right after `prev` instead of failing: records a
+    /// [`ParseErrorKind::MissingSemicolon`] into `errors` and lets the
+    /// caller keep parsing. Falls back to a hard [`Self::expect`] failure
+    /// for anything else, since that's not a missing-semicolon situation.
+    fn expect_semi_recover(&mut self, prev: Span, errors: &mut Vec<ParseError>) -> Result<Span, ParseError> {
+        if matches!(self.peek(), TokenKind::Semi) {
+            return Ok(self.advance().span);
+        }
+        if self.can_start_stmt() {
+            let synthesized = Span::new(prev.end, prev.end);
+            errors.push(ParseError::new(ParseErrorKind::MissingSemicolon, synthesized));
+            return Ok(synthesized);
+        }
+        self.expect(";", TokenKind::Semi)
+    }
+
+    /// Whether the current token could begin a new statement: a
+    /// statement-leading keyword, `}`/EOF closing the block, or anything
+    /// that can start an expression. Used to decide whether a missing `;`
+    /// is safely recoverable.
+    fn can_start_stmt(&self) -> bool {
+        matches!(
+            self.peek(),
+            TokenKind::Kw(Keyword::Let)
+                | TokenKind::Kw(Keyword::Return)
+                | TokenKind::Kw(Keyword::If)
+                | TokenKind::Kw(Keyword::While)
+                | TokenKind::Kw(Keyword::For)
+                | TokenKind::Kw(Keyword::True)
+                | TokenKind::Kw(Keyword::False)
+                | TokenKind::Ident(_)
+                | TokenKind::Int(..)
+                | TokenKind::Float(..)
+                | TokenKind::Str(_)
+                | TokenKind::LParen
+                | TokenKind::LBrace
+                | TokenKind::Minus
+                | TokenKind::Bang
+                | TokenKind::RBrace
+                | TokenKind::Eof
+        )
+    }
+
+    fn parse_let(&mut self) -> Result<Stmt, ParseError> {
+        let start = self.expect("let", TokenKind::Kw(Keyword::Let))?;
+        let (name, name_span) = self.expect_ident()?;
+        let ty = if matches!(self.peek(), TokenKind::Colon) {
+            self.advance();
+            Some(self.parse_type()?)
+        } else {
+            None
+        };
+        self.expect("=", TokenKind::Eq)?;
+        let value = self.parse_expr()?;
+        let end = self.expect(";", TokenKind::Semi)?;
+        Ok(Stmt::Let { name, name_span, ty, value, span: Span::merge(start, end) })
+    }
+
+    fn parse_return(&mut self) -> Result<Stmt, ParseError> {
+        let start = self.expect("return", TokenKind::Kw(Keyword::Return))?;
+        let value = if matches!(self.peek(), TokenKind::Semi) { None } else { Some(self.parse_expr()?) };
+        let end = self.expect(";", TokenKind::Semi)?;
+        Ok(Stmt::Return(value, Span::merge(start, end)))
+    }
+
+    fn parse_if(&mut self) -> Result<Stmt, ParseError> {
+        let start = self.expect("if", TokenKind::Kw(Keyword::If))?;
+        let cond = self.without_struct_lit(Self::parse_expr)?;
+        let then_branch = self.parse_block()?;
+        let mut span = Span::merge(start, then_branch.span);
+        let else_branch = if self.eat_kw(Keyword::Else) {
+            let branch = if matches!(self.peek(), TokenKind::Kw(Keyword::If)) {
+                self.parse_if()?
+            } else {
+                let block = self.parse_block()?;
+                let bspan = block.span;
+                Stmt::Block(block, bspan)
+            };
+            span = Span::merge(span, branch.span());
+            Some(Box::new(branch))
+        } else {
+            None
+        };
+        Ok(Stmt::If { cond, then_branch, else_branch, span })
+    }
+
+    fn parse_while(&mut self) -> Result<Stmt, ParseError> {
+        let start = self.expect("while", TokenKind::Kw(Keyword::While))?;
+        let cond = self.without_struct_lit(Self::parse_expr)?;
+        let body = self.parse_block()?;
+        let span = Span::merge(start, body.span);
+        Ok(Stmt::While { cond, body, span })
+    }
+
+    fn parse_for(&mut self) -> Result<Stmt, ParseError> {
+        let start = self.expect("for", TokenKind::Kw(Keyword::For))?;
+        let (var, _) = self.expect_ident()?;
+        self.expect_kw(Keyword::In, "in")?;
+        let iter = self.without_struct_lit(Self::parse_expr)?;
+        let body = self.parse_block()?;
+        let span = Span::merge(start, body.span);
+        Ok(Stmt::For { var, iter, body, span })
+    }
+
+    pub fn parse_expr(&mut self) -> Result<Expr, ParseError> {
+        self.parse_range()
+    }
+
+    fn can_start_expr(&self) -> bool {
+        matches!(
+            self.peek(),
+            TokenKind::Ident(_)
+                | TokenKind::Int(..)
+                | TokenKind::Float(..)
+                | TokenKind::Str(_)
+                | TokenKind::Kw(Keyword::True)
+                | TokenKind::Kw(Keyword::False)
+                | TokenKind::LParen
+                | TokenKind::Minus
+                | TokenKind::Bang
+        )
+    }
+
+    /// Ranges bind looser than every binary operator, so `a..b + 1` is
+    /// `a..(b + 1)`. This layer sits above [`Self::parse_prec`] and handles
+    /// the open-ended forms (`..b`, `a..`, bare `..`) that a plain Pratt
+    /// table can't express, since either side may simply be absent.
+    fn parse_range(&mut self) -> Result<Expr, ParseError> {
+        if let TokenKind::DotDot | TokenKind::DotDotEq = self.peek() {
+            let inclusive = matches!(self.peek(), TokenKind::DotDotEq);
+            let dots_span = self.advance().span;
+            let end = if self.can_start_expr() { Some(Box::new(self.parse_prec(0)?)) } else { None };
+            let span = end.as_ref().map_or(dots_span, |e| Span::merge(dots_span, e.span()));
+            return Ok(Expr::Range { start: None, end, inclusive, span });
+        }
+
+        let lhs = self.parse_prec(0)?;
+        if let TokenKind::DotDot | TokenKind::DotDotEq = self.peek() {
+            let inclusive = matches!(self.peek(), TokenKind::DotDotEq);
+            self.advance();
+            let end = if self.can_start_expr() { Some(Box::new(self.parse_prec(0)?)) } else { None };
+            let span = Span::merge(lhs.span(), end.as_ref().map_or(lhs.span(), |e| e.span()));
+            return Ok(Expr::Range { start: Some(Box::new(lhs)), end, inclusive, span });
+        }
+        Ok(lhs)
+    }
+
+    fn binop_bp(kind: &TokenKind<'a>) -> Option<(BinOp, u8, u8)> {
+        Some(match kind {
+            TokenKind::OrOr => (BinOp::Or, 1, 2),
+            TokenKind::AndAnd => (BinOp::And, 2, 3),
+            TokenKind::EqEq => (BinOp::Eq, 3, 4),
+            TokenKind::BangEq => (BinOp::Ne, 3, 4),
+            TokenKind::Lt => (BinOp::Lt, 3, 4),
+            TokenKind::Le => (BinOp::Le, 3, 4),
+            TokenKind::Gt => (BinOp::Gt, 3, 4),
+            TokenKind::Ge => (BinOp::Ge, 3, 4),
+            TokenKind::Plus => (BinOp::Add, 5, 6),
+            TokenKind::Minus => (BinOp::Sub, 5, 6),
+            TokenKind::Star => (BinOp::Mul, 7, 8),
+            TokenKind::Slash => (BinOp::Div, 7, 8),
+            TokenKind::Percent => (BinOp::Rem, 7, 8),
+            _ => return None,
+        })
+    }
+
+    /// Pratt-style precedence-climbing parser for binary expressions. Each
+    /// recursive call only accepts operators whose left binding power is at
+    /// least `min_bp`, which is what makes `*` bind tighter than `+` without
+    /// a separate grammar rule per precedence level.
+    fn parse_prec(&mut self, min_bp: u8) -> Result<Expr, ParseError> {
+        let mut lhs = self.parse_unary()?;
+        while let Some((op, left_bp, right_bp)) = Self::binop_bp(self.peek()) {
+            if left_bp < min_bp {
+                break;
+            }
+            self.advance();
+            let rhs = self.parse_prec(right_bp)?;
+            let span = Span::merge(lhs.span(), rhs.span());
+            lhs = Expr::Binary { op, lhs: Box::new(lhs), rhs: Box::new(rhs), span };
+        }
+        Ok(lhs)
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr, ParseError> {
+        match self.peek() {
+            TokenKind::Minus => {
+                let start = self.advance().span;
+                let expr = self.parse_unary()?;
+                let span = Span::merge(start, expr.span());
+                Ok(Expr::Unary { op: UnOp::Neg, expr: Box::new(expr), span })
+            }
+            TokenKind::Bang => {
+                let start = self.advance().span;
+                let expr = self.parse_unary()?;
+                let span = Span::merge(start, expr.span());
+                Ok(Expr::Unary { op: UnOp::Not, expr: Box::new(expr), span })
+            }
+            _ => self.parse_postfix(),
+        }
+    }
+
+    fn parse_postfix(&mut self) -> Result<Expr, ParseError> {
+        let mut expr = self.parse_primary()?;
+        loop {
+            match self.peek() {
+                TokenKind::LParen => {
+                    self.advance();
+                    let mut args = Vec::new();
+                    while !matches!(self.peek(), TokenKind::RParen) {
+                        args.push(self.with_struct_lit(Self::parse_expr)?);
+                        if matches!(self.peek(), TokenKind::Comma) {
+                            self.advance();
+                        } else {
+                            break;
+                        }
+                    }
+                    let end = self.expect(")", TokenKind::RParen)?;
+                    let span = Span::merge(expr.span(), end);
+                    expr = Expr::Call { callee: Box::new(expr), args, span };
+                }
+                TokenKind::Dot => {
+                    self.advance();
+                    let (name, name_span) = self.expect_ident()?;
+                    let span = Span::merge(expr.span(), name_span);
+                    expr = Expr::Field { expr: Box::new(expr), name, span };
+                }
+                TokenKind::LBracket => {
+                    self.advance();
+                    let index = self.with_struct_lit(Self::parse_expr)?;
+                    let end = self.expect("]", TokenKind::RBracket)?;
+                    let span = Span::merge(expr.span(), end);
+                    expr = Expr::Index { expr: Box::new(expr), index: Box::new(index), span };
+                }
+                _ => break,
+            }
+        }
+        Ok(expr)
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr, ParseError> {
+        let span = self.peek_span();
+        match self.peek().clone() {
+            TokenKind::Ident(s) => {
+                let name = s.to_string();
+                self.advance();
+                if !self.no_struct_lit && matches!(self.peek(), TokenKind::LBrace) {
+                    self.parse_struct_lit(name, span)
+                } else {
+                    Ok(Expr::Ident(name, span))
+                }
+            }
+            TokenKind::Int(v, _) => {
+                self.advance();
+                Ok(Expr::Int(v, span))
+            }
+            TokenKind::Float(v, _) => {
+                self.advance();
+                Ok(Expr::Float(v, span))
+            }
+            TokenKind::Str(s) => {
+                self.advance();
+                Ok(Expr::Str(s, span))
+            }
+            TokenKind::Kw(Keyword::True) => {
+                self.advance();
+                Ok(Expr::Bool(true, span))
+            }
+            TokenKind::Kw(Keyword::False) => {
+                self.advance();
+                Ok(Expr::Bool(false, span))
+            }
+            TokenKind::LParen => {
+                self.advance();
+                let inner = self.with_struct_lit(Self::parse_expr)?;
+                let end = self.expect(")", TokenKind::RParen)?;
+                Ok(Expr::Group(Box::new(inner), Span::merge(span, end)))
+            }
+            _ => Err(self.unexpected("expression")),
+        }
+    }
+
+    fn parse_struct_lit(&mut self, name: String, start: Span) -> Result<Expr, ParseError> {
+        self.expect("{", TokenKind::LBrace)?;
+        let mut fields = Vec::new();
+        while !matches!(self.peek(), TokenKind::RBrace) {
+            let (field_name, _) = self.expect_ident()?;
+            self.expect(":", TokenKind::Colon)?;
+            let value = self.with_struct_lit(Self::parse_expr)?;
+            fields.push((field_name, value));
+            if matches!(self.peek(), TokenKind::Comma) {
+                self.advance();
+            } else {
+                break;
+            }
+        }
+        let end = self.expect("}", TokenKind::RBrace)?;
+        Ok(Expr::StructLit { name, fields, span: Span::merge(start, end) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_one_expr(src: &str) -> Expr {
+        let mut p = Parser::new(src).unwrap();
+        p.parse_expr().unwrap()
+    }
+
+    #[test]
+    fn precedence_climbing_mul_binds_tighter_than_add() {
+        let expr = parse_one_expr("a + b * c");
+        match expr {
+            Expr::Binary { op: BinOp::Add, rhs, .. } => {
+                assert!(matches!(*rhs, Expr::Binary { op: BinOp::Mul, .. }));
+            }
+            other => panic!("expected top-level Add, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn binary_span_covers_whole_expression() {
+        let src = "a + b * c";
+        let expr = parse_one_expr(src);
+        let span = expr.span();
+        assert_eq!(span.start, 0);
+        assert_eq!(span.end as usize, src.len());
+    }
+
+    #[test]
+    fn call_span_extends_to_closing_paren() {
+        let src = "foo(1, 2)";
+        let expr = parse_one_expr(src);
+        assert_eq!(expr.span().end as usize, src.len());
+    }
+
+    #[test]
+    fn field_access_chain() {
+        let expr = parse_one_expr("a.b.c");
+        match expr {
+            Expr::Field { name, .. } => assert_eq!(name, "c"),
+            other => panic!("expected Field, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn nested_index_expressions() {
+        let expr = parse_one_expr("m[i][j]");
+        match expr {
+            Expr::Index { expr, .. } => assert!(matches!(*expr, Expr::Index { .. })),
+            other => panic!("expected Index, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn call_result_can_be_indexed() {
+        let src = "foo().bar[k]";
+        let expr = parse_one_expr(src);
+        assert_eq!(expr.span().end as usize, src.len());
+        match expr {
+            Expr::Index { expr, .. } => assert!(matches!(*expr, Expr::Field { .. })),
+            other => panic!("expected Index, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn tuple_type_as_param() {
+        let mut p = Parser::new("fn f(p: (i64, f64)) { }").unwrap();
+        let program = p.parse_program().unwrap();
+        match &program.items[0] {
+            vitte_ast::Item::Fn(f) => {
+                assert_eq!(f.params[0].ty, Type::Tuple(vec![Type::Prim(vitte_ast::PrimType::I64), Type::Prim(vitte_ast::PrimType::F64)]));
+            }
+            other => panic!("expected Fn, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn array_type_as_param() {
+        let mut p = Parser::new("fn f(xs: [i64]) { }").unwrap();
+        let program = p.parse_program().unwrap();
+        match &program.items[0] {
+            vitte_ast::Item::Fn(f) => {
+                assert_eq!(f.params[0].ty, Type::Array(Box::new(Type::Prim(vitte_ast::PrimType::I64))));
+            }
+            other => panic!("expected Fn, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn fn_type_as_param() {
+        let mut p = Parser::new("fn f(cb: fn(i64) -> bool) { }").unwrap();
+        let program = p.parse_program().unwrap();
+        match &program.items[0] {
+            vitte_ast::Item::Fn(f) => {
+                assert_eq!(
+                    f.params[0].ty,
+                    Type::Fn(vec![Type::Prim(vitte_ast::PrimType::I64)], Box::new(Type::Prim(vitte_ast::PrimType::Bool)))
+                );
+            }
+            other => panic!("expected Fn, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn exclusive_range() {
+        let expr = parse_one_expr("0..10");
+        match expr {
+            Expr::Range { start: Some(s), end: Some(e), inclusive: false, .. } => {
+                assert!(matches!(*s, Expr::Int(0, _)));
+                assert!(matches!(*e, Expr::Int(10, _)));
+            }
+            other => panic!("expected exclusive Range, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn inclusive_range_with_ident_end() {
+        let expr = parse_one_expr("1..=n");
+        assert!(matches!(expr, Expr::Range { inclusive: true, .. }));
+    }
+
+    #[test]
+    fn for_loop_over_range() {
+        let mut p = Parser::new("for i in 0..len { }").unwrap();
+        let stmt = p.parse_stmt().unwrap();
+        match stmt {
+            Stmt::For { iter: Expr::Range { .. }, .. } => {}
+            other => panic!("expected For over a Range, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_program_recover_collects_errors_past_broken_items() {
+        let src = "fn broken(a: i64 { }\nfn also_broken() nope\nfn good() { let x = 1; }";
+        let mut p = Parser::new(src).unwrap();
+        let (program, errors) = p.parse_program_recover();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(program.items.len(), 1);
+        match &program.items[0] {
+            vitte_ast::Item::Fn(f) => assert_eq!(f.name, "good"),
+            other => panic!("expected Fn, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn a_missing_semicolon_is_recovered_with_one_diagnostic() {
+        let src = "fn f() { let x = 1 let y = 2; }";
+        let mut p = Parser::new(src).unwrap();
+        let (program, errors) = p.parse_program_recover();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind, ParseErrorKind::MissingSemicolon);
+
+        let vitte_ast::Item::Fn(f) = &program.items[0] else { panic!("expected Fn, got {:?}", program.items[0]) };
+        assert_eq!(f.body.stmts.len(), 2);
+        match (&f.body.stmts[0], &f.body.stmts[1]) {
+            (Stmt::Let { name: a, .. }, Stmt::Let { name: b, .. }) => {
+                assert_eq!(a, "x");
+                assert_eq!(b, "y");
+            }
+            other => panic!("expected two Let statements, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn struct_literal_in_let() {
+        let mut p = Parser::new("let p = Point { x: 1, y: 2 };").unwrap();
+        let stmt = p.parse_stmt().unwrap();
+        match stmt {
+            Stmt::Let { value: Expr::StructLit { name, fields, .. }, .. } => {
+                assert_eq!(name, "Point");
+                assert_eq!(fields.len(), 2);
+                assert_eq!(fields[0].0, "x");
+            }
+            other => panic!("expected Let with StructLit, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn a_checkpoint_lets_a_failed_speculative_parse_fall_back_to_a_block() {
+        let mut p = Parser::new("{ 1; }").unwrap();
+        let cp = p.checkpoint();
+
+        // A bare `{` can't start an expression, so speculatively trying to
+        // parse one here is expected to fail...
+        assert!(p.parse_expr().is_err());
+
+        // ...but restoring the checkpoint rewinds the cursor so the same
+        // tokens can be parsed again, this time as the block they are.
+        p.restore(cp);
+        let block = p.parse_block().unwrap();
+        assert_eq!(block.stmts.len(), 1);
+    }
+
+    #[test]
+    fn else_if_chain_nests_without_mandatory_braces() {
+        let mut p = Parser::new("if a { } else if b { } else { }").unwrap();
+        let stmt = p.parse_stmt().unwrap();
+        let Stmt::If { else_branch: Some(first_else), .. } = stmt else {
+            panic!("expected outer If with an else branch")
+        };
+        match *first_else {
+            Stmt::If { else_branch: Some(second_else), .. } => {
+                assert!(matches!(*second_else, Stmt::Block(..)));
+            }
+            other => panic!("expected nested If for `else if`, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn if_condition_still_parses_as_block_not_struct_lit() {
+        let mut p = Parser::new("if cond { }").unwrap();
+        let stmt = p.parse_stmt().unwrap();
+        match stmt {
+            Stmt::If { cond, then_branch, .. } => {
+                assert!(matches!(cond, Expr::Ident(..)));
+                assert!(then_branch.stmts.is_empty());
+            }
+            other => panic!("expected If, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn simple_assignment() {
+        let mut p = Parser::new("x = 1;").unwrap();
+        let stmt = p.parse_stmt().unwrap();
+        match stmt {
+            Stmt::Assign { target, value, .. } => {
+                assert!(matches!(target, Expr::Ident(..)));
+                assert!(matches!(value, Expr::Int(1, _)));
+            }
+            other => panic!("expected Assign, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn field_assignment() {
+        let mut p = Parser::new("a.b = 2;").unwrap();
+        let stmt = p.parse_stmt().unwrap();
+        assert!(matches!(stmt, Stmt::Assign { target: Expr::Field { .. }, .. }));
+    }
+
+    #[test]
+    fn indexed_assignment() {
+        let mut p = Parser::new("a[i] = 2;").unwrap();
+        let stmt = p.parse_stmt().unwrap();
+        assert!(matches!(stmt, Stmt::Assign { target: Expr::Index { .. }, .. }));
+    }
+
+    #[test]
+    fn assigning_to_a_literal_is_rejected() {
+        let mut p = Parser::new("1 = 2;").unwrap();
+        let err = p.parse_stmt().unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::InvalidAssignTarget);
+    }
+
+    #[test]
+    fn let_statement_round_trips_span() {
+        let mut p = Parser::new("let x = 1 + 2;").unwrap();
+        let stmt = p.parse_stmt().unwrap();
+        assert_eq!(stmt.span().start, 0);
+    }
+
+    #[test]
+    fn for_loop_uses_in_keyword() {
+        let mut p = Parser::new("for x in xs { x; }").unwrap();
+        let stmt = p.parse_stmt().unwrap();
+        assert!(matches!(stmt, Stmt::For { .. }));
+    }
+
+    #[test]
+    fn for_loop_over_int_literal() {
+        let mut p = Parser::new("for i in 0 { }").unwrap();
+        let stmt = p.parse_stmt().unwrap();
+        match stmt {
+            Stmt::For { var, iter, .. } => {
+                assert_eq!(var, "i");
+                assert!(matches!(iter, Expr::Int(0, _)));
+            }
+            other => panic!("expected For, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn fn_decl_with_params_and_return_type() {
+        let mut p = Parser::new("fn add(a: i64, b: i64) -> i64 { return a + b; }").unwrap();
+        let program = p.parse_program().unwrap();
+        assert_eq!(program.items.len(), 1);
+    }
+}
@@ -0,0 +1,63 @@
+use std::time::Duration;
+
+/// A source of time for a running program. Swappable so sandboxed or
+/// deterministic execution (tests, replay) can substitute [`FixedClock`]
+/// for the host clock.
+pub trait Clock {
+    /// Elapsed time since some unspecified reference point (typically
+    /// process start). Must never go backward.
+    fn now_mono(&self) -> Duration;
+
+    /// Wall-clock time as nanoseconds since the Unix epoch, when the
+    /// backend can provide one.
+    fn now_unix_nanos(&self) -> Option<u128> {
+        None
+    }
+}
+
+/// A [`Clock`] with caller-supplied, unmoving readings — useful for tests
+/// that need reproducible timestamps.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FixedClock {
+    mono: Duration,
+    unix_nanos: Option<u128>,
+}
+
+impl FixedClock {
+    pub fn new(mono: Duration) -> Self {
+        Self { mono, unix_nanos: None }
+    }
+
+    pub fn with_unix_nanos(mut self, unix_nanos: u128) -> Self {
+        self.unix_nanos = Some(unix_nanos);
+        self
+    }
+}
+
+impl Clock for FixedClock {
+    fn now_mono(&self) -> Duration {
+        self.mono
+    }
+
+    fn now_unix_nanos(&self) -> Option<u128> {
+        self.unix_nanos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_clock_returns_its_configured_values() {
+        let clock = FixedClock::new(Duration::from_secs(42)).with_unix_nanos(123);
+        assert_eq!(clock.now_mono(), Duration::from_secs(42));
+        assert_eq!(clock.now_unix_nanos(), Some(123));
+    }
+
+    #[test]
+    fn fixed_clock_defaults_to_no_unix_time() {
+        let clock = FixedClock::new(Duration::from_secs(1));
+        assert_eq!(clock.now_unix_nanos(), None);
+    }
+}
@@ -0,0 +1,19 @@
+use std::fmt;
+
+/// A problem raised by a [`crate::File`] or [`crate::MemFs`] operation.
+#[derive(Debug)]
+pub enum FsError {
+    NotFound,
+    Io(&'static str),
+}
+
+impl fmt::Display for FsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FsError::NotFound => write!(f, "not found"),
+            FsError::Io(msg) => write!(f, "I/O error: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for FsError {}
@@ -0,0 +1,24 @@
+use crate::error::FsError;
+
+/// Mirrors `std::io::SeekFrom` so [`File`] doesn't force every caller to
+/// depend on `std`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeekFrom {
+    Start(u64),
+    Current(i64),
+    End(i64),
+}
+
+/// A handle to an open file, host-backed or otherwise.
+pub trait File {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, FsError>;
+    fn write(&mut self, buf: &[u8]) -> Result<usize, FsError>;
+    fn flush(&mut self) -> Result<(), FsError>;
+
+    /// Moves the file's cursor, returning its new absolute position.
+    /// Unsupported by default, since not every backend (e.g. a pipe) can
+    /// seek; implementations that can should override this.
+    fn seek(&mut self, _pos: SeekFrom) -> Result<u64, FsError> {
+        Err(FsError::Io("seek unsupported"))
+    }
+}
@@ -0,0 +1,171 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::RwLock;
+
+/// What [`MemFs::metadata`] reports about a path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Metadata {
+    pub is_dir: bool,
+}
+
+/// A minimal in-memory filesystem: an instance owns its files and
+/// directories, so two `MemFs` values never see each other's writes.
+/// Meant for tests and sandboxed execution where touching the real
+/// filesystem isn't desired.
+///
+/// Paths are plain strings compared as-is (no `.`/`..` resolution); callers
+/// are expected to pass normalized, slash-separated paths.
+#[derive(Debug, Default)]
+pub struct MemFs {
+    files: RwLock<BTreeMap<String, Vec<u8>>>,
+    dirs: RwLock<BTreeSet<String>>,
+}
+
+/// Strips a trailing slash so `"/tmp/"` and `"/tmp"` are treated as the
+/// same path.
+fn normalize(path: &str) -> &str {
+    path.trim_end_matches('/')
+}
+
+/// The immediate parent of `path`, or `None` if `path` has no slash (and so
+/// has no directory this filesystem tracks).
+fn parent_of(path: &str) -> Option<String> {
+    let path = normalize(path);
+    match path.rfind('/') {
+        Some(0) => Some("/".to_string()),
+        Some(idx) => Some(path[..idx].to_string()),
+        None => None,
+    }
+}
+
+impl MemFs {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Writes `data` to `path`. When `create_dirs` is set, every missing
+    /// ancestor directory is created first, the way `std::fs::create_dir_all`
+    /// followed by a write would behave.
+    pub fn write(&self, path: &str, data: Vec<u8>, create_dirs: bool) {
+        if create_dirs {
+            if let Some(parent) = parent_of(path) {
+                self.create_dir_all(&parent);
+            }
+        }
+        self.files.write().expect("MemFs lock poisoned").insert(normalize(path).to_string(), data);
+    }
+
+    pub fn read(&self, path: &str) -> Option<Vec<u8>> {
+        self.files.read().expect("MemFs lock poisoned").get(normalize(path)).cloned()
+    }
+
+    pub fn remove_file(&self, path: &str) -> Option<Vec<u8>> {
+        self.files.write().expect("MemFs lock poisoned").remove(normalize(path))
+    }
+
+    pub fn contains(&self, path: &str) -> bool {
+        self.files.read().expect("MemFs lock poisoned").contains_key(normalize(path))
+    }
+
+    /// Records `path` and every missing ancestor as a directory.
+    pub fn create_dir_all(&self, path: &str) {
+        let mut dirs = self.dirs.write().expect("MemFs lock poisoned");
+        let mut current = String::new();
+        for segment in normalize(path).split('/').filter(|s| !s.is_empty()) {
+            current.push('/');
+            current.push_str(segment);
+            dirs.insert(current.clone());
+        }
+    }
+
+    /// Reports whether `path` is a known directory, a known file, or
+    /// neither.
+    pub fn metadata(&self, path: &str) -> Option<Metadata> {
+        let path = normalize(path);
+        if self.dirs.read().expect("MemFs lock poisoned").contains(path) {
+            return Some(Metadata { is_dir: true });
+        }
+        if self.files.read().expect("MemFs lock poisoned").contains_key(path) {
+            return Some(Metadata { is_dir: false });
+        }
+        None
+    }
+
+    /// Lists the immediate children of `path` — files and directories whose
+    /// parent is exactly `path`, not further descendants.
+    pub fn read_dir(&self, path: &str) -> Vec<String> {
+        let path = normalize(path);
+        let files = self.files.read().expect("MemFs lock poisoned");
+        let dirs = self.dirs.read().expect("MemFs lock poisoned");
+        let mut children: Vec<String> =
+            files.keys().chain(dirs.iter()).filter(|candidate| parent_of(candidate).as_deref() == Some(path)).cloned().collect();
+        children.sort();
+        children.dedup();
+        children
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_then_read_round_trips_on_the_same_instance() {
+        let fs = MemFs::new();
+        fs.write("/tmp/a.txt", b"hello".to_vec(), false);
+        assert_eq!(fs.read("/tmp/a.txt"), Some(b"hello".to_vec()));
+    }
+
+    #[test]
+    fn two_instances_do_not_share_state() {
+        let a = MemFs::new();
+        let b = MemFs::new();
+        a.write("/tmp/a.txt", b"hello".to_vec(), false);
+        assert_eq!(a.read("/tmp/a.txt"), Some(b"hello".to_vec()));
+        assert_eq!(b.read("/tmp/a.txt"), None);
+    }
+
+    #[test]
+    fn remove_file_deletes_a_written_entry() {
+        let fs = MemFs::new();
+        fs.write("/tmp/a.txt", b"hello".to_vec(), false);
+        assert_eq!(fs.remove_file("/tmp/a.txt"), Some(b"hello".to_vec()));
+        assert_eq!(fs.read("/tmp/a.txt"), None);
+    }
+
+    #[test]
+    fn create_dir_all_records_every_ancestor() {
+        let fs = MemFs::new();
+        fs.create_dir_all("/tmp/sub/leaf");
+        assert_eq!(fs.metadata("/tmp"), Some(Metadata { is_dir: true }));
+        assert_eq!(fs.metadata("/tmp/sub"), Some(Metadata { is_dir: true }));
+        assert_eq!(fs.metadata("/tmp/sub/leaf"), Some(Metadata { is_dir: true }));
+    }
+
+    #[test]
+    fn metadata_distinguishes_files_from_directories() {
+        let fs = MemFs::new();
+        fs.create_dir_all("/tmp");
+        fs.write("/tmp/a.txt", b"hello".to_vec(), false);
+        assert_eq!(fs.metadata("/tmp"), Some(Metadata { is_dir: true }));
+        assert_eq!(fs.metadata("/tmp/a.txt"), Some(Metadata { is_dir: false }));
+        assert_eq!(fs.metadata("/tmp/missing"), None);
+    }
+
+    #[test]
+    fn read_dir_lists_only_immediate_children() {
+        let fs = MemFs::new();
+        fs.write("/tmp/a.txt", b"a".to_vec(), true);
+        fs.write("/tmp/sub/b.txt", b"b".to_vec(), true);
+        assert_eq!(fs.read_dir("/tmp"), vec!["/tmp/a.txt".to_string(), "/tmp/sub".to_string()]);
+        assert_eq!(fs.read_dir("/tmp/sub"), vec!["/tmp/sub/b.txt".to_string()]);
+    }
+
+    #[test]
+    fn write_with_create_dirs_implicitly_creates_parents() {
+        let fs = MemFs::new();
+        fs.write("/tmp/sub/a.txt", b"hello".to_vec(), true);
+        assert_eq!(fs.metadata("/tmp"), Some(Metadata { is_dir: true }));
+        assert_eq!(fs.metadata("/tmp/sub"), Some(Metadata { is_dir: true }));
+        assert_eq!(fs.read("/tmp/sub/a.txt"), Some(b"hello".to_vec()));
+    }
+}
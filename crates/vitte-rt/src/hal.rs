@@ -0,0 +1,103 @@
+use crate::entropy::{Entropy, FastEntropy};
+use std::fmt;
+
+#[derive(Debug)]
+pub enum HalError {
+    /// [`HalBuilder::with_secure_entropy`] was asked for a secure entropy
+    /// source, but none is compiled in (the `getrandom` feature is off).
+    NoSecureEntropy,
+}
+
+impl fmt::Display for HalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HalError::NoSecureEntropy => write!(f, "no secure entropy source is compiled in"),
+        }
+    }
+}
+
+impl std::error::Error for HalError {}
+
+/// The host-facing services a running program is given access to.
+pub struct Hal {
+    pub entropy: Box<dyn Entropy>,
+}
+
+/// Builds a [`Hal`], letting callers opt into stronger guarantees (like
+/// secure entropy) than the convenience defaults provide.
+#[derive(Default)]
+pub struct HalBuilder {
+    entropy: Option<Box<dyn Entropy>>,
+}
+
+impl HalBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Seeds every component with the best default available for a real
+    /// host: secure entropy when the `getrandom` feature is compiled in,
+    /// falling back to [`FastEntropy`] otherwise.
+    pub fn with_std(mut self) -> Self {
+        #[cfg(feature = "getrandom")]
+        {
+            self.entropy = Some(Box::new(crate::sys_entropy::SysEntropy));
+        }
+        #[cfg(not(feature = "getrandom"))]
+        {
+            self.entropy = Some(Box::new(FastEntropy::default()));
+        }
+        self
+    }
+
+    /// Requires a cryptographically secure entropy source, failing instead
+    /// of silently falling back to [`FastEntropy`] when one isn't compiled
+    /// in.
+    pub fn with_secure_entropy(self) -> Result<Self, HalError> {
+        #[cfg(feature = "getrandom")]
+        {
+            let mut this = self;
+            this.entropy = Some(Box::new(crate::sys_entropy::SysEntropy));
+            Ok(this)
+        }
+        #[cfg(not(feature = "getrandom"))]
+        {
+            let _ = self;
+            Err(HalError::NoSecureEntropy)
+        }
+    }
+
+    pub fn build(self) -> Hal {
+        Hal { entropy: self.entropy.unwrap_or_else(|| Box::new(FastEntropy::default())) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_falls_back_to_fast_entropy_when_unconfigured() {
+        let hal = HalBuilder::new().build();
+        let mut buf = [0u8; 8];
+        hal.entropy.fill(&mut buf);
+        assert_ne!(buf, [0u8; 8]);
+    }
+
+    #[cfg(not(feature = "getrandom"))]
+    #[test]
+    fn with_secure_entropy_errors_without_the_getrandom_feature() {
+        assert!(matches!(HalBuilder::new().with_secure_entropy(), Err(HalError::NoSecureEntropy)));
+    }
+
+    #[cfg(feature = "getrandom")]
+    #[test]
+    fn with_std_fills_distinct_buffers_across_two_calls() {
+        let hal = HalBuilder::new().with_std().build();
+        let mut first = [0u8; 16];
+        let mut second = [0u8; 16];
+        hal.entropy.fill(&mut first);
+        hal.entropy.fill(&mut second);
+        assert_ne!(first, second);
+    }
+}
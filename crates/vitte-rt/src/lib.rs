@@ -0,0 +1,41 @@
+//! Runtime support for executing compiled Vitte programs: filesystem,
+//! clock, and other host-facing services a program can be sandboxed
+//! against.
+
+mod clock;
+mod entropy;
+mod error;
+mod file;
+mod fs;
+mod hal;
+mod net;
+mod process;
+#[cfg(feature = "std")]
+mod std_clock;
+#[cfg(feature = "std")]
+mod std_file;
+#[cfg(feature = "net")]
+mod std_net;
+#[cfg(feature = "std")]
+mod std_process;
+#[cfg(feature = "getrandom")]
+mod sys_entropy;
+
+pub use clock::{Clock, FixedClock};
+pub use entropy::{Entropy, FastEntropy};
+pub use error::FsError;
+pub use file::{File, SeekFrom};
+pub use fs::{MemFs, Metadata};
+pub use hal::{Hal, HalBuilder, HalError};
+pub use net::{Net, NetError, NullNet, TcpListener, TcpStream, UdpSocket};
+pub use process::{NullProcess, Process, ProcessError, ProcessOutput};
+#[cfg(feature = "std")]
+pub use std_clock::StdClock;
+#[cfg(feature = "std")]
+pub use std_file::StdFile;
+#[cfg(feature = "net")]
+pub use std_net::StdNet;
+#[cfg(feature = "std")]
+pub use std_process::StdProcess;
+#[cfg(feature = "getrandom")]
+pub use sys_entropy::SysEntropy;
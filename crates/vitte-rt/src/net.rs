@@ -0,0 +1,82 @@
+use std::fmt;
+
+/// A problem raised by a [`Net`] operation.
+#[derive(Debug)]
+pub enum NetError {
+    /// The host [`Net`] implementation doesn't support this operation
+    /// (e.g. [`NullNet`], or a sandboxed build with networking disabled).
+    Unsupported,
+    Io(&'static str),
+}
+
+impl fmt::Display for NetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NetError::Unsupported => write!(f, "networking unsupported"),
+            NetError::Io(msg) => write!(f, "I/O error: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for NetError {}
+
+/// A connected TCP stream.
+pub trait TcpStream: Send {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, NetError>;
+    fn write(&mut self, buf: &[u8]) -> Result<usize, NetError>;
+}
+
+/// A bound, listening TCP socket.
+pub trait TcpListener: Send {
+    fn accept(&mut self) -> Result<(Box<dyn TcpStream>, String), NetError>;
+
+    /// The address actually bound to — in particular the port the host
+    /// assigned when the caller requested port `0`.
+    fn local_addr(&self) -> Result<String, NetError>;
+}
+
+/// A bound UDP socket.
+pub trait UdpSocket: Send {
+    fn send_to(&mut self, buf: &[u8], addr: &str) -> Result<usize, NetError>;
+    fn recv_from(&mut self, buf: &mut [u8]) -> Result<(usize, String), NetError>;
+}
+
+/// Host networking, swappable so sandboxed execution can substitute
+/// [`NullNet`] for the real thing.
+pub trait Net {
+    fn tcp_connect(&self, addr: &str, port: u16) -> Result<Box<dyn TcpStream>, NetError>;
+    fn tcp_listen(&self, addr: &str, port: u16) -> Result<Box<dyn TcpListener>, NetError>;
+    fn udp_bind(&self, addr: &str, port: u16) -> Result<Box<dyn UdpSocket>, NetError>;
+}
+
+/// A [`Net`] that rejects every operation — the default for sandboxed
+/// execution with networking disabled.
+#[derive(Debug, Default)]
+pub struct NullNet;
+
+impl Net for NullNet {
+    fn tcp_connect(&self, _addr: &str, _port: u16) -> Result<Box<dyn TcpStream>, NetError> {
+        Err(NetError::Unsupported)
+    }
+
+    fn tcp_listen(&self, _addr: &str, _port: u16) -> Result<Box<dyn TcpListener>, NetError> {
+        Err(NetError::Unsupported)
+    }
+
+    fn udp_bind(&self, _addr: &str, _port: u16) -> Result<Box<dyn UdpSocket>, NetError> {
+        Err(NetError::Unsupported)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn null_net_rejects_every_operation() {
+        let net = NullNet;
+        assert!(matches!(net.tcp_connect("127.0.0.1", 0), Err(NetError::Unsupported)));
+        assert!(matches!(net.tcp_listen("127.0.0.1", 0), Err(NetError::Unsupported)));
+        assert!(matches!(net.udp_bind("127.0.0.1", 0), Err(NetError::Unsupported)));
+    }
+}
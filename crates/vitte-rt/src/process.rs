@@ -0,0 +1,67 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum ProcessError {
+    /// The host [`Process`] implementation doesn't support this operation
+    /// (e.g. [`NullProcess`], or a sandboxed build with no process access).
+    Unsupported,
+    Io(&'static str),
+}
+
+impl fmt::Display for ProcessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProcessError::Unsupported => write!(f, "process spawning unsupported"),
+            ProcessError::Io(msg) => write!(f, "I/O error: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for ProcessError {}
+
+/// The result of running a child process to completion.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcessOutput {
+    pub status: i32,
+    pub stdout: Vec<u8>,
+    pub stderr: Vec<u8>,
+}
+
+/// Host process spawning, swappable so sandboxed execution can substitute
+/// [`NullProcess`] for the real thing.
+pub trait Process {
+    /// Spawns `prog` and waits for it to exit, without capturing its
+    /// output — for fire-and-forget invocations.
+    fn spawn(&self, prog: &str, args: &[&str]) -> Result<i32, ProcessError>;
+
+    /// Runs `prog` to completion, optionally feeding it `stdin`, and
+    /// captures its exit status and output.
+    fn run(&self, prog: &str, args: &[&str], stdin: Option<&[u8]>) -> Result<ProcessOutput, ProcessError>;
+}
+
+/// A [`Process`] that rejects every operation — the default for sandboxed
+/// execution with process spawning disabled.
+#[derive(Debug, Default)]
+pub struct NullProcess;
+
+impl Process for NullProcess {
+    fn spawn(&self, _prog: &str, _args: &[&str]) -> Result<i32, ProcessError> {
+        Err(ProcessError::Unsupported)
+    }
+
+    fn run(&self, _prog: &str, _args: &[&str], _stdin: Option<&[u8]>) -> Result<ProcessOutput, ProcessError> {
+        Err(ProcessError::Unsupported)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn null_process_rejects_every_operation() {
+        let process = NullProcess;
+        assert!(matches!(process.spawn("echo", &["hi"]), Err(ProcessError::Unsupported)));
+        assert!(matches!(process.run("echo", &["hi"], None), Err(ProcessError::Unsupported)));
+    }
+}
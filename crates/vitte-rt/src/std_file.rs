@@ -0,0 +1,61 @@
+use crate::error::FsError;
+use crate::file::{File, SeekFrom};
+use std::io::{Read, Seek, Write};
+
+/// A [`File`] backed by a real `std::fs::File`.
+pub struct StdFile(std::fs::File);
+
+impl StdFile {
+    pub fn open(path: &str) -> Result<Self, FsError> {
+        std::fs::File::open(path).map(StdFile).map_err(|_| FsError::Io("open failed"))
+    }
+
+    pub fn create(path: &str) -> Result<Self, FsError> {
+        std::fs::File::create(path).map(StdFile).map_err(|_| FsError::Io("create failed"))
+    }
+}
+
+impl File for StdFile {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, FsError> {
+        self.0.read(buf).map_err(|_| FsError::Io("read failed"))
+    }
+
+    fn write(&mut self, buf: &[u8]) -> Result<usize, FsError> {
+        self.0.write(buf).map_err(|_| FsError::Io("write failed"))
+    }
+
+    fn flush(&mut self) -> Result<(), FsError> {
+        self.0.flush().map_err(|_| FsError::Io("flush failed"))
+    }
+
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64, FsError> {
+        let pos = match pos {
+            SeekFrom::Start(n) => std::io::SeekFrom::Start(n),
+            SeekFrom::Current(n) => std::io::SeekFrom::Current(n),
+            SeekFrom::End(n) => std::io::SeekFrom::End(n),
+        };
+        self.0.seek(pos).map_err(|_| FsError::Io("seek failed"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeking_reads_from_the_requested_offset() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("vitte-rt-seek-test-{:?}", std::thread::current().id()));
+        let path = path.to_str().expect("temp path is valid utf-8");
+
+        std::fs::write(path, b"0123456789").expect("write temp file");
+        let mut file = StdFile::open(path).expect("open temp file");
+
+        file.seek(SeekFrom::Start(5)).expect("seek to offset 5");
+        let mut buf = [0u8; 4];
+        let n = file.read(&mut buf).expect("read after seek");
+        assert_eq!(&buf[..n], b"5678");
+
+        std::fs::remove_file(path).ok();
+    }
+}
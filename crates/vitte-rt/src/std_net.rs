@@ -0,0 +1,89 @@
+use crate::net::{Net, NetError, TcpListener, TcpStream, UdpSocket};
+use std::io::{Read, Write};
+
+/// A [`Net`] backed by `std::net`.
+#[derive(Debug, Default)]
+pub struct StdNet;
+
+impl Net for StdNet {
+    fn tcp_connect(&self, addr: &str, port: u16) -> Result<Box<dyn TcpStream>, NetError> {
+        let stream = std::net::TcpStream::connect((addr, port)).map_err(|_| NetError::Io("connect failed"))?;
+        Ok(Box::new(StdTcpStream(stream)))
+    }
+
+    fn tcp_listen(&self, addr: &str, port: u16) -> Result<Box<dyn TcpListener>, NetError> {
+        let listener = std::net::TcpListener::bind((addr, port)).map_err(|_| NetError::Io("bind failed"))?;
+        Ok(Box::new(StdTcpListener(listener)))
+    }
+
+    fn udp_bind(&self, addr: &str, port: u16) -> Result<Box<dyn UdpSocket>, NetError> {
+        let socket = std::net::UdpSocket::bind((addr, port)).map_err(|_| NetError::Io("bind failed"))?;
+        Ok(Box::new(StdUdpSocket(socket)))
+    }
+}
+
+struct StdTcpStream(std::net::TcpStream);
+
+impl TcpStream for StdTcpStream {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, NetError> {
+        self.0.read(buf).map_err(|_| NetError::Io("read failed"))
+    }
+
+    fn write(&mut self, buf: &[u8]) -> Result<usize, NetError> {
+        self.0.write(buf).map_err(|_| NetError::Io("write failed"))
+    }
+}
+
+struct StdTcpListener(std::net::TcpListener);
+
+impl TcpListener for StdTcpListener {
+    fn accept(&mut self) -> Result<(Box<dyn TcpStream>, String), NetError> {
+        let (stream, addr) = self.0.accept().map_err(|_| NetError::Io("accept failed"))?;
+        Ok((Box::new(StdTcpStream(stream)), addr.to_string()))
+    }
+
+    fn local_addr(&self) -> Result<String, NetError> {
+        self.0.local_addr().map(|addr| addr.to_string()).map_err(|_| NetError::Io("local_addr failed"))
+    }
+}
+
+struct StdUdpSocket(std::net::UdpSocket);
+
+impl UdpSocket for StdUdpSocket {
+    fn send_to(&mut self, buf: &[u8], addr: &str) -> Result<usize, NetError> {
+        self.0.send_to(buf, addr).map_err(|_| NetError::Io("send_to failed"))
+    }
+
+    fn recv_from(&mut self, buf: &mut [u8]) -> Result<(usize, String), NetError> {
+        let (n, addr) = self.0.recv_from(buf).map_err(|_| NetError::Io("recv_from failed"))?;
+        Ok((n, addr.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tcp_listener_echoes_a_single_byte() {
+        let net = StdNet;
+        let mut listener = net.tcp_listen("127.0.0.1", 0).expect("bind listener on an ephemeral port");
+        let addr = listener.local_addr().expect("listener has a local address");
+
+        let handle = std::thread::spawn(move || {
+            let (mut server_conn, _peer) = listener.accept().expect("accept incoming connection");
+            let mut buf = [0u8; 1];
+            server_conn.read(&mut buf).expect("read the byte the client sent");
+            server_conn.write(&buf).expect("echo it back");
+        });
+
+        let port: u16 = addr.rsplit(':').next().expect("address has a port").parse().expect("port is numeric");
+        let mut client = net.tcp_connect("127.0.0.1", port).expect("connect to the listener");
+        client.write(&[42]).expect("send a byte");
+        let mut echoed = [0u8; 1];
+        client.read(&mut echoed).expect("read the echoed byte");
+        assert_eq!(echoed, [42]);
+
+        handle.join().expect("server thread didn't panic");
+    }
+}
@@ -0,0 +1,42 @@
+use crate::process::{Process, ProcessError, ProcessOutput};
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// A [`Process`] backed by `std::process::Command`.
+#[derive(Debug, Default)]
+pub struct StdProcess;
+
+impl Process for StdProcess {
+    fn spawn(&self, prog: &str, args: &[&str]) -> Result<i32, ProcessError> {
+        let status = Command::new(prog).args(args).status().map_err(|_| ProcessError::Io("spawn failed"))?;
+        Ok(status.code().unwrap_or(-1))
+    }
+
+    fn run(&self, prog: &str, args: &[&str], stdin: Option<&[u8]>) -> Result<ProcessOutput, ProcessError> {
+        let mut command = Command::new(prog);
+        command.args(args).stdout(Stdio::piped()).stderr(Stdio::piped());
+        command.stdin(if stdin.is_some() { Stdio::piped() } else { Stdio::null() });
+
+        let mut child = command.spawn().map_err(|_| ProcessError::Io("spawn failed"))?;
+        if let Some(data) = stdin {
+            let mut child_stdin = child.stdin.take().expect("stdin was piped");
+            child_stdin.write_all(data).map_err(|_| ProcessError::Io("write to stdin failed"))?;
+        }
+
+        let output = child.wait_with_output().map_err(|_| ProcessError::Io("wait failed"))?;
+        Ok(ProcessOutput { status: output.status.code().unwrap_or(-1), stdout: output.stdout, stderr: output.stderr })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run_captures_the_childs_stdout() {
+        let process = StdProcess;
+        let output = process.run("echo", &["hello"], None).expect("echo is available");
+        assert_eq!(output.status, 0);
+        assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "hello");
+    }
+}
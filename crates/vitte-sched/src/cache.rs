@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The fingerprint recorded for a task the last time it ran.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CacheEntry {
+    pub fingerprint: u64,
+}
+
+/// In-memory task fingerprints, keyed by task name.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Cache {
+    mem: HashMap<String, CacheEntry>,
+}
+
+impl Cache {
+    pub fn get(&self, name: &str) -> Option<u64> {
+        self.mem.get(name).map(|entry| entry.fingerprint)
+    }
+
+    pub fn insert(&mut self, name: String, fingerprint: u64) {
+        self.mem.insert(name, CacheEntry { fingerprint });
+    }
+
+    /// Loads a previously saved cache from `path`. A missing or corrupt
+    /// file isn't an error here — callers should fall back to
+    /// `Cache::default()` and start fresh.
+    #[cfg(feature = "serde")]
+    pub fn load(path: &std::path::Path) -> Option<Self> {
+        let text = std::fs::read_to_string(path).ok()?;
+        serde_json::from_str(&text).ok()
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn save(&self, path: &std::path::Path) -> std::io::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let text = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+        std::fs::write(path, text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn an_unknown_task_has_no_recorded_fingerprint() {
+        let cache = Cache::default();
+        assert_eq!(cache.get("compile"), None);
+    }
+
+    #[test]
+    fn insert_then_get_round_trips_the_fingerprint() {
+        let mut cache = Cache::default();
+        cache.insert("compile".to_string(), 42);
+        assert_eq!(cache.get("compile"), Some(42));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn a_saved_cache_loads_back_with_the_same_entries() {
+        let dir = std::env::temp_dir().join(format!("vitte-sched-cache-test-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let path = dir.join("cache.json");
+
+        let mut cache = Cache::default();
+        cache.insert("compile".to_string(), 42);
+        cache.save(&path).expect("save succeeds");
+
+        let loaded = Cache::load(&path).expect("a freshly saved cache loads back");
+        assert_eq!(loaded.get("compile"), Some(42));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn loading_a_missing_file_returns_none() {
+        let path = std::env::temp_dir().join("vitte-sched-this-file-does-not-exist.json");
+        assert!(Cache::load(&path).is_none());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn loading_a_corrupt_file_returns_none() {
+        let dir = std::env::temp_dir().join(format!("vitte-sched-corrupt-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("cache.json");
+        std::fs::write(&path, "not json").unwrap();
+
+        assert!(Cache::load(&path).is_none());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,146 @@
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
+
+/// Which content hash [`crate::Options::hash_algo`] directs
+/// [`fingerprint_file`] to use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HashAlgo {
+    /// 64-bit FNV-1a. Dependency-free and fast, but collides more than a
+    /// cryptographic hash would once a build graph has enough distinct
+    /// files. Kept as the default so caches recorded before `Blake3` was
+    /// added still fingerprint the same way.
+    #[default]
+    Fnv64,
+    /// BLAKE3, behind the `blake3` feature. Far lower collision risk than
+    /// `Fnv64`; prefer it for large build graphs.
+    #[cfg(feature = "blake3")]
+    Blake3,
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Read in fixed-size chunks rather than [`std::io::Read::read_to_end`], so
+/// fingerprinting a huge build artifact doesn't balloon memory.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Fingerprints the file at `path` with `algo`, streaming it in
+/// [`CHUNK_SIZE`]-byte chunks instead of loading it into memory at once.
+pub fn fingerprint_file(path: &Path, algo: HashAlgo) -> io::Result<u64> {
+    let mut file = File::open(path)?;
+    let mut buf = [0u8; CHUNK_SIZE];
+    match algo {
+        HashAlgo::Fnv64 => {
+            let mut hash = FNV_OFFSET_BASIS;
+            loop {
+                let n = file.read(&mut buf)?;
+                if n == 0 {
+                    return Ok(hash);
+                }
+                for &byte in &buf[..n] {
+                    hash ^= byte as u64;
+                    hash = hash.wrapping_mul(FNV_PRIME);
+                }
+            }
+        }
+        #[cfg(feature = "blake3")]
+        HashAlgo::Blake3 => {
+            let mut hasher = blake3::Hasher::new();
+            loop {
+                let n = file.read(&mut buf)?;
+                if n == 0 {
+                    break;
+                }
+                hasher.update(&buf[..n]);
+            }
+            let digest = hasher.finalize();
+            let mut truncated = [0u8; 8];
+            truncated.copy_from_slice(&digest.as_bytes()[..8]);
+            Ok(u64::from_le_bytes(truncated))
+        }
+    }
+}
+
+/// Combines the per-file fingerprints of `paths`, in order, into one `u64`
+/// via the same FNV-1a fold regardless of `algo`, so a task with several
+/// input files still gets a single value to compare against
+/// [`crate::Cache`]. Fails on the first path that can't be read.
+pub fn fingerprint_files(paths: &[&Path], algo: HashAlgo) -> io::Result<u64> {
+    let mut combined = FNV_OFFSET_BASIS;
+    for path in paths {
+        let fp = fingerprint_file(path, algo)?;
+        combined ^= fp;
+        combined = combined.wrapping_mul(FNV_PRIME);
+    }
+    Ok(combined)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_temp(label: &str, contents: &[u8]) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("vitte-sched-fingerprint-test-{label}-{}", std::process::id()));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn a_one_byte_difference_changes_the_fnv64_fingerprint() {
+        let a = write_temp("fnv-a", b"hello world");
+        let b = write_temp("fnv-b", b"hello worle");
+
+        let fp_a = fingerprint_file(&a, HashAlgo::Fnv64).unwrap();
+        let fp_b = fingerprint_file(&b, HashAlgo::Fnv64).unwrap();
+        assert_ne!(fp_a, fp_b);
+
+        let _ = std::fs::remove_file(&a);
+        let _ = std::fs::remove_file(&b);
+    }
+
+    #[cfg(feature = "blake3")]
+    #[test]
+    fn a_one_byte_difference_changes_the_blake3_fingerprint() {
+        let a = write_temp("blake3-a", b"hello world");
+        let b = write_temp("blake3-b", b"hello worle");
+
+        let fp_a = fingerprint_file(&a, HashAlgo::Blake3).unwrap();
+        let fp_b = fingerprint_file(&b, HashAlgo::Blake3).unwrap();
+        assert_ne!(fp_a, fp_b);
+
+        let _ = std::fs::remove_file(&a);
+        let _ = std::fs::remove_file(&b);
+    }
+
+    #[test]
+    fn fingerprint_files_changes_when_any_input_file_changes() {
+        let a = write_temp("multi-a", b"hello");
+        let b = write_temp("multi-b", b"world");
+        let paths = [a.as_path(), b.as_path()];
+        let before = fingerprint_files(&paths, HashAlgo::Fnv64).unwrap();
+
+        std::fs::write(&b, b"wurld").unwrap();
+        let after = fingerprint_files(&paths, HashAlgo::Fnv64).unwrap();
+        assert_ne!(before, after);
+
+        let _ = std::fs::remove_file(&a);
+        let _ = std::fs::remove_file(&b);
+    }
+
+    #[test]
+    fn chunked_reading_matches_a_file_larger_than_one_chunk() {
+        let contents = vec![b'x'; CHUNK_SIZE * 3 + 17];
+        let path = write_temp("large", &contents);
+        let fp = fingerprint_file(&path, HashAlgo::Fnv64).unwrap();
+
+        let mut hash = FNV_OFFSET_BASIS;
+        for &byte in &contents {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+        assert_eq!(fp, hash);
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
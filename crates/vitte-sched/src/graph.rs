@@ -0,0 +1,297 @@
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// What a [`Task`] does once its dependencies have finished. Receives a
+/// fresh [`Ctx`] per run so workers never share mutable state.
+pub type TaskFn = Box<dyn Fn(&mut Ctx) + Send + Sync>;
+
+/// One node in the task graph: a name, the names of tasks it depends on,
+/// and the work to run once those have completed.
+pub struct Task {
+    pub name: String,
+    pub deps: Vec<String>,
+    pub run: TaskFn,
+    /// Glob patterns (`*`/`**`) for the files this task reads and writes,
+    /// used by [`crate::Shed::watch`] to decide whether a changed path
+    /// should trigger a rebuild. Empty by default, meaning no file change
+    /// will ever trigger this task on its own.
+    pub inputs: Vec<String>,
+    pub outputs: Vec<String>,
+}
+
+impl Task {
+    pub fn new(name: impl Into<String>, deps: Vec<String>, run: impl Fn(&mut Ctx) + Send + Sync + 'static) -> Self {
+        Self { name: name.into(), deps, run: Box::new(run), inputs: Vec::new(), outputs: Vec::new() }
+    }
+
+    pub fn with_inputs(mut self, inputs: Vec<String>) -> Self {
+        self.inputs = inputs;
+        self
+    }
+
+    pub fn with_outputs(mut self, outputs: Vec<String>) -> Self {
+        self.outputs = outputs;
+        self
+    }
+}
+
+/// Per-task scratch space: the outputs of its dependencies (keyed by
+/// dependency name), a place to record its own output for dependents to
+/// read, and a log buffer merged back into [`RunReport::log`] in
+/// deterministic topo order once the run finishes.
+pub struct Ctx {
+    pub name: String,
+    pub inputs: HashMap<String, String>,
+    pub output: Option<String>,
+    pub log: Vec<String>,
+    cwd: Option<PathBuf>,
+    env: HashMap<String, String>,
+}
+
+impl Ctx {
+    pub fn log(&mut self, message: impl Into<String>) {
+        self.log.push(message.into());
+    }
+
+    pub fn set_output(&mut self, value: impl Into<String>) {
+        self.output = Some(value.into());
+    }
+
+    /// Sets the working directory [`Ctx::shell`] spawns commands in. Lives
+    /// on this `Ctx`, so it never leaks to other tasks running in parallel.
+    pub fn with_cwd(mut self, cwd: PathBuf) -> Self {
+        self.cwd = Some(cwd);
+        self
+    }
+
+    /// Adds an environment variable [`Ctx::shell`] passes to the commands
+    /// it spawns, on top of this process's own environment.
+    pub fn env_set(&mut self, key: impl Into<String>, val: impl Into<String>) {
+        self.env.insert(key.into(), val.into());
+    }
+
+    /// Runs `cmd` through the platform shell, applying whatever cwd/env
+    /// this task has accumulated via [`Ctx::with_cwd`]/[`Ctx::env_set`].
+    pub fn shell(&self, cmd: &str) -> std::io::Result<std::process::Output> {
+        let (shell, flag) = if cfg!(windows) { ("cmd", "/C") } else { ("sh", "-c") };
+        let mut command = std::process::Command::new(shell);
+        command.arg(flag).arg(cmd);
+        if let Some(cwd) = &self.cwd {
+            command.current_dir(cwd);
+        }
+        command.envs(&self.env);
+        command.output()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Options {
+    /// Maximum number of tasks run concurrently within a single topo level.
+    pub parallel: usize,
+    /// How long [`crate::Shed::watch`] waits for more filesystem events
+    /// before coalescing what it's received so far into one rebuild.
+    pub debounce: Duration,
+    /// Makes [`crate::Shed::should_run`] (and [`crate::Shed::plan_report`])
+    /// report every task as dirty, bypassing the fingerprint cache.
+    pub force: bool,
+    /// Which hash [`crate::fingerprint_file`] uses for file-based
+    /// fingerprints.
+    pub hash_algo: crate::HashAlgo,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self { parallel: 1, debounce: Duration::from_millis(200), force: false, hash_algo: crate::HashAlgo::default() }
+    }
+}
+
+/// The result of [`run_all`]: which tasks ran (in deterministic topo
+/// order, not completion order), their merged logs in that same order, and
+/// each task's recorded output.
+#[derive(Debug, Default)]
+pub struct RunReport {
+    pub order: Vec<String>,
+    pub log: Vec<String>,
+    pub outputs: HashMap<String, String>,
+}
+
+/// Groups `tasks` by dependency level (every task in a level has all its
+/// deps in an earlier level) and runs each level on up to
+/// `options.parallel` threads at once, joining a level before the next one
+/// starts so dependents always see their dependencies' outputs.
+///
+/// Regardless of which worker thread finishes first, results are folded
+/// back into [`RunReport`] in the order tasks were submitted within their
+/// level, so logging order only ever depends on the graph's topology.
+pub fn run_all(tasks: &[Task], options: &Options) -> RunReport {
+    let parallel = options.parallel.max(1);
+    let mut report = RunReport::default();
+
+    for level in topo_levels(tasks) {
+        for chunk in level.chunks(parallel) {
+            let results: Vec<(String, Option<String>, Vec<String>)> = std::thread::scope(|scope| {
+                let handles: Vec<_> = chunk
+                    .iter()
+                    .map(|task| {
+                        let inputs = task
+                            .deps
+                            .iter()
+                            .filter_map(|dep| report.outputs.get(dep).map(|v| (dep.clone(), v.clone())))
+                            .collect();
+                        scope.spawn(move || {
+                            let mut ctx = Ctx {
+                                name: task.name.clone(),
+                                inputs,
+                                output: None,
+                                log: Vec::new(),
+                                cwd: None,
+                                env: HashMap::new(),
+                            };
+                            (task.run)(&mut ctx);
+                            (task.name.clone(), ctx.output, ctx.log)
+                        })
+                    })
+                    .collect();
+                handles.into_iter().map(|h| h.join().expect("task panicked")).collect()
+            });
+
+            for (name, output, log) in results {
+                if let Some(output) = output {
+                    report.outputs.insert(name.clone(), output);
+                }
+                report.log.extend(log);
+                report.order.push(name);
+            }
+        }
+    }
+
+    report
+}
+
+/// Splits `tasks` into levels via Kahn's algorithm: level 0 has no deps
+/// (among `tasks`), level `k` has every dep satisfied by an earlier level.
+/// A dep naming a task outside `tasks` is treated as already satisfied,
+/// since there's nothing here to wait for.
+fn topo_levels(tasks: &[Task]) -> Vec<Vec<&Task>> {
+    let names: HashSet<&str> = tasks.iter().map(|t| t.name.as_str()).collect();
+    let mut remaining: Vec<&Task> = tasks.iter().collect();
+    let mut done: HashSet<&str> = HashSet::new();
+    let mut levels = Vec::new();
+
+    while !remaining.is_empty() {
+        let (ready, pending): (Vec<&Task>, Vec<&Task>) = remaining
+            .into_iter()
+            .partition(|t| t.deps.iter().all(|d| !names.contains(d.as_str()) || done.contains(d.as_str())));
+
+        if ready.is_empty() {
+            // Every remaining task has an unmet dep among `tasks` itself,
+            // i.e. a cycle. There's no valid topo order left to find, so
+            // run what's left as one final level rather than looping
+            // forever.
+            levels.push(pending);
+            break;
+        }
+
+        for task in &ready {
+            done.insert(task.name.as_str());
+        }
+        levels.push(ready);
+        remaining = pending;
+    }
+
+    levels
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn two_independent_tasks_both_run_under_parallel_two() {
+        let ran = Arc::new(AtomicUsize::new(0));
+        let ran_a = ran.clone();
+        let ran_b = ran.clone();
+
+        let tasks = vec![
+            Task::new("a", Vec::new(), move |ctx| {
+                ran_a.fetch_add(1, Ordering::SeqCst);
+                ctx.set_output("a-output");
+            }),
+            Task::new("b", Vec::new(), move |ctx| {
+                ran_b.fetch_add(1, Ordering::SeqCst);
+                ctx.set_output("b-output");
+            }),
+        ];
+
+        let report = run_all(&tasks, &Options { parallel: 2, ..Options::default() });
+        assert_eq!(ran.load(Ordering::SeqCst), 2);
+        assert_eq!(report.outputs.get("a").map(String::as_str), Some("a-output"));
+        assert_eq!(report.outputs.get("b").map(String::as_str), Some("b-output"));
+    }
+
+    #[test]
+    fn a_dependent_task_sees_its_dependencies_outputs() {
+        let tasks = vec![
+            Task::new("a", Vec::new(), |ctx| ctx.set_output("from-a")),
+            Task::new("b", Vec::new(), |ctx| ctx.set_output("from-b")),
+            Task::new("combine", vec!["a".to_string(), "b".to_string()], |ctx| {
+                let combined = format!("{}+{}", ctx.inputs["a"], ctx.inputs["b"]);
+                ctx.set_output(combined);
+            }),
+        ];
+
+        let report = run_all(&tasks, &Options { parallel: 2, ..Options::default() });
+        assert_eq!(report.outputs.get("combine").map(String::as_str), Some("from-a+from-b"));
+    }
+
+    #[test]
+    fn logs_merge_in_topo_order_regardless_of_thread_completion_order() {
+        let tasks = vec![
+            Task::new("slow", Vec::new(), |ctx| {
+                std::thread::sleep(std::time::Duration::from_millis(20));
+                ctx.log("slow ran");
+            }),
+            Task::new("fast", Vec::new(), |ctx| ctx.log("fast ran")),
+            Task::new("after", vec!["slow".to_string(), "fast".to_string()], |ctx| ctx.log("after ran")),
+        ];
+
+        let report = run_all(&tasks, &Options { parallel: 2, ..Options::default() });
+        assert_eq!(report.order, vec!["slow", "fast", "after"]);
+        assert_eq!(report.log, vec!["slow ran", "fast ran", "after ran"]);
+    }
+
+    #[test]
+    fn shell_applies_the_contexts_cwd_and_env() {
+        let dir = std::env::temp_dir();
+        let mut ctx = Ctx {
+            name: "probe".to_string(),
+            inputs: HashMap::new(),
+            output: None,
+            log: Vec::new(),
+            cwd: None,
+            env: HashMap::new(),
+        }
+        .with_cwd(dir.clone());
+        ctx.env_set("VITTE_SCHED_TEST_VAR", "hello-from-ctx");
+
+        let output = ctx.shell("echo $VITTE_SCHED_TEST_VAR; pwd").expect("shell spawned");
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let mut lines = stdout.lines();
+        assert_eq!(lines.next(), Some("hello-from-ctx"));
+        let printed_cwd = std::path::PathBuf::from(lines.next().expect("pwd printed a line"));
+        assert_eq!(printed_cwd.canonicalize().unwrap(), dir.canonicalize().unwrap());
+    }
+
+    #[test]
+    fn parallel_one_still_runs_every_task() {
+        let tasks = vec![
+            Task::new("a", Vec::new(), |ctx| ctx.set_output("a")),
+            Task::new("b", vec!["a".to_string()], |ctx| ctx.set_output(ctx.inputs["a"].clone())),
+        ];
+        let report = run_all(&tasks, &Options { parallel: 1, ..Options::default() });
+        assert_eq!(report.outputs.get("b").map(String::as_str), Some("a"));
+    }
+}
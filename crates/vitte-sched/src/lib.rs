@@ -0,0 +1,303 @@
+//! A minimal incremental task runner: a [`Shed`] runs named tasks and skips
+//! ones whose inputs haven't changed since the last run, by comparing a
+//! content fingerprint rather than a timestamp. Behind `feature = "serde"`,
+//! the fingerprint cache persists to `{cache_dir}/cache.json`, so skipping
+//! survives across separate process invocations instead of resetting every
+//! time a CLI command runs.
+
+mod cache;
+mod fingerprint;
+mod graph;
+mod watch;
+
+pub use cache::{Cache, CacheEntry};
+pub use fingerprint::{fingerprint_file, fingerprint_files, HashAlgo};
+pub use graph::{run_all, Ctx, Options, RunReport, Task, TaskFn};
+pub use watch::{matches_any_glob, matches_glob};
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::Receiver;
+
+/// Whether [`Shed::run`] executed the task or skipped it because its inputs
+/// fingerprinted the same as last time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskStatus {
+    Ran,
+    UpToDate,
+}
+
+/// Runs tasks, skipping ones whose `inputs` fingerprint matches the last
+/// recorded run.
+pub struct Shed {
+    #[cfg(feature = "serde")]
+    cache_path: std::path::PathBuf,
+    cache: Cache,
+}
+
+impl Shed {
+    /// An in-memory-only scheduler: skipping still works within the
+    /// process, but the cache starts empty every time it's created.
+    #[cfg(not(feature = "serde"))]
+    pub fn new() -> Self {
+        Self { cache: Cache::default() }
+    }
+
+    /// Loads the fingerprint cache from `{cache_dir}/cache.json` if present,
+    /// so that tasks already up to date from a previous process run are
+    /// skipped immediately. A missing or corrupt cache file just means
+    /// starting fresh, not an error.
+    #[cfg(feature = "serde")]
+    pub fn new(cache_dir: impl Into<std::path::PathBuf>) -> Self {
+        let cache_path = cache_dir.into().join("cache.json");
+        let cache = Cache::load(&cache_path).unwrap_or_default();
+        Self { cache_path, cache }
+    }
+
+    /// Fingerprints `inputs`; if it matches `name`'s last recorded
+    /// fingerprint, reports [`TaskStatus::UpToDate`] without calling `f`.
+    /// Otherwise runs `f`, records the new fingerprint, and (behind
+    /// `feature = "serde"`) persists the cache to disk.
+    pub fn run(&mut self, name: &str, inputs: &str, f: impl FnOnce()) -> TaskStatus {
+        let fingerprint = fingerprint(inputs);
+        if self.cache.get(name) == Some(fingerprint) {
+            return TaskStatus::UpToDate;
+        }
+
+        f();
+        self.cache.insert(name.to_string(), fingerprint);
+        #[cfg(feature = "serde")]
+        let _ = self.cache.save(&self.cache_path);
+        TaskStatus::Ran
+    }
+
+    /// Whether running `name` with `inputs` would actually do work: either
+    /// `options.force` is set, or `inputs` fingerprints differently from
+    /// the last recorded run. Doesn't touch the cache either way.
+    pub fn should_run(&self, name: &str, inputs: &str, options: &Options) -> bool {
+        options.force || self.cache.get(name) != Some(fingerprint(inputs))
+    }
+
+    /// Like [`Shed::run`], but fingerprints `inputs` by hashing their file
+    /// contents with `options.hash_algo` instead of hashing an arbitrary
+    /// string, so the cache reflects what's actually on disk. Fails if any
+    /// input file can't be read.
+    pub fn run_files(&mut self, name: &str, inputs: &[&Path], options: &Options, f: impl FnOnce()) -> io::Result<TaskStatus> {
+        let fingerprint = fingerprint_files(inputs, options.hash_algo)?;
+        if self.cache.get(name) == Some(fingerprint) {
+            return Ok(TaskStatus::UpToDate);
+        }
+
+        f();
+        self.cache.insert(name.to_string(), fingerprint);
+        #[cfg(feature = "serde")]
+        let _ = self.cache.save(&self.cache_path);
+        Ok(TaskStatus::Ran)
+    }
+
+    /// Like [`Shed::should_run`], but fingerprints `inputs` as files on
+    /// disk via `options.hash_algo`, the way [`Shed::run_files`] does.
+    pub fn should_run_files(&self, name: &str, inputs: &[&Path], options: &Options) -> io::Result<bool> {
+        if options.force {
+            return Ok(true);
+        }
+        Ok(self.cache.get(name) != Some(fingerprint_files(inputs, options.hash_algo)?))
+    }
+
+    /// Reports, for each `(name, inputs)` pair in `tasks` (already in the
+    /// execution order the caller intends to run them in), whether
+    /// [`Shed::run`] would actually run it or skip it as up to date —
+    /// without running anything or mutating the cache. Useful for
+    /// inspecting a build graph before committing to running it.
+    pub fn plan_report(&self, tasks: &[(&str, &str)], options: &Options) -> Vec<(String, bool)> {
+        tasks.iter().map(|(name, inputs)| (name.to_string(), self.should_run(name, inputs, options))).collect()
+    }
+
+    /// Runs `tasks` once, then waits on `rx` for debounced batches of
+    /// changed paths (see [`watch::debounce`]) and reruns `tasks` whenever a
+    /// batch contains a path matching one of a task's `inputs`/`outputs`
+    /// globs. Returns once `rx`'s sender is dropped.
+    ///
+    /// The filesystem watcher that feeds `rx` is the caller's
+    /// responsibility — this only owns the debounce-and-filter policy, so
+    /// it doesn't pull in a platform file-watching dependency of its own.
+    pub fn watch(&mut self, tasks: &[Task], rx: &Receiver<PathBuf>, options: &Options) {
+        run_all(tasks, options);
+        loop {
+            let changed = watch::debounce(rx, options.debounce);
+            if changed.is_empty() {
+                return;
+            }
+            let affected = tasks
+                .iter()
+                .any(|task| changed.iter().any(|path| matches_any_glob(path, &task.inputs) || matches_any_glob(path, &task.outputs)));
+            if affected {
+                run_all(tasks, options);
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "serde"))]
+impl Default for Shed {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn fingerprint(inputs: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    inputs.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "serde"))]
+    #[test]
+    fn a_task_with_unchanged_inputs_is_skipped() {
+        let mut shed = Shed::new();
+        let mut runs = 0;
+        assert_eq!(shed.run("compile", "fn main() {}", || runs += 1), TaskStatus::Ran);
+        assert_eq!(shed.run("compile", "fn main() {}", || runs += 1), TaskStatus::UpToDate);
+        assert_eq!(runs, 1);
+    }
+
+    #[cfg(not(feature = "serde"))]
+    #[test]
+    fn changed_inputs_rerun_the_task() {
+        let mut shed = Shed::new();
+        assert_eq!(shed.run("compile", "a", || {}), TaskStatus::Ran);
+        assert_eq!(shed.run("compile", "b", || {}), TaskStatus::Ran);
+    }
+
+    #[cfg(not(feature = "serde"))]
+    #[test]
+    fn run_files_skips_until_an_input_file_changes() {
+        let path = std::env::temp_dir().join(format!("vitte-sched-run-files-test-{}", std::process::id()));
+        std::fs::write(&path, b"fn main() {}").unwrap();
+
+        let mut shed = Shed::new();
+        let options = Options::default();
+        let inputs = [path.as_path()];
+        let mut runs = 0;
+        assert_eq!(shed.run_files("compile", &inputs, &options, || runs += 1).unwrap(), TaskStatus::Ran);
+        assert_eq!(shed.run_files("compile", &inputs, &options, || runs += 1).unwrap(), TaskStatus::UpToDate);
+        assert_eq!(runs, 1);
+
+        std::fs::write(&path, b"fn main() { 1; }").unwrap();
+        assert_eq!(shed.run_files("compile", &inputs, &options, || runs += 1).unwrap(), TaskStatus::Ran);
+        assert_eq!(runs, 2);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[cfg(feature = "serde")]
+    fn temp_dir(label: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("vitte-sched-shed-test-{label}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn a_rebuilt_shed_reports_an_unchanged_task_as_up_to_date() {
+        let dir = temp_dir("rebuild");
+        let mut runs = 0;
+
+        {
+            let mut shed = Shed::new(&dir);
+            assert_eq!(shed.run("compile", "fn main() {}", || runs += 1), TaskStatus::Ran);
+        }
+
+        let mut shed = Shed::new(&dir);
+        assert_eq!(shed.run("compile", "fn main() {}", || runs += 1), TaskStatus::UpToDate);
+        assert_eq!(runs, 1, "the task must not rerun once its fingerprint is unchanged");
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn changed_inputs_rerun_even_after_a_rebuild() {
+        let dir = temp_dir("changed");
+
+        {
+            let mut shed = Shed::new(&dir);
+            assert_eq!(shed.run("compile", "a", || {}), TaskStatus::Ran);
+        }
+
+        let mut shed = Shed::new(&dir);
+        assert_eq!(shed.run("compile", "b", || {}), TaskStatus::Ran);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn a_missing_cache_file_starts_fresh_instead_of_failing() {
+        let dir = temp_dir("missing");
+        let mut shed = Shed::new(&dir);
+        assert_eq!(shed.run("compile", "fn main() {}", || {}), TaskStatus::Ran);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[cfg(not(feature = "serde"))]
+    #[test]
+    fn plan_report_marks_a_freshly_cached_task_as_skip() {
+        let mut shed = Shed::new();
+        shed.run("compile", "fn main() {}", || {});
+
+        let report = shed.plan_report(&[("compile", "fn main() {}")], &Options::default());
+        assert_eq!(report, vec![("compile".to_string(), false)]);
+    }
+
+    #[cfg(not(feature = "serde"))]
+    #[test]
+    fn force_flips_a_cached_task_to_run_without_mutating_the_cache() {
+        let mut shed = Shed::new();
+        shed.run("compile", "fn main() {}", || {});
+
+        let forced = Options { force: true, ..Options::default() };
+        let report = shed.plan_report(&[("compile", "fn main() {}")], &forced);
+        assert_eq!(report, vec![("compile".to_string(), true)]);
+
+        // plan_report must not have mutated the cache: a real run right
+        // after still sees the same fingerprint as up to date.
+        assert_eq!(shed.run("compile", "fn main() {}", || {}), TaskStatus::UpToDate);
+    }
+
+    #[cfg(not(feature = "serde"))]
+    #[test]
+    fn n_rapid_matching_events_produce_exactly_one_extra_run() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::mpsc::channel;
+        use std::sync::Arc;
+        use std::time::Duration;
+
+        let runs = Arc::new(AtomicUsize::new(0));
+        let runs_task = runs.clone();
+        let tasks =
+            vec![Task::new("build", Vec::new(), move |_| { runs_task.fetch_add(1, Ordering::SeqCst); })
+                .with_inputs(vec!["src/*.vitte".to_string()])];
+
+        let (tx, rx) = channel();
+        std::thread::spawn(move || {
+            for _ in 0..5 {
+                tx.send(std::path::PathBuf::from("src/main.vitte")).unwrap();
+            }
+        });
+
+        let mut shed = Shed::new();
+        let options = Options { parallel: 1, debounce: Duration::from_millis(50), ..Options::default() };
+        shed.watch(&tasks, &rx, &options);
+
+        // One run for the initial build, plus exactly one more for the
+        // whole burst of matching events coalesced into a single batch.
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,215 @@
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::Receiver;
+use std::time::Duration;
+
+/// Blocks for the first event on `rx`, then keeps collecting further
+/// events as long as each next one arrives within `window` of the last,
+/// returning the whole batch once the channel goes quiet. Saving a file
+/// typically fires several OS change events in quick succession; this
+/// turns that burst into a single batch instead of one rebuild per event.
+///
+/// Returns an empty `Vec` once `rx`'s sender is dropped and no event ever
+/// arrives.
+pub fn debounce(rx: &Receiver<PathBuf>, window: Duration) -> Vec<PathBuf> {
+    let Ok(first) = rx.recv() else {
+        return Vec::new();
+    };
+    let mut batch = vec![first];
+    while let Ok(path) = rx.recv_timeout(window) {
+        batch.push(path);
+    }
+    batch
+}
+
+/// Whether `path` matches any of `globs`. An empty glob list matches
+/// nothing — a task that declares no inputs/outputs shouldn't be rebuilt
+/// just because some unrelated file changed.
+pub fn matches_any_glob(path: &Path, globs: &[String]) -> bool {
+    let path = path.to_string_lossy();
+    globs.iter().any(|glob| matches_glob(glob, &path))
+}
+
+/// Matches `path` against a glob `pattern` made of `/`-separated segments,
+/// where `*` matches any run of characters within a segment, `**` matches
+/// zero or more whole segments (so it can cross directories), `[...]`
+/// matches one character from a class (`[a-z]` for a range, `[!...]` or
+/// `[^...]` negated), and a `{a,b}` brace group expands to one pattern per
+/// alternative before matching, so the path matches if any expansion does.
+pub fn matches_glob(pattern: &str, path: &str) -> bool {
+    expand_braces(pattern).iter().any(|pattern| matches_one(pattern, path))
+}
+
+fn matches_one(pattern: &str, path: &str) -> bool {
+    let pattern: Vec<&str> = pattern.split('/').collect();
+    let path: Vec<&str> = path.split('/').collect();
+    match_segments(&pattern, &path)
+}
+
+/// Expands the first `{a,b,c}` brace group in `pattern` into one string per
+/// alternative, then recurses so a pattern with more than one group expands
+/// fully. A pattern with no brace group expands to itself.
+fn expand_braces(pattern: &str) -> Vec<String> {
+    let Some(open) = pattern.find('{') else {
+        return vec![pattern.to_string()];
+    };
+    let Some(close_offset) = pattern[open..].find('}') else {
+        return vec![pattern.to_string()];
+    };
+    let close = open + close_offset;
+
+    let prefix = &pattern[..open];
+    let alternatives = &pattern[open + 1..close];
+    let suffix = &pattern[close + 1..];
+
+    alternatives
+        .split(',')
+        .flat_map(|alt| expand_braces(&format!("{prefix}{alt}{suffix}")))
+        .collect()
+}
+
+fn match_segments(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => {
+            match_segments(rest, path) || (!path.is_empty() && match_segments(pattern, &path[1..]))
+        }
+        Some((seg, rest)) => match path.split_first() {
+            Some((first, path_rest)) => segment_matches(seg, first) && match_segments(rest, path_rest),
+            None => false,
+        },
+    }
+}
+
+fn segment_matches(pattern: &str, text: &str) -> bool {
+    fn helper(pattern: &[u8], text: &[u8]) -> bool {
+        match pattern.first() {
+            None => text.is_empty(),
+            Some(b'*') => helper(&pattern[1..], text) || (!text.is_empty() && helper(pattern, &text[1..])),
+            Some(b'[') => match (parse_class(pattern), text.first()) {
+                (Some((class, rest)), Some(&c)) if class.matches(c) => helper(rest, &text[1..]),
+                _ => false,
+            },
+            Some(&p) => matches!(text.first(), Some(&t) if p == t) && helper(&pattern[1..], &text[1..]),
+        }
+    }
+    helper(pattern.as_bytes(), text.as_bytes())
+}
+
+/// A `[...]` character class: a set of individual characters and `a-z`
+/// style ranges, optionally negated with a leading `!` or `^`.
+struct CharClass<'a> {
+    negated: bool,
+    body: &'a [u8],
+}
+
+impl CharClass<'_> {
+    fn matches(&self, c: u8) -> bool {
+        let mut body = self.body;
+        let mut found = false;
+        while let Some(&b) = body.first() {
+            if body.len() >= 3 && body[1] == b'-' {
+                let (lo, hi) = (b, body[2]);
+                if lo <= c && c <= hi {
+                    found = true;
+                }
+                body = &body[3..];
+            } else {
+                if b == c {
+                    found = true;
+                }
+                body = &body[1..];
+            }
+        }
+        found != self.negated
+    }
+}
+
+/// Parses a `[...]` class starting at `pattern[0] == b'['`, returning the
+/// class and the remaining pattern after its closing `]`. Returns `None`
+/// if there's no closing `]`, in which case `[` is treated as a literal.
+fn parse_class(pattern: &[u8]) -> Option<(CharClass<'_>, &[u8])> {
+    let end = pattern.iter().position(|&b| b == b']')?;
+    let mut body = &pattern[1..end];
+    let negated = matches!(body.first(), Some(b'!') | Some(b'^'));
+    if negated {
+        body = &body[1..];
+    }
+    Some((CharClass { negated, body }, &pattern[end + 1..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc::channel;
+    use std::thread;
+
+    #[test]
+    fn rapid_events_within_the_window_coalesce_into_one_batch() {
+        let (tx, rx) = channel();
+        thread::spawn(move || {
+            for i in 0..5 {
+                tx.send(PathBuf::from(format!("src/main.vitte#{i}"))).unwrap();
+            }
+        });
+        let batch = debounce(&rx, Duration::from_millis(50));
+        assert_eq!(batch.len(), 5, "{batch:?}");
+    }
+
+    #[test]
+    fn events_spaced_beyond_the_window_form_separate_batches() {
+        let (tx, rx) = channel();
+        tx.send(PathBuf::from("a")).unwrap();
+        let first = debounce(&rx, Duration::from_millis(20));
+        assert_eq!(first, vec![PathBuf::from("a")]);
+
+        thread::sleep(Duration::from_millis(40));
+        tx.send(PathBuf::from("b")).unwrap();
+        let second = debounce(&rx, Duration::from_millis(20));
+        assert_eq!(second, vec![PathBuf::from("b")]);
+    }
+
+    #[test]
+    fn a_dropped_sender_with_no_events_yields_an_empty_batch() {
+        let (tx, rx) = channel::<PathBuf>();
+        drop(tx);
+        assert!(debounce(&rx, Duration::from_millis(10)).is_empty());
+    }
+
+    #[test]
+    fn star_matches_within_a_single_segment_only() {
+        assert!(matches_glob("src/*.vitte", "src/main.vitte"));
+        assert!(!matches_glob("src/*.vitte", "src/nested/main.vitte"));
+    }
+
+    #[test]
+    fn double_star_matches_across_segments() {
+        assert!(matches_glob("src/**/*.vitte", "src/a/b/main.vitte"));
+        assert!(matches_glob("src/**/*.vitte", "src/main.vitte"));
+        assert!(!matches_glob("src/**/*.vitte", "tests/main.vitte"));
+    }
+
+    #[test]
+    fn an_empty_glob_list_matches_nothing() {
+        assert!(!matches_any_glob(Path::new("src/main.vitte"), &[]));
+    }
+
+    #[test]
+    fn a_character_class_matches_one_of_its_members() {
+        assert!(matches_glob("[abc]*.txt", "a.txt"));
+        assert!(matches_glob("[a-c]*.txt", "b.txt"));
+        assert!(!matches_glob("[abc]*.txt", "d.txt"));
+    }
+
+    #[test]
+    fn a_negated_character_class_matches_everything_else() {
+        assert!(matches_glob("[!abc]*.txt", "d.txt"));
+        assert!(!matches_glob("[!abc]*.txt", "a.txt"));
+    }
+
+    #[test]
+    fn a_braced_alternation_expands_to_two_patterns() {
+        assert!(matches_glob("src/*.{rs,vitte}", "src/main.rs"));
+        assert!(matches_glob("src/*.{rs,vitte}", "src/main.vitte"));
+        assert!(!matches_glob("src/*.{rs,vitte}", "src/main.toml"));
+    }
+}
@@ -0,0 +1,458 @@
+use crate::error::{TypeError, TypeErrorKind};
+use crate::symbols::SymbolTable;
+use crate::types::TypeId;
+use std::collections::HashMap;
+use vitte_ast::{BinOp, Block, Expr, FnDecl, Stmt, WithSpan};
+use vitte_core::{LineMap, Span};
+
+/// Checks one function's body against a module-wide [`SymbolTable`],
+/// inferring each expression's [`TypeId`] and reporting unknown names,
+/// call-arity mismatches, and argument-type mismatches along the way.
+///
+/// Locals get a flat name-to-type map, populated from parameters up front
+/// and extended by each `let`; like [`vitte_ast`]'s other passes it doesn't
+/// model block-scoped shadowing. A `let` that rebinds an existing name is
+/// still allowed — it's a common, intentional pattern — but reports a
+/// [`TypeErrorKind::ShadowedBinding`] warning so an accidental one doesn't
+/// go unnoticed.
+pub struct TypeChecker<'a> {
+    symbols: &'a SymbolTable,
+    locals: HashMap<String, TypeId>,
+    line_map: Option<LineMap>,
+    errors: Vec<TypeError>,
+}
+
+impl<'a> TypeChecker<'a> {
+    pub fn new(symbols: &'a SymbolTable) -> Self {
+        Self { symbols, locals: HashMap::new(), line_map: None, errors: Vec::new() }
+    }
+
+    /// Checks a function body. `src` is the source text its spans were
+    /// computed against; every diagnostic is resolved to a `line:column`
+    /// in it.
+    pub fn check_fn(mut self, f: &FnDecl, src: &str) -> Vec<TypeError> {
+        self.line_map = Some(LineMap::new(src));
+        for param in &f.params {
+            self.locals.insert(param.name.clone(), TypeId::from_ast(&param.ty));
+        }
+        self.check_block(&f.body);
+        self.errors
+    }
+
+    fn line_col(&self, span: Span) -> (u32, u32) {
+        self.line_map.as_ref().expect("line_map set at the start of check_fn").line_col(span.start)
+    }
+
+    fn push_error(&mut self, kind: TypeErrorKind, span: Span) {
+        let (line, column) = self.line_col(span);
+        self.errors.push(TypeError::new(kind, span, line, column));
+    }
+
+    fn push_warning(&mut self, kind: TypeErrorKind, span: Span) {
+        let (line, column) = self.line_col(span);
+        self.errors.push(TypeError::warn(kind, span, line, column));
+    }
+
+    fn check_block(&mut self, block: &Block) {
+        for stmt in &block.stmts {
+            self.check_stmt(stmt);
+        }
+    }
+
+    fn check_stmt(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::Expr(expr, _) => {
+                self.type_of_expr(expr);
+            }
+            Stmt::Return(value, _) => {
+                if let Some(expr) = value {
+                    self.type_of_expr(expr);
+                }
+            }
+            Stmt::Let { name, name_span, value, .. } => {
+                if self.locals.contains_key(name) {
+                    self.push_warning(TypeErrorKind::ShadowedBinding { name: name.clone() }, *name_span);
+                }
+                let mut ty = self.type_of_expr(value);
+                // Record and variant types are structural (see
+                // `TypeId::Record`/`TypeId::Variant`), so a binding's exact
+                // shape is only trustworthy when it comes straight from a
+                // literal built out of values. A non-value RHS (e.g. a
+                // call) could depend on mutable state or other effects that
+                // make its "shape" unstable across calls — the value
+                // restriction — so don't carry that shape through one;
+                // treat it as `Unknown` instead.
+                if matches!(ty, TypeId::Record(_) | TypeId::Variant(_)) && !value.is_value() {
+                    ty = TypeId::Unknown;
+                }
+                self.locals.insert(name.clone(), ty);
+            }
+            Stmt::If { cond, then_branch, else_branch, .. } => {
+                self.type_of_expr(cond);
+                self.check_block(then_branch);
+                if let Some(else_stmt) = else_branch {
+                    self.check_stmt(else_stmt);
+                }
+            }
+            Stmt::While { cond, body, .. } => {
+                self.type_of_expr(cond);
+                self.check_block(body);
+            }
+            Stmt::Block(block, _) => self.check_block(block),
+            Stmt::Assign { target, value, .. } => {
+                self.type_of_expr(target);
+                self.type_of_expr(value);
+            }
+            Stmt::For { iter, body, .. } => {
+                self.type_of_expr(iter);
+                self.check_block(body);
+            }
+        }
+    }
+
+    fn type_of_expr(&mut self, expr: &Expr) -> TypeId {
+        match expr {
+            Expr::Int(_, _) => TypeId::Int,
+            Expr::Float(_, _) => TypeId::Float,
+            Expr::Bool(_, _) => TypeId::Bool,
+            Expr::Str(_, _) => TypeId::Str,
+            Expr::Ident(name, span) => match self.locals.get(name) {
+                Some(ty) => ty.clone(),
+                None => {
+                    self.push_error(TypeErrorKind::UnknownLocal { name: name.clone() }, *span);
+                    TypeId::Unknown
+                }
+            },
+            Expr::Unary { expr, .. } => self.type_of_expr(expr),
+            Expr::Binary { op, lhs, rhs, span } => {
+                let lhs_ty = self.type_of_expr(lhs);
+                let rhs_ty = self.type_of_expr(rhs);
+                if !lhs_ty.unifies_with(&rhs_ty) {
+                    self.push_error(
+                        TypeErrorKind::IncompatibleBinaryOp { op: *op, lhs: lhs_ty.clone(), rhs: rhs_ty },
+                        *span,
+                    );
+                }
+                match op {
+                    BinOp::Eq | BinOp::Ne | BinOp::Lt | BinOp::Le | BinOp::Gt | BinOp::Ge | BinOp::And | BinOp::Or => {
+                        TypeId::Bool
+                    }
+                    BinOp::Add | BinOp::Sub | BinOp::Mul | BinOp::Div | BinOp::Rem => lhs_ty,
+                }
+            }
+            Expr::Group(inner, _) => self.type_of_expr(inner),
+            Expr::Call { callee, args, span } => self.type_of_call(callee, args, *span),
+            Expr::StructLit { fields, .. } => {
+                let fields = fields.iter().map(|(name, value)| (name.clone(), self.type_of_expr(value))).collect();
+                TypeId::Record(fields)
+            }
+            Expr::Variant { tag, value, .. } => TypeId::Variant(vec![(tag.clone(), self.type_of_expr(value))]),
+            Expr::Field { expr, name, span } => match self.type_of_expr(expr) {
+                TypeId::Unknown => TypeId::Unknown,
+                base => match base.field(name) {
+                    Some(ty) => ty.clone(),
+                    None => {
+                        self.push_error(TypeErrorKind::UnknownField { field: name.clone(), on: base }, *span);
+                        TypeId::Unknown
+                    }
+                },
+            },
+            // Index access and ranges aren't typed yet.
+            Expr::Index { .. } | Expr::Range { .. } => TypeId::Unknown,
+        }
+    }
+
+    fn type_of_call(&mut self, callee: &Expr, args: &[Expr], span: Span) -> TypeId {
+        let arg_types: Vec<TypeId> = args.iter().map(|arg| self.type_of_expr(arg)).collect();
+
+        let name = match callee {
+            Expr::Ident(name, _) => name,
+            // e.g. `foo().bar()` — resolving a non-identifier callee isn't
+            // supported yet.
+            _ => return TypeId::Unknown,
+        };
+        let Some(sig) = self.symbols.resolve(name) else {
+            self.push_error(TypeErrorKind::UnknownFunction { name: name.clone() }, span);
+            return TypeId::Unknown;
+        };
+
+        if arg_types.len() != sig.params.len() {
+            self.push_error(
+                TypeErrorKind::ArityMismatch { function: name.clone(), expected: sig.params.len(), found: arg_types.len() },
+                span,
+            );
+            return sig.ret.clone();
+        }
+
+        for (index, (expected, (found, arg))) in sig.params.iter().zip(arg_types.iter().zip(args)).enumerate() {
+            if !expected.unifies_with(found) {
+                self.push_error(
+                    TypeErrorKind::ArgTypeMismatch {
+                        function: name.clone(),
+                        param_index: index,
+                        expected: expected.clone(),
+                        found: found.clone(),
+                    },
+                    arg.span(),
+                );
+            }
+        }
+        sig.ret.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Severity;
+    use vitte_ast::{Item, Param, PrimType, Program, Type};
+
+    fn span() -> Span {
+        Span::new(0, 0)
+    }
+
+    fn ident(name: &str) -> Expr {
+        Expr::Ident(name.to_string(), span())
+    }
+
+    fn int(v: i64) -> Expr {
+        Expr::Int(v, span())
+    }
+
+    fn str_lit(s: &str) -> Expr {
+        Expr::Str(s.to_string(), span())
+    }
+
+    fn call(callee: &str, args: Vec<Expr>) -> Expr {
+        Expr::Call { callee: Box::new(ident(callee)), args, span: span() }
+    }
+
+    fn struct_lit(fields: Vec<(&str, Expr)>) -> Expr {
+        Expr::StructLit {
+            name: "Anon".to_string(),
+            fields: fields.into_iter().map(|(name, value)| (name.to_string(), value)).collect(),
+            span: span(),
+        }
+    }
+
+    fn field(expr: Expr, name: &str) -> Expr {
+        Expr::Field { expr: Box::new(expr), name: name.to_string(), span: span() }
+    }
+
+    fn variant(enum_name: &str, tag: &str, value: Expr) -> Expr {
+        Expr::Variant { enum_name: enum_name.to_string(), tag: tag.to_string(), value: Box::new(value), span: span() }
+    }
+
+    /// `fn add(a: i64, b: i64) -> i64 { a }`
+    fn add_decl() -> FnDecl {
+        FnDecl {
+            name: "add".to_string(),
+            name_span: span(),
+            params: vec![
+                Param { name: "a".to_string(), name_span: span(), ty: Type::Prim(PrimType::I64) },
+                Param { name: "b".to_string(), name_span: span(), ty: Type::Prim(PrimType::I64) },
+            ],
+            ret: Some(Type::Prim(PrimType::I64)),
+            body: Block { stmts: vec![Stmt::Return(Some(ident("a")), span())], span: span() },
+            span: span(),
+        }
+    }
+
+    fn main_calling(call_expr: Expr) -> FnDecl {
+        FnDecl {
+            name: "main".to_string(),
+            name_span: span(),
+            params: Vec::new(),
+            ret: None,
+            body: Block { stmts: vec![Stmt::Expr(call_expr, span())], span: span() },
+            span: span(),
+        }
+    }
+
+    fn symbols_with_add() -> SymbolTable {
+        SymbolTable::collect(&Program { items: vec![Item::Fn(add_decl())] })
+    }
+
+    fn symbols_with_prelude() -> SymbolTable {
+        SymbolTable::collect_with_builtins(&Program { items: Vec::new() }, crate::builtin_prelude())
+    }
+
+    #[test]
+    fn a_correct_call_reports_no_errors() {
+        let main = main_calling(call("add", vec![int(1), int(2)]));
+        let errors = TypeChecker::new(&symbols_with_add()).check_fn(&main, "");
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn a_wrong_arity_call_is_reported() {
+        let main = main_calling(call("add", vec![int(1)]));
+        let errors = TypeChecker::new(&symbols_with_add()).check_fn(&main, "");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind, TypeErrorKind::ArityMismatch { function: "add".to_string(), expected: 2, found: 1 });
+    }
+
+    #[test]
+    fn a_wrong_type_argument_is_reported() {
+        let main = main_calling(call("add", vec![int(1), str_lit("two")]));
+        let errors = TypeChecker::new(&symbols_with_add()).check_fn(&main, "");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].kind,
+            TypeErrorKind::ArgTypeMismatch { function: "add".to_string(), param_index: 1, expected: TypeId::Int, found: TypeId::Str }
+        );
+    }
+
+    #[test]
+    fn an_incompatible_binary_op_is_reported_at_the_operators_span() {
+        let src = "fn main() { 1 + \"two\"; }";
+        let lhs_start = src.find('1').unwrap() as u32;
+        let lhs = Expr::Int(1, Span::new(lhs_start, lhs_start + 1));
+        let rhs_start = src.find("\"two\"").unwrap() as u32;
+        let rhs = Expr::Str("two".to_string(), Span::new(rhs_start, rhs_start + "\"two\"".len() as u32));
+        let bin_span = Span::merge(lhs.span(), rhs.span());
+        let expr = Expr::Binary { op: BinOp::Add, lhs: Box::new(lhs), rhs: Box::new(rhs), span: bin_span };
+
+        let main = FnDecl {
+            name: "main".to_string(),
+            name_span: span(),
+            params: Vec::new(),
+            ret: None,
+            body: Block { stmts: vec![Stmt::Expr(expr, bin_span)], span: bin_span },
+            span: bin_span,
+        };
+        let errors = TypeChecker::new(&symbols_with_add()).check_fn(&main, src);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].kind,
+            TypeErrorKind::IncompatibleBinaryOp { op: BinOp::Add, lhs: TypeId::Int, rhs: TypeId::Str }
+        );
+
+        let (expected_line, expected_column) = LineMap::new(src).line_col(bin_span.start);
+        assert_eq!(errors[0].line, expected_line);
+        assert_eq!(errors[0].column, expected_column);
+    }
+
+    #[test]
+    fn redeclaring_a_local_with_let_reports_a_shadow_warning() {
+        let main = FnDecl {
+            name: "main".to_string(),
+            name_span: span(),
+            params: Vec::new(),
+            ret: None,
+            body: Block {
+                stmts: vec![
+                    Stmt::Let { name: "x".to_string(), name_span: span(), ty: None, value: int(1), span: span() },
+                    Stmt::Let { name: "x".to_string(), name_span: span(), ty: None, value: int(2), span: span() },
+                ],
+                span: span(),
+            },
+            span: span(),
+        };
+        let errors = TypeChecker::new(&symbols_with_add()).check_fn(&main, "");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind, TypeErrorKind::ShadowedBinding { name: "x".to_string() });
+        assert_eq!(errors[0].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn a_correctly_typed_builtin_call_reports_no_errors() {
+        let main = main_calling(call("len", vec![str_lit("hi")]));
+        let errors = TypeChecker::new(&symbols_with_prelude()).check_fn(&main, "");
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn a_wrong_arity_builtin_call_is_reported() {
+        let main = main_calling(call("len", vec![str_lit("a"), str_lit("b")]));
+        let errors = TypeChecker::new(&symbols_with_prelude()).check_fn(&main, "");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind, TypeErrorKind::ArityMismatch { function: "len".to_string(), expected: 1, found: 2 });
+    }
+
+    #[test]
+    fn projecting_a_field_present_on_two_differently_shaped_records_succeeds() {
+        let point = struct_lit(vec![("x", int(1)), ("y", int(2))]);
+        let labeled = struct_lit(vec![("x", int(3)), ("label", str_lit("origin"))]);
+        let main = FnDecl {
+            name: "main".to_string(),
+            name_span: span(),
+            params: Vec::new(),
+            ret: None,
+            body: Block {
+                stmts: vec![Stmt::Expr(field(point, "x"), span()), Stmt::Expr(field(labeled, "x"), span())],
+                span: span(),
+            },
+            span: span(),
+        };
+        let errors = TypeChecker::new(&symbols_with_add()).check_fn(&main, "");
+        assert!(errors.is_empty(), "{errors:?}");
+    }
+
+    #[test]
+    fn projecting_a_field_that_does_not_exist_is_reported() {
+        let rec = struct_lit(vec![("x", int(1))]);
+        let main = main_calling(field(rec, "y"));
+        let errors = TypeChecker::new(&symbols_with_add()).check_fn(&main, "");
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(&errors[0].kind, TypeErrorKind::UnknownField { field, .. } if field == "y"));
+    }
+
+    #[test]
+    fn a_record_bound_from_a_non_value_expression_is_not_trusted_as_a_record() {
+        let rec = struct_lit(vec![("x", call("add", vec![int(1), int(2)]))]);
+        let main = FnDecl {
+            name: "main".to_string(),
+            name_span: span(),
+            params: Vec::new(),
+            ret: None,
+            body: Block {
+                stmts: vec![
+                    Stmt::Let { name: "r".to_string(), name_span: span(), ty: None, value: rec, span: span() },
+                    Stmt::Expr(field(ident("r"), "y"), span()),
+                ],
+                span: span(),
+            },
+            span: span(),
+        };
+        let errors = TypeChecker::new(&symbols_with_add()).check_fn(&main, "");
+        assert!(errors.is_empty(), "{errors:?}");
+    }
+
+    #[test]
+    fn a_projected_field_with_a_conflicting_type_fails_to_unify_at_the_call_site() {
+        let rec = struct_lit(vec![("x", str_lit("hi"))]);
+        let main = main_calling(call("add", vec![field(rec, "x"), int(2)]));
+        let errors = TypeChecker::new(&symbols_with_add()).check_fn(&main, "");
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(&errors[0].kind, TypeErrorKind::ArgTypeMismatch { param_index: 0, .. }));
+    }
+
+    #[test]
+    fn constructing_some_of_one_infers_a_single_tag_variant_that_does_not_unify_with_an_unrelated_function() {
+        let some_one = variant("Option", "Some", int(1));
+        let main = main_calling(call("add", vec![some_one, int(2)]));
+        let errors = TypeChecker::new(&symbols_with_add()).check_fn(&main, "");
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            &errors[0].kind,
+            TypeErrorKind::ArgTypeMismatch { param_index: 0, expected: TypeId::Int, found: TypeId::Variant(tags), .. }
+                if tags == &[("Some".to_string(), TypeId::Int)]
+        ));
+    }
+
+    #[test]
+    fn a_variant_bound_from_a_non_value_expression_is_not_trusted_as_a_variant() {
+        let some_sum = variant("Option", "Some", call("add", vec![int(1), int(2)]));
+        let main = FnDecl {
+            name: "main".to_string(),
+            name_span: span(),
+            params: Vec::new(),
+            ret: None,
+            body: Block { stmts: vec![Stmt::Let { name: "v".to_string(), name_span: span(), ty: None, value: some_sum, span: span() }], span: span() },
+            span: span(),
+        };
+        let errors = TypeChecker::new(&symbols_with_add()).check_fn(&main, "");
+        assert!(errors.is_empty(), "{errors:?}");
+    }
+}
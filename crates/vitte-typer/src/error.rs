@@ -0,0 +1,89 @@
+use crate::types::TypeId;
+use std::fmt;
+use vitte_ast::BinOp;
+use vitte_core::Span;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeErrorKind {
+    /// `Expr::Ident` referenced a name with no matching local binding.
+    UnknownLocal { name: String },
+    /// `Expr::Call` targeted a name with no matching function declaration.
+    UnknownFunction { name: String },
+    /// A call passed a different number of arguments than the callee
+    /// declares parameters for.
+    ArityMismatch { function: String, expected: usize, found: usize },
+    /// A call's argument at `param_index` doesn't unify with the callee's
+    /// declared parameter type.
+    ArgTypeMismatch { function: String, param_index: usize, expected: TypeId, found: TypeId },
+    /// `Expr::Field` projected a field the base record doesn't have.
+    UnknownField { field: String, on: TypeId },
+    /// `Expr::Binary`'s operands don't unify, e.g. adding an `i64` to a
+    /// `str`.
+    IncompatibleBinaryOp { op: BinOp, lhs: TypeId, rhs: TypeId },
+    /// A `let` rebound a name already bound in this function, hiding the
+    /// earlier binding. Not an error — shadowing is legal — but worth
+    /// flagging since it's easy to do by accident.
+    ShadowedBinding { name: String },
+}
+
+impl fmt::Display for TypeErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypeErrorKind::UnknownLocal { name } => write!(f, "unknown local `{name}`"),
+            TypeErrorKind::UnknownFunction { name } => write!(f, "call to unknown function `{name}`"),
+            TypeErrorKind::ArityMismatch { function, expected, found } => {
+                write!(f, "`{function}` expects {expected} argument(s), found {found}")
+            }
+            TypeErrorKind::ArgTypeMismatch { function, param_index, expected, found } => {
+                write!(f, "`{function}` argument {param_index} expected `{expected}`, found `{found}`")
+            }
+            TypeErrorKind::UnknownField { field, on } => {
+                write!(f, "no field `{field}` on `{on}`")
+            }
+            TypeErrorKind::ShadowedBinding { name } => write!(f, "`{name}` shadows previous binding"),
+            TypeErrorKind::IncompatibleBinaryOp { op, lhs, rhs } => {
+                write!(f, "incompatible operand types `{lhs}` and `{rhs}` for `{op:?}`")
+            }
+        }
+    }
+}
+
+/// Whether a [`TypeError`] blocks compilation or is just advisory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// A single type diagnostic, carrying both its raw source [`Span`] and the
+/// 1-indexed `line:column` it was resolved to when raised.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeError {
+    pub kind: TypeErrorKind,
+    pub span: Span,
+    pub line: u32,
+    pub column: u32,
+    pub severity: Severity,
+}
+
+impl TypeError {
+    pub fn new(kind: TypeErrorKind, span: Span, line: u32, column: u32) -> Self {
+        Self { kind, span, line, column, severity: Severity::Error }
+    }
+
+    pub fn warn(kind: TypeErrorKind, span: Span, line: u32, column: u32) -> Self {
+        Self { kind, span, line, column, severity: Severity::Warning }
+    }
+}
+
+impl fmt::Display for TypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        write!(f, "{label}: {} at {}:{}", self.kind, self.line, self.column)
+    }
+}
+
+impl std::error::Error for TypeError {}
@@ -0,0 +1,12 @@
+//! Checks a `vitte-ast` tree for type errors — unknown names, call-arity
+//! mismatches, and argument-type mismatches — before it reaches the emitter.
+
+mod checker;
+mod error;
+mod symbols;
+mod types;
+
+pub use checker::TypeChecker;
+pub use error::{Severity, TypeError, TypeErrorKind};
+pub use symbols::{builtin_prelude, SymbolTable};
+pub use types::{FunctionSig, TypeId};
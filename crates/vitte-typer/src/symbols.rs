@@ -0,0 +1,95 @@
+use crate::types::{FunctionSig, TypeId};
+use std::collections::HashMap;
+use vitte_ast::{Item, Program};
+
+/// Maps function names to their declared signature, collected once for the
+/// whole module and shared across every function's [`crate::TypeChecker`].
+#[derive(Debug, Clone, Default)]
+pub struct SymbolTable {
+    functions: HashMap<String, FunctionSig>,
+}
+
+impl SymbolTable {
+    /// Collects every `fn` declared at the top level of `program`.
+    pub fn collect(program: &Program) -> Self {
+        Self::collect_with_builtins(program, Vec::new())
+    }
+
+    /// Like [`SymbolTable::collect`], but seeds the table with `builtins`
+    /// first so calls to them type-check the same way calls to a
+    /// user-declared `fn` do. A top-level declaration with the same name
+    /// as a builtin overrides it, since it's the more specific definition.
+    pub fn collect_with_builtins(program: &Program, builtins: impl IntoIterator<Item = (String, FunctionSig)>) -> Self {
+        let mut functions: HashMap<String, FunctionSig> = builtins.into_iter().collect();
+        for item in &program.items {
+            if let Item::Fn(f) = item {
+                let params = f.params.iter().map(|p| TypeId::from_ast(&p.ty)).collect();
+                let ret = f.ret.as_ref().map(TypeId::from_ast).unwrap_or(TypeId::Unit);
+                functions.insert(f.name.clone(), FunctionSig { params, ret });
+            }
+        }
+        Self { functions }
+    }
+
+    pub fn resolve(&self, name: &str) -> Option<&FunctionSig> {
+        self.functions.get(name)
+    }
+}
+
+/// A small seeded set of signatures for functions every program can call
+/// without declaring them itself, for use with
+/// [`SymbolTable::collect_with_builtins`].
+pub fn builtin_prelude() -> Vec<(String, FunctionSig)> {
+    vec![
+        ("print".to_string(), FunctionSig { params: vec![TypeId::Str], ret: TypeId::Unit }),
+        ("len".to_string(), FunctionSig { params: vec![TypeId::Str], ret: TypeId::Int }),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vitte_ast::{Block, FnDecl, Param, PrimType, Type};
+    use vitte_core::Span;
+
+    fn span() -> Span {
+        Span::new(0, 0)
+    }
+
+    #[test]
+    fn collects_parameter_and_return_types() {
+        let f = FnDecl {
+            name: "add".to_string(),
+            name_span: span(),
+            params: vec![
+                Param { name: "a".to_string(), name_span: span(), ty: Type::Prim(PrimType::I64) },
+                Param { name: "b".to_string(), name_span: span(), ty: Type::Prim(PrimType::I64) },
+            ],
+            ret: Some(Type::Prim(PrimType::I64)),
+            body: Block { stmts: Vec::new(), span: span() },
+            span: span(),
+        };
+        let program = Program { items: vec![Item::Fn(f)] };
+        let symbols = SymbolTable::collect(&program);
+
+        let sig = symbols.resolve("add").expect("add is declared");
+        assert_eq!(sig.params, vec![TypeId::Int, TypeId::Int]);
+        assert_eq!(sig.ret, TypeId::Int);
+        assert_eq!(symbols.resolve("missing"), None);
+    }
+
+    #[test]
+    fn a_function_with_no_return_type_returns_unit() {
+        let f = FnDecl {
+            name: "noop".to_string(),
+            name_span: span(),
+            params: Vec::new(),
+            ret: None,
+            body: Block { stmts: Vec::new(), span: span() },
+            span: span(),
+        };
+        let program = Program { items: vec![Item::Fn(f)] };
+        let symbols = SymbolTable::collect(&program);
+        assert_eq!(symbols.resolve("noop").unwrap().ret, TypeId::Unit);
+    }
+}
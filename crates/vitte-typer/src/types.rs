@@ -0,0 +1,189 @@
+use std::fmt;
+use vitte_ast::{PrimType, Type};
+
+/// A type resolved during checking. `Unknown` stands for anything the
+/// checker doesn't model yet (tuples, function types, ...); it unifies with
+/// everything so unsupported type forms don't produce false positives, the
+/// same way the emitter leaves unsupported AST nodes as no-ops rather than
+/// erroring on them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypeId {
+    Int,
+    Float,
+    Bool,
+    Str,
+    /// The type of an expression with no value, e.g. a call to a `fn`
+    /// with no return type.
+    Unit,
+    Unknown,
+    /// A structural record, inferred from an `Expr::StructLit`'s fields
+    /// rather than looked up by name. Row-polymorphic: see
+    /// [`TypeId::unifies_with`].
+    Record(Vec<(String, TypeId)>),
+    /// A tagged union. An `Expr::Variant` value carries exactly one
+    /// `(tag, payload)` pair; a declared parameter/return type lists every
+    /// tag the enum can take. See [`TypeId::unifies_with`].
+    Variant(Vec<(String, TypeId)>),
+}
+
+impl TypeId {
+    pub fn from_ast(ty: &Type) -> Self {
+        match ty {
+            Type::Prim(PrimType::I64) => TypeId::Int,
+            Type::Prim(PrimType::F64) => TypeId::Float,
+            Type::Prim(PrimType::Bool) => TypeId::Bool,
+            Type::Prim(PrimType::Str) => TypeId::Str,
+            Type::Ident(_) | Type::Tuple(_) | Type::Array(_) | Type::Fn(_, _) => TypeId::Unknown,
+        }
+    }
+
+    /// The type of `self`'s field `name`, if `self` is a record that has
+    /// one.
+    pub fn field(&self, name: &str) -> Option<&TypeId> {
+        match self {
+            TypeId::Record(fields) => fields.iter().find(|(n, _)| n == name).map(|(_, ty)| ty),
+            _ => None,
+        }
+    }
+
+    /// Whether `self` and `other` can stand in for each other. `Unknown`
+    /// unifies with anything. Records unify row-polymorphically: every
+    /// field `self` requires must be present (with a unifying type) on
+    /// `other`, but `other` may carry extra fields beyond that. Variants
+    /// unify the other way around: every tag `other` actually carries must
+    /// be among the tags `self` declares, with a unifying payload — an
+    /// unrecognized tag is a mismatch.
+    pub fn unifies_with(&self, other: &TypeId) -> bool {
+        match (self, other) {
+            (TypeId::Unknown, _) | (_, TypeId::Unknown) => true,
+            (TypeId::Record(required), TypeId::Record(actual)) => required.iter().all(|(name, ty)| {
+                actual.iter().any(|(actual_name, actual_ty)| actual_name == name && ty.unifies_with(actual_ty))
+            }),
+            (TypeId::Variant(declared), TypeId::Variant(actual)) => actual.iter().all(|(tag, ty)| {
+                declared.iter().any(|(declared_tag, declared_ty)| declared_tag == tag && declared_ty.unifies_with(ty))
+            }),
+            _ => self == other,
+        }
+    }
+}
+
+impl fmt::Display for TypeId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypeId::Int => write!(f, "int"),
+            TypeId::Float => write!(f, "float"),
+            TypeId::Bool => write!(f, "bool"),
+            TypeId::Str => write!(f, "str"),
+            TypeId::Unit => write!(f, "unit"),
+            TypeId::Unknown => write!(f, "unknown"),
+            TypeId::Record(fields) => {
+                write!(f, "{{")?;
+                for (i, (name, ty)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{name}: {ty}")?;
+                }
+                write!(f, "}}")
+            }
+            TypeId::Variant(tags) => {
+                for (i, (tag, ty)) in tags.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " | ")?;
+                    }
+                    write!(f, "{tag}({ty})")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// A function's parameter and return types, as declared.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionSig {
+    pub params: Vec<TypeId>,
+    pub ret: TypeId,
+}
+
+impl fmt::Display for FunctionSig {
+    /// Renders as `(param, param) -> ret`, e.g. `(int, {x: int}) -> bool`,
+    /// reusing [`TypeId`]'s `Display` for each part. Since identifiers in
+    /// this checker are plain `String`s rather than interned symbols,
+    /// field and type names are already the real source text — there's no
+    /// separate "resolve for diagnostics" step needed.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        for (i, param) in self.params.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{param}")?;
+        }
+        write!(f, ") -> {}", self.ret)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(fields: &[(&str, TypeId)]) -> TypeId {
+        TypeId::Record(fields.iter().map(|(n, t)| (n.to_string(), t.clone())).collect())
+    }
+
+    #[test]
+    fn a_record_with_an_extra_field_unifies_with_one_requiring_fewer() {
+        let required = record(&[("x", TypeId::Int)]);
+        let actual = record(&[("x", TypeId::Int), ("y", TypeId::Bool)]);
+        assert!(required.unifies_with(&actual));
+    }
+
+    #[test]
+    fn a_record_missing_a_required_field_does_not_unify() {
+        let required = record(&[("x", TypeId::Int)]);
+        let actual = record(&[("y", TypeId::Bool)]);
+        assert!(!required.unifies_with(&actual));
+    }
+
+    #[test]
+    fn a_required_field_with_a_conflicting_type_does_not_unify() {
+        let required = record(&[("x", TypeId::Int)]);
+        let actual = record(&[("x", TypeId::Str)]);
+        assert!(!required.unifies_with(&actual));
+    }
+
+    #[test]
+    fn a_record_type_displays_with_its_real_field_names() {
+        let ty = record(&[("x", TypeId::Int), ("y", TypeId::Bool)]);
+        assert_eq!(ty.to_string(), "{x: int, y: bool}");
+    }
+
+    #[test]
+    fn a_signature_with_a_record_parameter_displays_every_part() {
+        let sig = FunctionSig { params: vec![record(&[("x", TypeId::Int)]), TypeId::Bool], ret: TypeId::Unit };
+        assert_eq!(sig.to_string(), "({x: int}, bool) -> unit");
+    }
+
+    fn option_of_int() -> TypeId {
+        TypeId::Variant(vec![("Some".to_string(), TypeId::Int), ("None".to_string(), TypeId::Unit)])
+    }
+
+    #[test]
+    fn a_variant_value_unifies_with_a_declared_type_that_has_a_matching_tag() {
+        let some_one = TypeId::Variant(vec![("Some".to_string(), TypeId::Int)]);
+        assert!(option_of_int().unifies_with(&some_one));
+    }
+
+    #[test]
+    fn a_variant_value_with_an_unknown_tag_does_not_unify() {
+        let unexpected = TypeId::Variant(vec![("Err".to_string(), TypeId::Str)]);
+        assert!(!option_of_int().unifies_with(&unexpected));
+    }
+
+    #[test]
+    fn a_variant_value_with_a_conflicting_payload_type_does_not_unify() {
+        let some_string = TypeId::Variant(vec![("Some".to_string(), TypeId::Str)]);
+        assert!(!option_of_int().unifies_with(&some_string));
+    }
+}
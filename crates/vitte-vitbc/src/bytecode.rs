@@ -0,0 +1,279 @@
+use crate::crc32::crc32;
+use crate::error::VitbcError;
+use crate::line_table::LineEntry;
+use crate::reader::Reader;
+
+pub const MAGIC: [u8; 6] = *b"VITBC\0";
+pub const VERSION: u16 = 1;
+
+/// The sections of a compiled Vitte program, as they're laid out in a
+/// VITBC container: constant pools (`ints`, `floats`, `strings`), a raw
+/// `data` blob for anything else the compiler wants to embed, the `code`
+/// instruction stream, debug `names`, and a debug `lines` table mapping
+/// bytecode offsets back to source positions.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Bytecode {
+    pub ints: Vec<i64>,
+    pub floats: Vec<f64>,
+    pub strings: Vec<String>,
+    pub data: Vec<u8>,
+    pub code: Vec<u8>,
+    pub names: Vec<String>,
+    pub lines: Vec<LineEntry>,
+}
+
+impl Bytecode {
+    /// Serializes to a VITBC container: a `VITBC\0` magic and version,
+    /// followed by `[tag:4][len:u32][payload]` sections in a fixed order,
+    /// and a trailing `CRCC` section holding the CRC-32 of everything
+    /// written before it.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(&MAGIC);
+        out.extend_from_slice(&VERSION.to_le_bytes());
+
+        write_section(&mut out, b"INTS", &encode_ints(&self.ints));
+        write_section(&mut out, b"FLTS", &encode_floats(&self.floats));
+        write_section(&mut out, b"STRS", &encode_strings(&self.strings));
+        write_section(&mut out, b"DATA", &self.data);
+        write_section(&mut out, b"CODE", &encode_code(&self.code));
+        write_section(&mut out, b"NAME", &encode_strings(&self.names));
+        write_section(&mut out, b"LINE", &encode_lines(&self.lines));
+
+        let crc = crc32(&out);
+        write_section(&mut out, b"CRCC", &crc.to_le_bytes());
+        out
+    }
+
+    /// Parses a VITBC container written by [`Bytecode::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Bytecode, VitbcError> {
+        let mut r = Reader::new(bytes);
+        let magic = r.read_exact(6, "magic")?;
+        if magic != MAGIC {
+            return Err(VitbcError::BadMagic);
+        }
+        let version = r.read_u16("version")?;
+        if version != VERSION {
+            return Err(VitbcError::UnsupportedVersion(version));
+        }
+
+        let mut bc = Bytecode::default();
+        let mut seen_crcc = false;
+
+        while r.remaining() > 0 {
+            let section_start = r.pos;
+            let tag: [u8; 4] = r.read_exact(4, "section tag")?.try_into().expect("read_exact(4) yields 4 bytes");
+            let len = r.read_u32("section length")? as usize;
+            let payload = r.read_exact(len, "section payload")?;
+
+            if tag == *b"INTS" {
+                bc.ints = decode_ints(payload)?;
+            } else if tag == *b"FLTS" {
+                bc.floats = decode_floats(payload)?;
+            } else if tag == *b"STRS" {
+                bc.strings = decode_strings(payload)?;
+            } else if tag == *b"DATA" {
+                bc.data = payload.to_vec();
+            } else if tag == *b"CODE" {
+                bc.code = decode_code(payload)?;
+            } else if tag == *b"NAME" {
+                bc.names = decode_strings(payload)?;
+            } else if tag == *b"LINE" {
+                bc.lines = decode_lines(payload)?;
+            } else if tag == *b"CRCC" {
+                let expected = u32::from_le_bytes(payload.try_into().map_err(|_| VitbcError::Truncated { section: "CRCC" })?);
+                let actual = crc32(&bytes[..section_start]);
+                if expected != actual {
+                    return Err(VitbcError::CrcMismatch { expected, actual });
+                }
+                seen_crcc = true;
+            } else {
+                return Err(VitbcError::UnknownSection(tag));
+            }
+        }
+
+        if !seen_crcc {
+            return Err(VitbcError::Truncated { section: "CRCC" });
+        }
+
+        Ok(bc)
+    }
+}
+
+fn write_section(out: &mut Vec<u8>, tag: &[u8; 4], payload: &[u8]) {
+    out.extend_from_slice(tag);
+    out.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    out.extend_from_slice(payload);
+}
+
+fn encode_ints(ints: &[i64]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(4 + ints.len() * 8);
+    out.extend_from_slice(&(ints.len() as u32).to_le_bytes());
+    for &v in ints {
+        out.extend_from_slice(&v.to_le_bytes());
+    }
+    out
+}
+
+fn decode_ints(payload: &[u8]) -> Result<Vec<i64>, VitbcError> {
+    let mut r = Reader::new(payload);
+    let count = r.read_u32("INTS")? as usize;
+    let mut out = Vec::with_capacity(count);
+    for _ in 0..count {
+        let b = r.read_exact(8, "INTS")?;
+        out.push(i64::from_le_bytes(b.try_into().expect("read_exact(8) yields 8 bytes")));
+    }
+    Ok(out)
+}
+
+fn encode_floats(floats: &[f64]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(4 + floats.len() * 8);
+    out.extend_from_slice(&(floats.len() as u32).to_le_bytes());
+    for &v in floats {
+        out.extend_from_slice(&v.to_le_bytes());
+    }
+    out
+}
+
+fn decode_floats(payload: &[u8]) -> Result<Vec<f64>, VitbcError> {
+    let mut r = Reader::new(payload);
+    let count = r.read_u32("FLTS")? as usize;
+    let mut out = Vec::with_capacity(count);
+    for _ in 0..count {
+        let b = r.read_exact(8, "FLTS")?;
+        out.push(f64::from_le_bytes(b.try_into().expect("read_exact(8) yields 8 bytes")));
+    }
+    Ok(out)
+}
+
+fn encode_strings(strings: &[String]) -> Vec<u8> {
+    let mut out = Vec::new();
+    out.extend_from_slice(&(strings.len() as u32).to_le_bytes());
+    for s in strings {
+        out.extend_from_slice(&(s.len() as u32).to_le_bytes());
+        out.extend_from_slice(s.as_bytes());
+    }
+    out
+}
+
+fn decode_strings(payload: &[u8]) -> Result<Vec<String>, VitbcError> {
+    let mut r = Reader::new(payload);
+    let count = r.read_u32("STRS")? as usize;
+    let mut out = Vec::with_capacity(count);
+    for _ in 0..count {
+        let len = r.read_u32("STRS")? as usize;
+        let bytes = r.read_exact(len, "STRS")?;
+        out.push(String::from_utf8(bytes.to_vec()).map_err(|_| VitbcError::InvalidUtf8)?);
+    }
+    Ok(out)
+}
+
+fn encode_lines(lines: &[LineEntry]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(4 + lines.len() * 12);
+    out.extend_from_slice(&(lines.len() as u32).to_le_bytes());
+    for entry in lines {
+        out.extend_from_slice(&entry.offset.to_le_bytes());
+        out.extend_from_slice(&entry.line.to_le_bytes());
+        out.extend_from_slice(&entry.column.to_le_bytes());
+    }
+    out
+}
+
+fn decode_lines(payload: &[u8]) -> Result<Vec<LineEntry>, VitbcError> {
+    let mut r = Reader::new(payload);
+    let count = r.read_u32("LINE")? as usize;
+    let mut out = Vec::with_capacity(count);
+    for _ in 0..count {
+        let offset = r.read_u32("LINE")?;
+        let line = r.read_u32("LINE")?;
+        let column = r.read_u32("LINE")?;
+        out.push(LineEntry { offset, line, column });
+    }
+    Ok(out)
+}
+
+#[cfg(feature = "zstd")]
+fn encode_code(code: &[u8]) -> Vec<u8> {
+    zstd::stream::encode_all(code, 0).expect("compressing an in-memory buffer cannot fail")
+}
+
+#[cfg(not(feature = "zstd"))]
+fn encode_code(code: &[u8]) -> Vec<u8> {
+    code.to_vec()
+}
+
+#[cfg(feature = "zstd")]
+fn decode_code(payload: &[u8]) -> Result<Vec<u8>, VitbcError> {
+    zstd::stream::decode_all(payload).map_err(|_| VitbcError::Truncated { section: "CODE" })
+}
+
+#[cfg(not(feature = "zstd"))]
+fn decode_code(payload: &[u8]) -> Result<Vec<u8>, VitbcError> {
+    Ok(payload.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn small_program() -> Bytecode {
+        Bytecode {
+            ints: vec![0, 1, -42, i64::MAX],
+            floats: vec![0.0, 3.5, -1.25],
+            strings: vec!["hello".to_string(), "world".to_string()],
+            data: vec![0xDE, 0xAD, 0xBE, 0xEF],
+            code: vec![0x01, 0x02, 0x00, 0x03, 0xFF],
+            names: vec!["main".to_string(), "add".to_string()],
+            lines: vec![LineEntry { offset: 0, line: 1, column: 1 }, LineEntry { offset: 3, line: 2, column: 5 }],
+        }
+    }
+
+    #[test]
+    fn round_trips_a_small_compiled_program() {
+        let bc = small_program();
+        let bytes = bc.to_bytes();
+        let decoded = Bytecode::from_bytes(&bytes).expect("valid container");
+        assert_eq!(decoded, bc);
+    }
+
+    #[test]
+    fn round_trips_an_empty_program() {
+        let bc = Bytecode::default();
+        let bytes = bc.to_bytes();
+        assert_eq!(Bytecode::from_bytes(&bytes).unwrap(), bc);
+    }
+
+    #[test]
+    fn rejects_bad_magic() {
+        let mut bytes = small_program().to_bytes();
+        bytes[0] = b'X';
+        assert_eq!(Bytecode::from_bytes(&bytes), Err(VitbcError::BadMagic));
+    }
+
+    #[test]
+    fn rejects_unsupported_version() {
+        let mut bytes = small_program().to_bytes();
+        bytes[6..8].copy_from_slice(&99u16.to_le_bytes());
+        assert_eq!(Bytecode::from_bytes(&bytes), Err(VitbcError::UnsupportedVersion(99)));
+    }
+
+    #[test]
+    fn rejects_truncated_input() {
+        let bytes = small_program().to_bytes();
+        let truncated = &bytes[..bytes.len() - 10];
+        assert!(matches!(Bytecode::from_bytes(truncated), Err(VitbcError::Truncated { .. })));
+    }
+
+    #[test]
+    fn rejects_a_corrupted_byte_via_crc_mismatch() {
+        let mut bytes = small_program().to_bytes();
+        // Flip a byte inside the raw `DATA` payload: unlike the string
+        // sections it isn't validated on the way in, so the corruption
+        // survives until the trailing CRCC check catches it instead of
+        // tripping some other structural error first.
+        let needle = [0xDE, 0xAD, 0xBE, 0xEF];
+        let at = bytes.windows(needle.len()).position(|w| w == needle).expect("DATA payload present");
+        bytes[at] ^= 0xFF;
+        assert!(matches!(Bytecode::from_bytes(&bytes), Err(VitbcError::CrcMismatch { .. })));
+    }
+}
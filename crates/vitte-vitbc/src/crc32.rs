@@ -0,0 +1,78 @@
+//! A self-contained CRC-32 (IEEE 802.3, the same variant used by zip and
+//! gzip) so the container format doesn't need an external dependency just
+//! to checksum a handful of bytes.
+
+const fn build_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut c = i as u32;
+        let mut j = 0;
+        while j < 8 {
+            c = if c & 1 != 0 { 0xEDB8_8320 ^ (c >> 1) } else { c >> 1 };
+            j += 1;
+        }
+        table[i] = c;
+        i += 1;
+    }
+    table
+}
+
+const TABLE: [u32; 256] = build_table();
+
+/// Computes the CRC-32 checksum of `bytes` using the precomputed lookup
+/// table above. Public so other crates can checksum data the same way this
+/// container format does, without re-implementing it.
+pub fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for &b in bytes {
+        let idx = ((crc ^ b as u32) & 0xFF) as usize;
+        crc = TABLE[idx] ^ (crc >> 8);
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_the_standard_check_value() {
+        // The canonical CRC-32/ISO-HDLC check value for the ASCII string
+        // "123456789", used by every CRC-32 implementation's test suite.
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn empty_input_is_zero() {
+        assert_eq!(crc32(b""), 0);
+    }
+
+    /// A reference bit-at-a-time CRC-32, kept only here to check the
+    /// table-driven version above against an independent implementation.
+    fn crc32_bitwise(bytes: &[u8]) -> u32 {
+        let mut crc = 0xFFFF_FFFFu32;
+        for &b in bytes {
+            crc ^= b as u32;
+            for _ in 0..8 {
+                crc = if crc & 1 != 0 { 0xEDB8_8320 ^ (crc >> 1) } else { crc >> 1 };
+            }
+        }
+        !crc
+    }
+
+    #[test]
+    fn table_version_matches_the_bitwise_reference_over_random_data() {
+        let mut state = 0x2545F4914F6CDD1Du64;
+        let mut next_byte = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state & 0xFF) as u8
+        };
+        for len in [0, 1, 2, 7, 16, 63, 100, 255, 1000] {
+            let data: Vec<u8> = (0..len).map(|_| next_byte()).collect();
+            assert_eq!(crc32(&data), crc32_bitwise(&data), "mismatch for {len} random bytes");
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use std::fmt;
+
+/// Everything that can go wrong parsing a VITBC container.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VitbcError {
+    /// The first 6 bytes weren't the `VITBC\0` magic.
+    BadMagic,
+    /// The container declares a version this reader doesn't know.
+    UnsupportedVersion(u16),
+    /// Ran out of bytes while reading `section`.
+    Truncated { section: &'static str },
+    /// A section tag that isn't one of the known ones.
+    UnknownSection([u8; 4]),
+    /// The trailing `CRCC` checksum doesn't match the recomputed one.
+    CrcMismatch { expected: u32, actual: u32 },
+    /// A string section contained bytes that aren't valid UTF-8.
+    InvalidUtf8,
+}
+
+impl fmt::Display for VitbcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VitbcError::BadMagic => write!(f, "not a VITBC container (bad magic)"),
+            VitbcError::UnsupportedVersion(v) => write!(f, "unsupported VITBC version {v}"),
+            VitbcError::Truncated { section } => write!(f, "truncated {section} section"),
+            VitbcError::UnknownSection(tag) => {
+                write!(f, "unknown section tag {:?}", String::from_utf8_lossy(tag))
+            }
+            VitbcError::CrcMismatch { expected, actual } => {
+                write!(f, "CRC mismatch: expected {expected:#010x}, got {actual:#010x}")
+            }
+            VitbcError::InvalidUtf8 => write!(f, "section contains invalid utf-8"),
+        }
+    }
+}
+
+impl std::error::Error for VitbcError {}
@@ -0,0 +1,13 @@
+//! Reader and writer for VITBC, the container format the Vitte compiler
+//! emits compiled bytecode into.
+
+mod bytecode;
+mod crc32;
+mod error;
+mod line_table;
+mod reader;
+
+pub use bytecode::{Bytecode, MAGIC, VERSION};
+pub use crc32::crc32;
+pub use error::VitbcError;
+pub use line_table::LineEntry;
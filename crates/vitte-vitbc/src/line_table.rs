@@ -0,0 +1,11 @@
+/// Maps one bytecode offset to the source position it was generated from.
+///
+/// `offset` is a bytecode-offset, not a byte offset into the source file —
+/// it's whatever unit the emitter counts instructions in. `line`/`column`
+/// are 1-indexed, matching `vitte_core::LineMap`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineEntry {
+    pub offset: u32,
+    pub line: u32,
+    pub column: u32,
+}
@@ -0,0 +1,38 @@
+use crate::error::VitbcError;
+
+/// A cursor over a byte slice that turns running out of input into a
+/// [`VitbcError::Truncated`] naming the section being read, instead of a
+/// panic or a generic "unexpected end of input".
+pub(crate) struct Reader<'a> {
+    bytes: &'a [u8],
+    pub(crate) pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    pub(crate) fn remaining(&self) -> usize {
+        self.bytes.len() - self.pos
+    }
+
+    pub(crate) fn read_exact(&mut self, n: usize, section: &'static str) -> Result<&'a [u8], VitbcError> {
+        if self.remaining() < n {
+            return Err(VitbcError::Truncated { section });
+        }
+        let slice = &self.bytes[self.pos..self.pos + n];
+        self.pos += n;
+        Ok(slice)
+    }
+
+    pub(crate) fn read_u16(&mut self, section: &'static str) -> Result<u16, VitbcError> {
+        let b = self.read_exact(2, section)?;
+        Ok(u16::from_le_bytes([b[0], b[1]]))
+    }
+
+    pub(crate) fn read_u32(&mut self, section: &'static str) -> Result<u32, VitbcError> {
+        let b = self.read_exact(4, section)?;
+        Ok(u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    }
+}